@@ -5,6 +5,30 @@ pub const AFTER_FAILED_AUTOMATION_REPLY_ID: u64 = 2;
 pub const AFTER_DELEGATION_REPLY_ID: u64 = 3;
 pub const AFTER_PROVIDE_LIQUIDITY_REPLY_ID: u64 = 4;
 pub const AFTER_BOND_LP_TOKENS_REPLY_ID: u64 = 5;
+pub const AFTER_PULL_FUNDS_REPLY_ID: u64 = 6;
+pub const AFTER_CLAIM_STAKING_REWARDS_REPLY_ID: u64 = 7;
+
+pub const MAX_CONSECUTIVE_FAILED_PULLS: u32 = 3;
+
+pub const ADMIN_PROPOSAL_EXPIRY_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+pub const LATEST_STORAGE_VERSION: u16 = 5;
+
+pub const PRICE_HISTORY_CAPACITY: u32 = 720;
+
+pub const DEFAULT_MOVING_AVERAGE_RESOLUTION_SECONDS: u64 = 60 * 60;
+
+pub const MAX_MOVING_AVERAGE_RESOLUTION_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+pub const MAX_VOLATILITY_WINDOWS: u8 = 24;
+
+pub const MAX_WEIGHTED_SCALE_TIERS: usize = 10;
+
+pub const TWAP_HISTORY_RETENTION_SECONDS: u64 = 48 * 60 * 60;
+
+pub const MAX_LOCK_DURATION_SECONDS: u64 = i64::MAX as u64 / 1_000_000_000;
+
+pub const ESCROW_CHECKPOINT_INTERVAL_SECONDS: u64 = 30 * 24 * 60 * 60;
 
 pub const ONE_MICRON: Uint128 = Uint128::new(1);
 pub const TWO_MICRONS: Uint128 = Uint128::new(2);
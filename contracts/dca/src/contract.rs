@@ -1,37 +1,73 @@
 use crate::constants::{
-    AFTER_BOND_LP_TOKENS_REPLY_ID, AFTER_DELEGATION_REPLY_ID, AFTER_FAILED_AUTOMATION_REPLY_ID,
-    AFTER_PROVIDE_LIQUIDITY_REPLY_ID, AFTER_SWAP_REPLY_ID,
+    AFTER_BOND_LP_TOKENS_REPLY_ID, AFTER_CLAIM_STAKING_REWARDS_REPLY_ID, AFTER_DELEGATION_REPLY_ID,
+    AFTER_FAILED_AUTOMATION_REPLY_ID, AFTER_PROVIDE_LIQUIDITY_REPLY_ID, AFTER_PULL_FUNDS_REPLY_ID,
+    AFTER_SWAP_REPLY_ID,
 };
 use crate::error::ContractError;
+use crate::handlers::accept_admin::accept_admin_handler;
+use crate::handlers::cancel_admin_proposal::cancel_admin_proposal_handler;
+use crate::handlers::cancel_queued_config_change::cancel_queued_config_change_handler;
 use crate::handlers::cancel_vault::cancel_vault_handler;
+use crate::handlers::continue_migration::continue_migration_handler;
 use crate::handlers::create_custom_swap_fee::create_custom_swap_fee_handler;
 use crate::handlers::create_pair::create_pair_handler;
 use crate::handlers::create_pairs::create_pairs_handler;
 use crate::handlers::create_vault::create_vault_handler;
+use crate::handlers::delete_pair::delete_pair_handler;
 use crate::handlers::deposit::deposit_handler;
 use crate::handlers::disburse_escrow::disburse_escrow_handler;
 use crate::handlers::disburse_funds::disburse_funds_handler;
+use crate::handlers::execute_queued_config_change::execute_queued_config_change_handler;
 use crate::handlers::execute_trigger::execute_trigger_handler;
 use crate::handlers::fix_position_type::fix_position_type;
-use crate::handlers::get_config::get_config_handler;
+use crate::handlers::get_circuit_breaker::get_circuit_breaker_handler;
+use crate::handlers::get_config::{
+    get_config_handler, get_pending_admin_handler, get_pending_config_changes_handler,
+};
 use crate::handlers::get_custom_swap_fees::get_custom_swap_fees_handler;
 use crate::handlers::get_disburse_escrow_tasks::get_disburse_escrow_tasks_handler;
+use crate::handlers::get_escrow_releases::get_escrow_releases_handler;
 use crate::handlers::get_events::get_events_handler;
 use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
-use crate::handlers::get_pairs::get_pairs_handler;
+use crate::handlers::get_migration_status::get_migration_status_handler;
+use crate::handlers::get_pairs::{get_pairs_by_denom_handler, get_pairs_handler};
+use crate::handlers::get_price_history::get_price_history_handler;
+use crate::handlers::get_risk_weighted_average_models::get_risk_weighted_average_models_handler;
+use crate::handlers::get_roles::{get_role_members_handler, get_roles_handler};
+use crate::handlers::get_solvency_report::get_solvency_report_handler;
+use crate::handlers::get_swap_adjustment::{
+    get_swap_adjustment_handler, get_swap_adjustment_history_handler,
+    get_swap_adjustment_publishers_handler, get_swap_adjustments_handler,
+};
 use crate::handlers::get_time_trigger_ids::get_time_trigger_ids_handler;
 use crate::handlers::get_vault::get_vault_handler;
 use crate::handlers::get_vault_performance::get_vault_performance_handler;
 use crate::handlers::get_vaults::get_vaults_handler;
 use crate::handlers::get_vaults_by_address::get_vaults_by_address_handler;
+use crate::handlers::grant_role::grant_role_handler;
 use crate::handlers::handle_failed_automation::handle_failed_automation_handler;
 use crate::handlers::instantiate::instantiate_handler;
 use crate::handlers::migrate::migrate_handler;
+use crate::handlers::propose_new_admin::propose_new_admin_handler;
+use crate::handlers::receive_pulled_funds::receive_pulled_funds_handler;
+use crate::handlers::receive_staking_rewards::receive_staking_rewards_handler;
+use crate::handlers::recover_surplus::recover_surplus_handler;
 use crate::handlers::remove_custom_swap_fee::remove_custom_swap_fee_handler;
+use crate::handlers::remove_risk_weighted_average_model::remove_risk_weighted_average_model_handler;
+use crate::handlers::reset_circuit_breaker::reset_circuit_breaker_handler;
+use crate::handlers::revoke_role::revoke_role_handler;
+use crate::handlers::set_paused::set_paused_handler;
+use crate::handlers::set_risk_weighted_average_model::set_risk_weighted_average_model_handler;
+use crate::handlers::set_swap_adjustment_aggregation::set_swap_adjustment_aggregation_handler;
 use crate::handlers::update_config::update_config_handler;
+use crate::handlers::update_pair_config::update_pair_config_handler;
+use crate::handlers::update_pair_route::update_pair_route_handler;
 use crate::handlers::update_swap_adjustment_handler::update_swap_adjustment_handler;
+use crate::handlers::update_swap_adjustments::update_swap_adjustments_handler;
 use crate::handlers::update_vault::update_vault_handler;
-use crate::handlers::z_delegate::{log_delegation_result, z_delegate_handler};
+use crate::handlers::z_delegate::{
+    log_delegation_result, z_delegate_handler, z_delegate_to_validators_handler,
+};
 use crate::handlers::z_provide_liquidity::{
     bond_lp_tokens, log_bond_lp_tokens_result, z_provide_liquidity_handler,
 };
@@ -45,8 +81,8 @@ pub const CONTRACT_NAME: &str = "crates.io:calc-dca";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[entry_point]
-pub fn migrate(deps: DepsMut, _: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
-    migrate_handler(deps, msg)
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_handler(deps, env, msg)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -71,7 +107,17 @@ pub fn execute(
             base_denom,
             quote_denom,
             route,
-        } => create_pair_handler(deps, info, base_denom, quote_denom, route),
+            price_source,
+            circuit_breaker,
+        } => create_pair_handler(
+            deps,
+            info,
+            base_denom,
+            quote_denom,
+            route,
+            price_source,
+            circuit_breaker,
+        ),
         ExecuteMsg::CreateVault {
             owner,
             label,
@@ -85,6 +131,7 @@ pub fn execute(
             target_start_time_utc_seconds,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            funding_strategy,
         } => create_vault_handler(
             deps,
             env,
@@ -101,6 +148,7 @@ pub fn execute(
             target_start_time_utc_seconds,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            funding_strategy,
         ),
         ExecuteMsg::UpdateVault {
             vault_id,
@@ -112,6 +160,7 @@ pub fn execute(
             swap_adjustment_strategy,
         } => update_vault_handler(
             deps,
+            env,
             info,
             vault_id,
             label,
@@ -137,8 +186,11 @@ pub fn execute(
             risk_weighted_average_escrow_level,
             twap_period,
             default_slippage_tolerance,
+            config_change_delay_seconds,
+            performance_fee_percent,
         } => update_config_handler(
             deps,
+            env,
             info,
             executors,
             fee_collectors,
@@ -150,35 +202,47 @@ pub fn execute(
             risk_weighted_average_escrow_level,
             twap_period,
             default_slippage_tolerance,
+            config_change_delay_seconds,
+            performance_fee_percent,
         ),
         ExecuteMsg::CreateCustomSwapFee {
             denom,
             swap_fee_percent,
-        } => create_custom_swap_fee_handler(deps, info, denom, swap_fee_percent),
+        } => create_custom_swap_fee_handler(deps, env, info, denom, swap_fee_percent),
         ExecuteMsg::RemoveCustomSwapFee { denom } => {
-            remove_custom_swap_fee_handler(deps, info, denom)
+            remove_custom_swap_fee_handler(deps, env, info, denom)
         }
         ExecuteMsg::UpdateSwapAdjustment { strategy, value } => {
             update_swap_adjustment_handler(deps, env, info, strategy, value)
         }
+        ExecuteMsg::UpdateSwapAdjustments { adjustments } => {
+            update_swap_adjustments_handler(deps, env, info, adjustments)
+        }
         ExecuteMsg::DisburseEscrow { vault_id } => {
             disburse_escrow_handler(deps, &env, info, vault_id)
         }
         ExecuteMsg::ZDelegate {
             delegator_address,
             validator_address,
-        } => z_delegate_handler(
-            deps.as_ref(),
+        } => z_delegate_handler(deps, env, info, delegator_address, validator_address),
+        ExecuteMsg::ZDelegateToValidators {
+            delegator_address,
+            validators,
+            max_commission_rate,
+        } => z_delegate_to_validators_handler(
+            deps,
             env,
             info,
             delegator_address,
-            validator_address,
+            validators,
+            max_commission_rate,
         ),
         ExecuteMsg::ZProvideLiquidity {
             provider_address,
             pool_id,
             duration,
             slippage_tolerance,
+            superfluid_validator_address,
         } => z_provide_liquidity_handler(
             deps,
             env,
@@ -187,9 +251,55 @@ pub fn execute(
             pool_id,
             duration,
             slippage_tolerance,
+            superfluid_validator_address,
         ),
         ExecuteMsg::CreatePairs { pairs } => create_pairs_handler(deps, info, pairs),
-        ExecuteMsg::FixPositionType { vault_id } => fix_position_type(deps, vault_id),
+        ExecuteMsg::FixPositionType { vault_id } => fix_position_type(deps, info, vault_id),
+        ExecuteMsg::ResetCircuitBreaker {
+            base_denom,
+            quote_denom,
+        } => reset_circuit_breaker_handler(deps, info, base_denom, quote_denom),
+        ExecuteMsg::UpdatePairConfig {
+            base_denom,
+            quote_denom,
+            config,
+        } => update_pair_config_handler(deps, info, base_denom, quote_denom, config),
+        ExecuteMsg::UpdatePairRoute {
+            base_denom,
+            quote_denom,
+            route,
+        } => update_pair_route_handler(deps, env, info, base_denom, quote_denom, route),
+        ExecuteMsg::DeletePair {
+            base_denom,
+            quote_denom,
+        } => delete_pair_handler(deps, info, base_denom, quote_denom),
+        ExecuteMsg::GrantRole { role, address } => grant_role_handler(deps, info, role, address),
+        ExecuteMsg::RevokeRole { role, address } => revoke_role_handler(deps, info, role, address),
+        ExecuteMsg::SetPaused { paused } => set_paused_handler(deps, info, paused),
+        ExecuteMsg::ProposeNewAdmin { address } => {
+            propose_new_admin_handler(deps, env, info, address)
+        }
+        ExecuteMsg::AcceptAdmin {} => accept_admin_handler(deps, env, info),
+        ExecuteMsg::CancelAdminProposal {} => cancel_admin_proposal_handler(deps, info),
+        ExecuteMsg::ExecuteQueuedConfigChange { id } => {
+            execute_queued_config_change_handler(deps, env, info, id)
+        }
+        ExecuteMsg::CancelQueuedConfigChange { id } => {
+            cancel_queued_config_change_handler(deps, info, id)
+        }
+        ExecuteMsg::ContinueMigration { limit } => continue_migration_handler(deps, info, limit),
+        ExecuteMsg::RecoverSurplus { denom, recipient } => {
+            recover_surplus_handler(deps, env, info, denom, recipient)
+        }
+        ExecuteMsg::SetRiskWeightedAverageModel { model } => {
+            set_risk_weighted_average_model_handler(deps, info, model)
+        }
+        ExecuteMsg::RemoveRiskWeightedAverageModel { base_denom } => {
+            remove_risk_weighted_average_model_handler(deps, info, base_denom)
+        }
+        ExecuteMsg::SetSwapAdjustmentAggregation { aggregation } => {
+            set_swap_adjustment_aggregation_handler(deps, info, aggregation)
+        }
     }
 }
 
@@ -198,9 +308,11 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, Contract
     match reply.id {
         AFTER_SWAP_REPLY_ID => disburse_funds_handler(deps, &env, reply),
         AFTER_FAILED_AUTOMATION_REPLY_ID => handle_failed_automation_handler(deps, env, reply),
-        AFTER_DELEGATION_REPLY_ID => log_delegation_result(reply),
+        AFTER_DELEGATION_REPLY_ID => log_delegation_result(deps, reply),
         AFTER_PROVIDE_LIQUIDITY_REPLY_ID => bond_lp_tokens(deps.as_ref(), env),
-        AFTER_BOND_LP_TOKENS_REPLY_ID => log_bond_lp_tokens_result(deps, reply),
+        AFTER_BOND_LP_TOKENS_REPLY_ID => log_bond_lp_tokens_result(deps, env, reply),
+        AFTER_PULL_FUNDS_REPLY_ID => receive_pulled_funds_handler(deps, env, reply),
+        AFTER_CLAIM_STAKING_REWARDS_REPLY_ID => receive_staking_rewards_handler(deps, env, reply),
         id => Err(ContractError::CustomError {
             val: format!("unhandled DCA contract reply id: {}", id),
         }),
@@ -213,6 +325,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetPairs { limit, start_after } => {
             to_binary(&get_pairs_handler(deps, start_after, limit)?)
         }
+        QueryMsg::GetPairsByDenom { denom } => to_binary(&get_pairs_by_denom_handler(deps, denom)?),
         QueryMsg::GetTimeTriggerIds { limit } => {
             to_binary(&get_time_trigger_ids_handler(deps, env, limit)?)
         }
@@ -251,11 +364,64 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         } => to_binary(&get_events_handler(deps, start_after, limit, reverse)?),
         QueryMsg::GetCustomSwapFees {} => to_binary(&get_custom_swap_fees_handler(deps)?),
         QueryMsg::GetConfig {} => to_binary(&get_config_handler(deps)?),
+        QueryMsg::GetPendingAdmin {} => to_binary(&get_pending_admin_handler(deps)?),
+        QueryMsg::GetPendingConfigChanges {} => {
+            to_binary(&get_pending_config_changes_handler(deps)?)
+        }
+        QueryMsg::GetMigrationStatus {} => to_binary(&get_migration_status_handler(deps)?),
+        QueryMsg::GetSolvencyReport {} => to_binary(&get_solvency_report_handler(deps, env)?),
+        QueryMsg::GetRiskWeightedAverageModels {} => {
+            to_binary(&get_risk_weighted_average_models_handler(deps)?)
+        }
+        QueryMsg::GetSwapAdjustment { strategy } => {
+            to_binary(&get_swap_adjustment_handler(deps, env, strategy)?)
+        }
+        QueryMsg::GetSwapAdjustments { start_after, limit } => to_binary(
+            &get_swap_adjustments_handler(deps, env, start_after, limit)?,
+        ),
+        QueryMsg::GetSwapAdjustmentHistory {
+            strategy,
+            start_after,
+            limit,
+            reverse,
+        } => to_binary(&get_swap_adjustment_history_handler(
+            deps,
+            strategy,
+            start_after,
+            limit,
+            reverse,
+        )?),
+        QueryMsg::GetSwapAdjustmentPublishers { strategy } => to_binary(
+            &get_swap_adjustment_publishers_handler(deps, env, strategy)?,
+        ),
         QueryMsg::GetVaultPerformance { vault_id } => {
             to_binary(&get_vault_performance_handler(deps, &env, vault_id)?)
         }
         QueryMsg::GetDisburseEscrowTasks { limit } => {
             to_binary(&get_disburse_escrow_tasks_handler(deps, env, limit)?)
         }
+        QueryMsg::GetEscrowReleases { vault_id, limit } => {
+            to_binary(&get_escrow_releases_handler(deps, vault_id, limit)?)
+        }
+        QueryMsg::GetRoles { address } => to_binary(&get_roles_handler(deps, address)?),
+        QueryMsg::GetRoleMembers { role } => to_binary(&get_role_members_handler(deps, role)?),
+        QueryMsg::GetCircuitBreaker {
+            base_denom,
+            quote_denom,
+        } => to_binary(&get_circuit_breaker_handler(deps, base_denom, quote_denom)?),
+        QueryMsg::GetPriceHistory {
+            base_denom,
+            quote_denom,
+            from,
+            to,
+            resolution,
+        } => to_binary(&get_price_history_handler(
+            deps,
+            base_denom,
+            quote_denom,
+            from,
+            to,
+            resolution,
+        )?),
     }
 }
@@ -0,0 +1,142 @@
+use crate::error::ContractError;
+use crate::state::config::{clear_pending_admin, get_config, get_pending_admin, update_config};
+use crate::types::config::Config;
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+
+pub fn accept_admin_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending_admin = get_pending_admin(deps.storage)?.ok_or(ContractError::CustomError {
+        val: "there is no pending admin proposal".to_string(),
+    })?;
+
+    if info.sender != pending_admin.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if env.block.time > pending_admin.expires_at {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "admin proposal expired at {}",
+                pending_admin.expires_at.seconds()
+            ),
+        });
+    }
+
+    let config = get_config(deps.storage)?;
+
+    update_config(
+        deps.storage,
+        Config {
+            admin: pending_admin.address.clone(),
+            ..config.clone()
+        },
+    )?;
+
+    clear_pending_admin(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("accept_admin", "true")
+        .add_event(
+            Event::new("admin_accepted")
+                .add_attribute("previous_admin", config.admin)
+                .add_attribute("admin", pending_admin.address),
+        ))
+}
+
+#[cfg(test)]
+mod accept_admin_tests {
+    use super::*;
+    use crate::constants::ADMIN_PROPOSAL_EXPIRY_SECONDS;
+    use crate::handlers::propose_new_admin::propose_new_admin_handler;
+    use crate::helpers::validation::assert_sender_is_admin;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn without_pending_admin_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = accept_admin_handler(deps.as_mut(), env, mock_info(USER, &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: there is no pending admin proposal");
+    }
+
+    #[test]
+    fn with_sender_other_than_pending_admin_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        let err = accept_admin_handler(deps.as_mut(), env, mock_info("not-the-new-admin", &[]))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_expired_proposal_fails() {
+        let mut deps = calc_mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        env.block.time = env
+            .block
+            .time
+            .plus_seconds(ADMIN_PROPOSAL_EXPIRY_SECONDS + 1);
+
+        let err = accept_admin_handler(deps.as_mut(), env, mock_info(USER, &[])).unwrap_err();
+
+        assert!(err.to_string().starts_with("Error: admin proposal expired"));
+    }
+
+    #[test]
+    fn transfers_admin() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        accept_admin_handler(deps.as_mut(), env, mock_info(USER, &[])).unwrap();
+
+        assert_eq!(
+            get_config(deps.as_ref().storage).unwrap().admin,
+            Addr::unchecked(USER)
+        );
+        assert!(assert_sender_is_admin(deps.as_mut().storage, Addr::unchecked(ADMIN)).is_err());
+        assert_eq!(get_pending_admin(deps.as_ref().storage).unwrap(), None);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_is_admin;
+use crate::state::config::{clear_pending_admin, get_pending_admin};
+use cosmwasm_std::{DepsMut, Event, MessageInfo, Response};
+
+pub fn cancel_admin_proposal_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    let pending_admin = get_pending_admin(deps.storage)?.ok_or(ContractError::CustomError {
+        val: "there is no pending admin proposal".to_string(),
+    })?;
+
+    clear_pending_admin(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("cancel_admin_proposal", "true")
+        .add_event(
+            Event::new("admin_proposal_cancelled")
+                .add_attribute("proposed_admin", pending_admin.address),
+        ))
+}
+
+#[cfg(test)]
+mod cancel_admin_proposal_tests {
+    use super::*;
+    use crate::handlers::propose_new_admin::propose_new_admin_handler;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn clears_pending_admin() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        cancel_admin_proposal_handler(deps.as_mut(), mock_info(ADMIN, &[])).unwrap();
+
+        assert_eq!(get_pending_admin(deps.as_ref().storage).unwrap(), None);
+    }
+
+    #[test]
+    fn without_pending_admin_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = cancel_admin_proposal_handler(deps.as_mut(), mock_info(ADMIN, &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: there is no pending admin proposal");
+    }
+}
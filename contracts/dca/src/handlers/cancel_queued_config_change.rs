@@ -0,0 +1,119 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_can_manage_config_change,
+    state::config::{get_queued_config_change, remove_queued_config_change},
+};
+use cosmwasm_std::{DepsMut, Event, MessageInfo, Response};
+
+pub fn cancel_queued_config_change_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let queued_change =
+        get_queued_config_change(deps.storage, id)?.ok_or(ContractError::CustomError {
+            val: format!("queued config change {} does not exist", id),
+        })?;
+
+    assert_sender_can_manage_config_change(deps.storage, &info.sender, &queued_change.change)?;
+
+    remove_queued_config_change(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("cancel_queued_config_change", "true")
+        .add_event(
+            Event::new("config_change_cancelled")
+                .add_attribute("id", id.to_string())
+                .add_attribute("change", format!("{:?}", queued_change.change)),
+        ))
+}
+
+#[cfg(test)]
+mod cancel_queued_config_change_tests {
+    use super::*;
+    use crate::{
+        handlers::update_config::update_config_handler,
+        state::config::{get_config, get_queued_config_changes, save_config_change_delay},
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{calc_mock_dependencies, ADMIN, USER},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr,
+    };
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+        save_config_change_delay(deps.as_mut().storage, 3600).unwrap();
+
+        update_config_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Some(vec![Addr::unchecked("executor")]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let err = cancel_queued_config_change_handler(deps.as_mut(), mock_info(USER, &[]), 1)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn removes_queued_change_without_applying_it() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+        save_config_change_delay(deps.as_mut().storage, 3600).unwrap();
+
+        let executors_before = get_config(deps.as_ref().storage).unwrap().executors;
+
+        update_config_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Some(vec![Addr::unchecked("executor")]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        cancel_queued_config_change_handler(deps.as_mut(), mock_info(ADMIN, &[]), 1).unwrap();
+
+        assert!(get_queued_config_changes(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            get_config(deps.as_ref().storage).unwrap().executors,
+            executors_before
+        );
+    }
+}
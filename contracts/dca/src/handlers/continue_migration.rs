@@ -0,0 +1,102 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        migrations::run_migration_batch,
+        validation::{assert_migration_limit_is_valid, assert_sender_is_admin},
+    },
+    state::{config::get_config, migrations::get_migration_progress},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn continue_migration_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    if get_migration_progress(deps.storage)?.is_none() {
+        return Err(ContractError::CustomError {
+            val: "there is no migration in progress".to_string(),
+        });
+    }
+
+    let limit = limit.unwrap_or(get_config(deps.storage)?.default_page_limit);
+
+    assert_migration_limit_is_valid(limit)?;
+
+    let progress = run_migration_batch(deps.storage, limit)?;
+
+    let response = Response::new()
+        .add_attribute("continue_migration", "true")
+        .add_attribute("migration_complete", progress.is_none().to_string());
+
+    Ok(match progress {
+        Some(progress) => response
+            .add_attribute("migration_version", progress.version.to_string())
+            .add_attribute("migration_cursor", format!("{:?}", progress.cursor)),
+        None => response,
+    })
+}
+
+#[cfg(test)]
+mod continue_migration_tests {
+    use super::*;
+    use crate::{
+        constants::LATEST_STORAGE_VERSION,
+        helpers::migrations::start_migration,
+        state::migrations::{get_storage_version, save_storage_version},
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, USER},
+        },
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err =
+            continue_migration_handler(deps.as_mut(), mock_info(USER, &[]), None).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn without_migration_in_progress_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err =
+            continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), None).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: there is no migration in progress");
+    }
+
+    #[test]
+    fn completes_pending_migration() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        save_storage_version(deps.as_mut().storage, 1).unwrap();
+        start_migration(deps.as_mut().storage).unwrap();
+
+        while get_migration_progress(deps.as_ref().storage)
+            .unwrap()
+            .is_some()
+        {
+            continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), None).unwrap();
+        }
+
+        assert_eq!(
+            get_storage_version(deps.as_ref().storage).unwrap(),
+            LATEST_STORAGE_VERSION
+        );
+        assert_eq!(get_migration_progress(deps.as_ref().storage).unwrap(), None);
+    }
+}
@@ -1,22 +1,45 @@
 use crate::{
     error::ContractError,
-    helpers::validation::{assert_denom_exists, assert_fee_level_is_valid, assert_sender_is_admin},
-    state::config::create_custom_fee,
+    helpers::validation::{assert_denom_exists, assert_fee_level_is_valid, assert_sender_has_role},
+    state::config::{create_custom_fee, get_config_change_delay, queue_config_change},
+    types::{config::ConfigChange, role::Role},
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::Response;
-use cosmwasm_std::{Decimal, DepsMut, MessageInfo};
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo};
 
 pub fn create_custom_swap_fee_handler(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     denom: String,
     swap_fee_percent: Decimal,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_sender_has_role(deps.storage, &info.sender, Role::FeeManager)?;
     assert_denom_exists(deps.as_ref().storage, denom.clone())?;
     assert_fee_level_is_valid(&swap_fee_percent)?;
 
+    let delay_seconds = get_config_change_delay(deps.storage)?;
+
+    if delay_seconds > 0 {
+        let queued_change = queue_config_change(
+            deps.storage,
+            ConfigChange::CreateCustomSwapFee {
+                denom: denom.clone(),
+                swap_fee_percent,
+            },
+            env.block.time,
+            env.block.time.plus_seconds(delay_seconds),
+        )?;
+
+        return Ok(Response::new()
+            .add_attribute("create_custom_swap_fee", "queued")
+            .add_attribute("denom", denom)
+            .add_attribute("swap_fee_percent", swap_fee_percent.to_string())
+            .add_attribute("queued_config_change_id", queued_change.id.to_string())
+            .add_attribute("executable_at", queued_change.executable_at.to_string()));
+    }
+
     create_custom_fee(deps.storage, denom.clone(), swap_fee_percent)?;
 
     Ok(Response::new()
@@ -47,7 +70,7 @@ mod create_custom_swap_fee_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
-        instantiate_contract(deps.as_mut(), env, info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let denom = DENOM_UOSMO.to_string();
 
@@ -57,12 +80,21 @@ mod create_custom_swap_fee_tests {
                 base_denom: denom.clone(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                price_source: None,
+                circuit_breaker: None,
+                config: None,
             },
         )
         .unwrap();
 
-        create_custom_swap_fee_handler(deps.as_mut(), info, denom.clone(), Decimal::percent(1))
-            .unwrap();
+        create_custom_swap_fee_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            denom.clone(),
+            Decimal::percent(1),
+        )
+        .unwrap();
 
         let custom_fees = get_custom_swap_fees_handler(deps.as_ref()).unwrap();
 
@@ -75,7 +107,7 @@ mod create_custom_swap_fee_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
-        instantiate_contract(deps.as_mut(), env, info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let denom = DENOM_UOSMO.to_string();
 
@@ -85,12 +117,16 @@ mod create_custom_swap_fee_tests {
                 base_denom: denom.clone(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                price_source: None,
+                circuit_breaker: None,
+                config: None,
             },
         )
         .unwrap();
 
         create_custom_swap_fee_handler(
             deps.as_mut(),
+            env.clone(),
             info.clone(),
             denom.clone(),
             Decimal::percent(1),
@@ -102,8 +138,14 @@ mod create_custom_swap_fee_tests {
         assert_eq!(custom_fees.len(), 1);
         assert_eq!(custom_fees[0], (denom.clone(), Decimal::percent(1)));
 
-        create_custom_swap_fee_handler(deps.as_mut(), info, denom.clone(), Decimal::percent(3))
-            .unwrap();
+        create_custom_swap_fee_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            denom.clone(),
+            Decimal::percent(3),
+        )
+        .unwrap();
 
         let custom_fees = get_custom_swap_fees_handler(deps.as_ref()).unwrap();
 
@@ -116,7 +158,7 @@ mod create_custom_swap_fee_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
-        instantiate_contract(deps.as_mut(), env, info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let denom = DENOM_UOSMO.to_string();
 
@@ -126,13 +168,21 @@ mod create_custom_swap_fee_tests {
                 base_denom: denom.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                price_source: None,
+                circuit_breaker: None,
+                config: None,
             },
         )
         .unwrap();
 
-        let response =
-            create_custom_swap_fee_handler(deps.as_mut(), info, denom, Decimal::percent(6))
-                .unwrap_err();
+        let response = create_custom_swap_fee_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            denom,
+            Decimal::percent(6),
+        )
+        .unwrap_err();
 
         assert_eq!(
             response.to_string(),
@@ -145,7 +195,7 @@ mod create_custom_swap_fee_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
-        instantiate_contract(deps.as_mut(), env, info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let denom = DENOM_UOSMO.to_string();
 
@@ -155,13 +205,21 @@ mod create_custom_swap_fee_tests {
                 base_denom: DENOM_UATOM.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                price_source: None,
+                circuit_breaker: None,
+                config: None,
             },
         )
         .unwrap();
 
-        let response =
-            create_custom_swap_fee_handler(deps.as_mut(), info, denom, Decimal::percent(2))
-                .unwrap_err();
+        let response = create_custom_swap_fee_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            denom,
+            Decimal::percent(2),
+        )
+        .unwrap_err();
 
         assert_eq!(response.to_string(), "Error: uosmo is not supported");
     }
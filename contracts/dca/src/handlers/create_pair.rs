@@ -1,8 +1,12 @@
 use crate::helpers::validation::{
+    assert_circuit_breaker_is_valid, assert_pair_does_not_exist, assert_price_source_is_valid,
     assert_route_has_no_duplicate_entries, assert_route_matches_denoms, assert_route_not_empty,
-    assert_sender_is_admin,
+    assert_sender_has_role,
 };
 use crate::state::pairs::save_pair;
+use crate::types::circuit_breaker::CircuitBreaker;
+use crate::types::price_source::PriceSource;
+use crate::types::role::Role;
 use crate::{error::ContractError, types::pair::Pair};
 use cosmwasm_std::DepsMut;
 #[cfg(not(feature = "library"))]
@@ -14,8 +18,10 @@ pub fn create_pair_handler(
     base_denom: String,
     quote_denom: String,
     route: Vec<u64>,
+    price_source: Option<PriceSource>,
+    circuit_breaker: Option<CircuitBreaker>,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
     assert_route_not_empty(route.clone())?;
     assert_route_has_no_duplicate_entries(route.clone())?;
 
@@ -23,9 +29,15 @@ pub fn create_pair_handler(
         base_denom: base_denom.clone(),
         quote_denom: quote_denom.clone(),
         route: route.clone(),
+        price_source,
+        circuit_breaker,
+        config: None,
     };
 
+    assert_pair_does_not_exist(deps.storage, &pair)?;
     assert_route_matches_denoms(&deps.querier, &pair)?;
+    assert_price_source_is_valid(deps.as_ref(), &pair)?;
+    assert_circuit_breaker_is_valid(&pair)?;
 
     save_pair(deps.storage, &pair)?;
 
@@ -45,10 +57,39 @@ mod create_pair_tests {
         state::pairs::find_pair,
         tests::{
             helpers::instantiate_contract,
-            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO},
+            mocks::{calc_mock_dependencies, mock_oracle, ADMIN, DENOM_STAKE, DENOM_UOSMO, ORACLE},
         },
+        types::{circuit_breaker::CircuitBreaker, price_source::PriceSource},
     };
     use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Decimal};
+
+    #[test]
+    fn with_invalid_circuit_breaker_max_deviation_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let create_pair_execute_message = ExecuteMsg::CreatePair {
+            base_denom: DENOM_STAKE.to_string(),
+            quote_denom: DENOM_UOSMO.to_string(),
+            route: vec![3],
+            price_source: None,
+            circuit_breaker: Some(CircuitBreaker {
+                max_deviation: Decimal::zero(),
+                auto_pause: true,
+            }),
+        };
+
+        let err = execute(deps.as_mut(), env, info, create_pair_execute_message).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: circuit breaker max deviation must be greater than 0 and no more than 1"
+        )
+    }
 
     #[test]
     fn with_duplicate_route_entries_fails() {
@@ -62,6 +103,8 @@ mod create_pair_tests {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![4, 1, 4, 1],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         let err = execute(deps.as_mut(), env, info, create_pair_execute_message).unwrap_err();
@@ -84,6 +127,8 @@ mod create_pair_tests {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         execute(deps.as_mut(), env, info, create_pair_execute_message).unwrap();
@@ -96,7 +141,7 @@ mod create_pair_tests {
     }
 
     #[test]
-    fn create_pair_that_already_exists_should_update_it() {
+    fn create_pair_that_already_exists_should_fail() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -107,26 +152,34 @@ mod create_pair_tests {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![4, 1],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         let message = ExecuteMsg::CreatePair {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         execute(deps.as_mut(), env.clone(), info.clone(), original_message).unwrap();
 
-        let denoms = [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()];
-
-        let original_pair = find_pair(deps.as_ref().storage, denoms.clone()).unwrap();
+        let err = execute(deps.as_mut(), env, info, message).unwrap_err();
 
-        execute(deps.as_mut(), env, info, message).unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Error: pair for uosmo and stake already exists, use UpdatePairRoute to change its route"
+        );
 
-        let pair = find_pair(deps.as_ref().storage, denoms).unwrap();
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
 
-        assert_eq!(original_pair.route, vec![4, 1]);
-        assert_eq!(pair.route, vec![3]);
+        assert_eq!(pair.route, vec![4, 1]);
     }
 
     #[test]
@@ -143,6 +196,8 @@ mod create_pair_tests {
             base_denom: String::from("base"),
             quote_denom: String::from("quote"),
             route: vec![0],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         let result = execute(
@@ -172,6 +227,8 @@ mod create_pair_tests {
                 base_denom: DENOM_UOSMO.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![],
+                price_source: None,
+                circuit_breaker: None,
             },
         )
         .unwrap_err();
@@ -195,6 +252,8 @@ mod create_pair_tests {
                 base_denom: DENOM_UOSMO.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![2],
+                price_source: None,
+                circuit_breaker: None,
             },
         )
         .unwrap_err();
@@ -206,7 +265,7 @@ mod create_pair_tests {
     }
 
     #[test]
-    fn recreate_pair_with_switched_denoms_should_overwrite_it() {
+    fn recreate_pair_with_switched_denoms_should_fail() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -217,25 +276,104 @@ mod create_pair_tests {
             quote_denom: DENOM_UOSMO.to_string(),
             base_denom: DENOM_STAKE.to_string(),
             route: vec![1, 4],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         let message = ExecuteMsg::CreatePair {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            price_source: None,
+            circuit_breaker: None,
         };
 
         execute(deps.as_mut(), env.clone(), info.clone(), original_message).unwrap();
 
-        let denoms = [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()];
+        let err = execute(deps.as_mut(), env, info, message).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: pair for uosmo and stake already exists, use UpdatePairRoute to change its route"
+        );
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
 
-        let original_pair = find_pair(deps.as_ref().storage, denoms.clone()).unwrap();
+        assert_eq!(pair.route, vec![1, 4]);
+        assert_eq!(pair.base_denom, DENOM_STAKE);
+    }
 
-        execute(deps.as_mut(), env, info, message).unwrap();
+    #[test]
+    fn create_pair_with_oracle_price_source_should_save_price_source() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
 
-        let pair = find_pair(deps.as_ref().storage, denoms).unwrap();
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        assert_eq!(original_pair.route, vec![1, 4]);
-        assert_eq!(pair.route, vec![3]);
+        deps.querier.update_wasm(mock_oracle(Decimal::one()));
+
+        execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                price_source: Some(PriceSource::Oracle {
+                    contract_address: Addr::unchecked(ORACLE),
+                }),
+                circuit_breaker: None,
+            },
+        )
+        .unwrap();
+
+        let pair = find_pair(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            pair.price_source,
+            Some(PriceSource::Oracle {
+                contract_address: Addr::unchecked(ORACLE),
+            })
+        );
+    }
+
+    #[test]
+    fn create_pair_with_unresponsive_oracle_price_source_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let result = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::CreatePair {
+                base_denom: DENOM_UOSMO.to_string(),
+                quote_denom: DENOM_STAKE.to_string(),
+                route: vec![3],
+                price_source: Some(PriceSource::Oracle {
+                    contract_address: Addr::unchecked("not-an-oracle"),
+                }),
+                circuit_breaker: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            result.to_string(),
+            "Error: oracle not-an-oracle did not return a price for uosmo in stake"
+        )
     }
 }
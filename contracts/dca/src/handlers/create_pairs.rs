@@ -1,11 +1,12 @@
 use crate::{
     error::ContractError,
     helpers::validation::{
-        assert_route_has_no_duplicate_entries, assert_route_matches_denoms, assert_route_not_empty,
-        assert_sender_is_admin,
+        assert_circuit_breaker_is_valid, assert_pair_config_is_valid, assert_pair_does_not_exist,
+        assert_price_source_is_valid, assert_route_has_no_duplicate_entries,
+        assert_route_matches_denoms, assert_route_not_empty, assert_sender_has_role,
     },
     state::pairs::save_pair,
-    types::pair::Pair,
+    types::{pair::Pair, role::Role},
 };
 use cosmwasm_std::{DepsMut, MessageInfo, Response};
 
@@ -15,11 +16,18 @@ pub fn create_pairs_handler(
     pairs: Vec<Pair>,
 ) -> Result<Response, ContractError> {
     for pair in pairs.clone() {
-        assert_sender_is_admin(deps.storage, info.sender.clone())?;
+        assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
         assert_route_not_empty(pair.route.clone())?;
         assert_route_has_no_duplicate_entries(pair.route.clone())?;
+        assert_pair_does_not_exist(deps.storage, &pair)?;
 
         assert_route_matches_denoms(&deps.querier, &pair)?;
+        assert_price_source_is_valid(deps.as_ref(), &pair)?;
+        assert_circuit_breaker_is_valid(&pair)?;
+
+        if let Some(config) = &pair.config {
+            assert_pair_config_is_valid(config)?;
+        }
 
         save_pair(deps.storage, &pair)?;
     }
@@ -1,27 +1,31 @@
 use crate::error::ContractError;
 use crate::helpers::validation::{
-    assert_address_is_valid, assert_contract_destination_callbacks_are_valid,
-    assert_contract_is_not_paused, assert_destination_allocations_add_up_to_one,
-    assert_destination_callback_addresses_are_valid, assert_destinations_limit_is_not_breached,
-    assert_exactly_one_asset, assert_label_is_no_longer_than_100_characters,
-    assert_no_destination_allocations_are_zero, assert_pair_exists_for_denoms,
+    assert_address_is_valid, assert_contract_is_not_paused, assert_destination_actions_are_valid,
+    assert_destination_allocations_add_up_to_one, assert_destination_callback_addresses_are_valid,
+    assert_destinations_limit_is_not_breached, assert_funding_strategy_is_valid,
+    assert_label_is_no_longer_than_100_characters, assert_no_destination_allocations_are_zero,
+    assert_pair_exists_for_denoms, assert_pair_is_enabled,
     assert_slippage_tolerance_is_less_than_or_equal_to_one,
+    assert_slippage_tolerance_is_within_pair_maximum,
     assert_swap_adjusment_and_performance_assessment_strategies_are_compatible,
-    assert_swap_adjustment_strategy_params_are_valid, assert_swap_amount_is_greater_than_50000,
+    assert_swap_adjustment_strategy_params_are_valid, assert_swap_amount_meets_pair_minimum,
     assert_target_start_time_is_not_in_the_past, assert_time_interval_is_valid,
 };
 use crate::helpers::vault::get_risk_weighted_average_model_id;
 use crate::msg::ExecuteMsg;
 use crate::state::cache::VAULT_CACHE;
-use crate::state::config::get_config;
+use crate::state::config::{get_config, get_performance_fee_rate};
 use crate::state::events::create_event;
 use crate::state::pairs::find_pair;
+use crate::state::risk_weighted_average_models::get_risk_weighted_average_model;
 use crate::state::triggers::save_trigger;
 use crate::state::vaults::save_vault;
-use crate::types::destination::Destination;
+use crate::types::destination::{Destination, DestinationAction};
 use crate::types::event::{EventBuilder, EventData};
+use crate::types::funding_strategy::{FundingStrategy, FundingStrategyParams};
 use crate::types::performance_assessment_strategy::{
-    PerformanceAssessmentStrategy, PerformanceAssessmentStrategyParams,
+    PerformanceAssessmentStrategy, PerformanceAssessmentStrategyParams, PerformanceBenchmark,
+    PerformanceFee,
 };
 use crate::types::position_type::PositionType;
 use crate::types::swap_adjustment_strategy::{
@@ -50,18 +54,42 @@ pub fn create_vault_handler(
     target_start_time_utc_seconds: Option<Uint64>,
     performance_assessment_strategy_params: Option<PerformanceAssessmentStrategyParams>,
     swap_adjustment_strategy_params: Option<SwapAdjustmentStrategyParams>,
+    funding_strategy_params: Option<FundingStrategyParams>,
 ) -> Result<Response, ContractError> {
     assert_contract_is_not_paused(deps.storage)?;
     assert_address_is_valid(deps.as_ref(), &owner, "owner")?;
-    assert_exactly_one_asset(info.funds.clone())?;
-    assert_swap_amount_is_greater_than_50000(swap_amount)?;
+    assert_funding_strategy_is_valid(
+        &info.sender,
+        &owner,
+        &info.funds,
+        &funding_strategy_params,
+        &swap_adjustment_strategy_params,
+    )?;
+
+    let (deposit, funding_strategy) = match funding_strategy_params {
+        Some(FundingStrategyParams::AuthzPull { swap_denom }) => (
+            Coin::new(0, swap_denom),
+            FundingStrategy::AuthzPull {
+                consecutive_failures: 0,
+            },
+        ),
+        Some(FundingStrategyParams::StakingRewards {}) => (
+            Coin::new(0, deps.querier.query_bonded_denom()?),
+            FundingStrategy::StakingRewards {
+                consecutive_failures: 0,
+            },
+        ),
+        _ => (info.funds[0].clone(), FundingStrategy::Deposit),
+    };
+
+    assert_swap_amount_meets_pair_minimum(
+        deps.storage,
+        swap_amount,
+        [deposit.denom.clone(), target_denom.clone()],
+    )?;
     assert_destinations_limit_is_not_breached(&destinations)?;
     assert_time_interval_is_valid(&time_interval)?;
-    assert_pair_exists_for_denoms(
-        deps.as_ref(),
-        info.funds[0].denom.clone(),
-        target_denom.clone(),
-    )?;
+    assert_pair_exists_for_denoms(deps.as_ref(), deposit.denom.clone(), target_denom.clone())?;
     assert_swap_adjusment_and_performance_assessment_strategies_are_compatible(
         &swap_adjustment_strategy_params,
         &performance_assessment_strategy_params,
@@ -72,7 +100,10 @@ pub fn create_vault_handler(
     }
 
     if let Some(swap_adjustment_strategy_params) = &swap_adjustment_strategy_params {
-        assert_swap_adjustment_strategy_params_are_valid(swap_adjustment_strategy_params)?;
+        assert_swap_adjustment_strategy_params_are_valid(
+            deps.storage,
+            swap_adjustment_strategy_params,
+        )?;
     }
 
     if let Some(slippage_tolerance) = slippage_tolerance {
@@ -90,53 +121,158 @@ pub fn create_vault_handler(
         destinations.push(Destination {
             allocation: Decimal::percent(100),
             address: owner.clone(),
-            msg: None,
+            action: DestinationAction::Send,
         });
     }
 
     assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
-    assert_contract_destination_callbacks_are_valid(&destinations, &env.contract.address)?;
+    assert_destination_actions_are_valid(
+        deps.as_ref(),
+        &env.contract.address,
+        &destinations,
+        target_denom.clone(),
+    )?;
     assert_no_destination_allocations_are_zero(&destinations)?;
     assert_destination_allocations_add_up_to_one(&destinations)?;
 
     let config = get_config(deps.storage)?;
 
-    let swap_denom = info.funds[0].denom.clone();
+    let swap_denom = deposit.denom.clone();
 
     let pair = find_pair(deps.storage, [swap_denom.clone(), target_denom.clone()])?;
 
-    let swap_adjustment_strategy = swap_adjustment_strategy_params.map(|params| match params {
-        SwapAdjustmentStrategyParams::RiskWeightedAverage { base_denom } => {
-            SwapAdjustmentStrategy::RiskWeightedAverage {
+    assert_pair_is_enabled(&pair)?;
+
+    let slippage_tolerance = match slippage_tolerance {
+        Some(slippage_tolerance) => {
+            assert_slippage_tolerance_is_within_pair_maximum(&pair, slippage_tolerance)?;
+            slippage_tolerance
+        }
+        None => pair.capped_slippage_tolerance(config.default_slippage_tolerance),
+    };
+
+    let swap_adjustment_strategy = match swap_adjustment_strategy_params {
+        Some(SwapAdjustmentStrategyParams::RiskWeightedAverage { base_denom }) => {
+            let model = get_risk_weighted_average_model(deps.storage, &base_denom)?.ok_or(
+                ContractError::CustomError {
+                    val: format!(
+                        "no risk weighted average model is registered for {:?}",
+                        base_denom
+                    ),
+                },
+            )?;
+
+            Some(SwapAdjustmentStrategy::RiskWeightedAverage {
                 model_id: get_risk_weighted_average_model_id(
+                    &model,
                     &env.block.time,
-                    &info.funds[0],
+                    &deposit,
                     &swap_amount,
                     &time_interval,
                 ),
                 base_denom,
                 position_type: pair.position_type(swap_denom.clone()),
-            }
+            })
         }
-        SwapAdjustmentStrategyParams::WeightedScale {
+        Some(SwapAdjustmentStrategyParams::WeightedScale {
             base_receive_amount,
             multiplier,
             increase_only,
-        } => SwapAdjustmentStrategy::WeightedScale {
+            curve,
+            min_swap_amount,
+            max_swap_amount,
+        }) => Some(SwapAdjustmentStrategy::WeightedScale {
             base_receive_amount,
             multiplier,
             increase_only,
-        },
-    });
+            curve,
+            min_swap_amount,
+            max_swap_amount,
+        }),
+        Some(SwapAdjustmentStrategyParams::MovingAverage {
+            lookback,
+            resolution,
+            sensitivity,
+            min_adjustment,
+            max_adjustment,
+        }) => Some(SwapAdjustmentStrategy::MovingAverage {
+            lookback,
+            resolution,
+            sensitivity,
+            min_adjustment,
+            max_adjustment,
+        }),
+        Some(SwapAdjustmentStrategyParams::ValueAveraging {
+            target_increment,
+            max_swap_multiple,
+            allow_sells,
+        }) => Some(SwapAdjustmentStrategy::ValueAveraging {
+            target_increment,
+            max_swap_multiple,
+            allow_sells,
+        }),
+        Some(SwapAdjustmentStrategyParams::VolatilityScaled {
+            window_seconds,
+            windows,
+            target_volatility,
+            min_adjustment,
+            max_adjustment,
+        }) => Some(SwapAdjustmentStrategy::VolatilityScaled {
+            window_seconds,
+            windows,
+            target_volatility,
+            min_adjustment,
+            max_adjustment,
+        }),
+        None => None,
+    };
+
+    let performance_fee_rate = get_performance_fee_rate(deps.storage)?;
 
     let performance_assessment_strategy = match performance_assessment_strategy_params {
         Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => {
             Some(PerformanceAssessmentStrategy::CompareToStandardDca {
                 swapped_amount: Coin::new(0, swap_denom.clone()),
                 received_amount: Coin::new(0, target_denom.clone()),
+                performance_fee: Some(PerformanceFee {
+                    rate: performance_fee_rate,
+                    high_water_mark: None,
+                    settled_added_value: None,
+                }),
+            })
+        }
+        Some(PerformanceAssessmentStrategyParams::CompareToBenchmark {
+            benchmark,
+            high_water_mark,
+        }) => {
+            let performance_fee = PerformanceFee {
+                rate: performance_fee_rate,
+                high_water_mark: if high_water_mark {
+                    Some(Uint128::zero())
+                } else {
+                    None
+                },
+                settled_added_value: None,
+            };
+
+            Some(match benchmark {
+                PerformanceBenchmark::StandardDca => {
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(0, swap_denom.clone()),
+                        received_amount: Coin::new(0, target_denom.clone()),
+                        performance_fee: Some(performance_fee),
+                    }
+                }
+                PerformanceBenchmark::LumpSum => PerformanceAssessmentStrategy::CompareToLumpSum {
+                    start_price: None,
+                    performance_fee,
+                },
+                PerformanceBenchmark::HoldSwapDenom => {
+                    PerformanceAssessmentStrategy::CompareToHoldingSwapDenom { performance_fee }
+                }
             })
         }
-        _ => None,
+        None => None,
     };
 
     let escrow_level = performance_assessment_strategy
@@ -154,34 +290,35 @@ pub fn create_vault_handler(
         target_denom: target_denom.clone(),
         swap_amount,
         position_type,
-        slippage_tolerance: slippage_tolerance.unwrap_or(config.default_slippage_tolerance),
+        slippage_tolerance,
         minimum_receive_amount,
-        balance: info.funds[0].clone(),
+        balance: deposit.clone(),
         time_interval,
         started_at: None,
         escrow_level,
-        deposited_amount: info.funds[0].clone(),
+        deposited_amount: deposit.clone(),
         swapped_amount: Coin::new(0, swap_denom),
         received_amount: Coin::new(0, target_denom.clone()),
         escrowed_amount: Coin::new(0, target_denom),
         swap_adjustment_strategy,
         performance_assessment_strategy,
+        funding_strategy,
     };
 
     let vault = save_vault(deps.storage, vault_builder)?;
 
     VAULT_CACHE.save(deps.storage, &vault.id)?;
 
-    create_event(
-        deps.storage,
-        EventBuilder::new(
-            vault.id,
-            env.block.clone(),
-            EventData::DcaVaultFundsDeposited {
-                amount: info.funds[0].clone(),
-            },
-        ),
-    )?;
+    if !deposit.amount.is_zero() {
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                vault.id,
+                env.block.clone(),
+                EventData::DcaVaultFundsDeposited { amount: deposit },
+            ),
+        )?;
+    }
 
     let mut response = Response::new()
         .add_attribute("create_vault", "true")
@@ -218,20 +355,28 @@ pub fn create_vault_handler(
 #[cfg(test)]
 mod create_vault_tests {
     use super::*;
+    use crate::constants::{
+        MAX_MOVING_AVERAGE_RESOLUTION_SECONDS, PRICE_HISTORY_CAPACITY,
+        TWAP_HISTORY_RETENTION_SECONDS,
+    };
     use crate::handlers::create_pair::create_pair_handler;
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
     use crate::handlers::get_vault::get_vault_handler;
     use crate::msg::ExecuteMsg;
-    use crate::state::config::{get_config, update_config};
-    use crate::tests::helpers::instantiate_contract;
+    use crate::state::config::{get_config, save_performance_fee_rate, update_config};
+    use crate::state::pairs::save_pair;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
     use crate::tests::mocks::{
         calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, USER, VALIDATOR,
     };
     use crate::types::config::Config;
-    use crate::types::destination::Destination;
+    use crate::types::destination::{Destination, WeightedValidator};
     use crate::types::event::{EventBuilder, EventData};
-    use crate::types::pair::Pair;
-    use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+    use crate::types::lockable_duration::LockableDuration;
+    use crate::types::pair::{Pair, PairConfig};
+    use crate::types::swap_adjustment_strategy::{
+        SwapAdjustmentStrategy, WeightedScaleCurve, WeightedScaleTier,
+    };
     use crate::types::time_interval::TimeInterval;
     use crate::types::trigger::TriggerConfiguration;
     use crate::types::vault::{Vault, VaultStatus};
@@ -262,6 +407,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -298,6 +444,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -331,6 +478,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -356,6 +504,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -370,7 +520,7 @@ mod create_vault_tests {
             vec![Destination {
                 allocation: Decimal::percent(50),
                 address: Addr::unchecked(USER),
-                msg: None,
+                action: DestinationAction::Send,
             }],
             DENOM_UOSMO.to_string(),
             None,
@@ -381,6 +531,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -406,6 +557,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -421,12 +574,12 @@ mod create_vault_tests {
                 Destination {
                     allocation: Decimal::percent(100),
                     address: Addr::unchecked(USER),
-                    msg: None,
+                    action: DestinationAction::Send,
                 },
                 Destination {
                     allocation: Decimal::percent(0),
                     address: Addr::unchecked("other"),
-                    msg: None,
+                    action: DestinationAction::Send,
                 },
             ],
             DENOM_UOSMO.to_string(),
@@ -438,6 +591,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -462,11 +616,10 @@ mod create_vault_tests {
             info.sender.clone(),
             None,
             (0..20)
-                .into_iter()
                 .map(|i| Destination {
                     allocation: Decimal::percent(5),
                     address: Addr::unchecked(format!("destination-{}", i)),
-                    msg: None,
+                    action: DestinationAction::Send,
                 })
                 .collect(),
             DENOM_UOSMO.to_string(),
@@ -478,6 +631,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -511,6 +665,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -536,6 +691,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -560,7 +717,11 @@ mod create_vault_tests {
                 base_receive_amount: Uint128::new(100000),
                 multiplier: Decimal::percent(1100),
                 increase_only: false,
+                curve: None,
+                min_swap_amount: None,
+                max_swap_amount: None,
             }),
+            None,
         )
         .unwrap_err();
 
@@ -570,6 +731,73 @@ mod create_vault_tests {
         );
     }
 
+    #[test]
+    fn with_descending_weighted_scale_tier_prices_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            admin_info,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(100000),
+                multiplier: Decimal::percent(150),
+                increase_only: false,
+                curve: Some(WeightedScaleCurve::Stepped {
+                    tiers: vec![
+                        WeightedScaleTier {
+                            price: Decimal::percent(120),
+                            adjustment: Decimal::percent(50),
+                        },
+                        WeightedScaleTier {
+                            price: Decimal::percent(80),
+                            adjustment: Decimal::percent(200),
+                        },
+                    ],
+                }),
+                min_swap_amount: None,
+                max_swap_amount: None,
+            }),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: weighted scale tier prices must be strictly increasing"
+        );
+    }
+
     #[test]
     fn when_contract_is_paused_fails() {
         let mut deps = calc_mock_dependencies();
@@ -605,6 +833,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -627,6 +856,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -648,6 +879,7 @@ mod create_vault_tests {
             Some(env.block.time.minus_seconds(10).seconds().into()),
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -681,6 +913,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -706,6 +939,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -728,6 +963,7 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             None,
+            None,
         )
         .unwrap_err();
 
@@ -753,6 +989,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -775,6 +1013,7 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
         )
         .unwrap_err();
 
@@ -800,6 +1039,8 @@ mod create_vault_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -822,6 +1063,7 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -832,30 +1074,16 @@ mod create_vault_tests {
     }
 
     #[test]
-    fn should_create_vault() {
+    fn with_authz_pull_funding_and_assets_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        let pair = Pair::default();
-
-        create_pair_handler(
-            deps.as_mut(),
-            info.clone(),
-            pair.base_denom.clone(),
-            pair.quote_denom.clone(),
-            pair.route,
-        )
-        .unwrap();
-
-        let swap_amount = Uint128::new(100000);
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
-
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
-            env.clone(),
+            env,
             &info,
             info.sender.clone(),
             None,
@@ -864,74 +1092,34 @@ mod create_vault_tests {
             None,
             None,
             None,
-            swap_amount,
+            Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            None,
+            Some(FundingStrategyParams::AuthzPull {
+                swap_denom: DENOM_STAKE.to_string(),
+            }),
         )
-        .unwrap();
-
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
-
-        let config = get_config(deps.as_ref().storage).unwrap();
+        .unwrap_err();
 
         assert_eq!(
-            vault,
-            Vault {
-                minimum_receive_amount: None,
-                label: None,
-                id: Uint128::new(1),
-                owner: info.sender,
-                destinations: vec![Destination::default()],
-                created_at: env.block.time,
-                status: VaultStatus::Scheduled,
-                time_interval: TimeInterval::Daily,
-                balance: info.funds[0].clone(),
-                slippage_tolerance: config.default_slippage_tolerance,
-                swap_amount,
-                target_denom: DENOM_UOSMO.to_string(),
-                started_at: None,
-                deposited_amount: info.funds[0].clone(),
-                escrow_level: Decimal::zero(),
-                swapped_amount: Coin::new(0, DENOM_STAKE.to_string()),
-                received_amount: Coin::new(0, DENOM_UOSMO.to_string()),
-                escrowed_amount: Coin::new(0, DENOM_UOSMO.to_string()),
-                swap_adjustment_strategy: None,
-                performance_assessment_strategy: None,
-                trigger: Some(TriggerConfiguration::Time {
-                    target_time: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
-                }),
-            }
+            err.to_string(),
+            "Error: vaults funded via authz pull cannot receive funds on creation"
         );
     }
 
     #[test]
-    fn should_publish_deposit_event() {
+    fn with_authz_pull_funding_and_swap_adjustment_strategy_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        let pair = Pair::default();
-
-        create_pair_handler(
-            deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
-            pair.route,
-        )
-        .unwrap();
-
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
-
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
-            env.clone(),
+            env,
             &info,
             info.sender.clone(),
             None,
@@ -942,56 +1130,41 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(100000),
+                multiplier: Decimal::percent(200),
+                increase_only: false,
+                curve: None,
+                min_swap_amount: None,
+                max_swap_amount: None,
+            }),
+            Some(FundingStrategyParams::AuthzPull {
+                swap_denom: DENOM_STAKE.to_string(),
+            }),
         )
-        .unwrap();
-
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
-                .unwrap()
-                .events;
+        .unwrap_err();
 
-        assert!(events.contains(
-            &EventBuilder::new(
-                Uint128::one(),
-                env.block,
-                EventData::DcaVaultFundsDeposited {
-                    amount: info.funds[0].clone()
-                },
-            )
-            .build(1),
-        ))
+        assert_eq!(
+            err.to_string(),
+            "Error: vaults funded via authz pull cannot use a swap adjustment strategy"
+        );
     }
 
     #[test]
-    fn for_different_owner_should_succeed() {
+    fn with_authz_pull_funding_for_another_owner_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        let pair = Pair::default();
-
-        create_pair_handler(
-            deps.as_mut(),
-            info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
-            pair.route,
-        )
-        .unwrap();
-
-        let owner = Addr::unchecked(USER);
-        info = mock_info(ADMIN, &[Coin::new(100000, DENOM_STAKE)]);
-
-        create_vault_handler(
+        let err = create_vault_handler(
             deps.as_mut(),
-            env.clone(),
+            env,
             &info,
-            owner,
+            Addr::unchecked("victim"),
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -1000,24 +1173,23 @@ mod create_vault_tests {
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            None,
+            Some(FundingStrategyParams::AuthzPull {
+                swap_denom: DENOM_STAKE.to_string(),
+            }),
         )
-        .unwrap();
+        .unwrap_err();
 
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
-
-        assert_eq!(vault.owner, Addr::unchecked(USER));
+        assert_eq!(err.to_string(), "Unauthorized");
     }
 
     #[test]
-    fn with_multiple_destinations_should_succeed() {
+    fn with_authz_pull_funding_should_create_empty_vault() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
@@ -1025,39 +1197,16 @@ mod create_vault_tests {
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
+            info,
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
-
-        let destinations = vec![
-            Destination {
-                allocation: Decimal::percent(50),
-                address: env.contract.address.clone(),
-                msg: Some(
-                    to_binary(&ExecuteMsg::ZDelegate {
-                        delegator_address: Addr::unchecked("dest-1"),
-                        validator_address: Addr::unchecked(VALIDATOR),
-                    })
-                    .unwrap(),
-                ),
-            },
-            Destination {
-                allocation: Decimal::percent(50),
-                address: env.contract.address.clone(),
-                msg: Some(
-                    to_binary(&ExecuteMsg::ZDelegate {
-                        delegator_address: Addr::unchecked("dest-2"),
-                        validator_address: Addr::unchecked(VALIDATOR),
-                    })
-                    .unwrap(),
-                ),
-            },
-        ];
+        let info = mock_info(USER, &[]);
 
         create_vault_handler(
             deps.as_mut(),
@@ -1065,7 +1214,7 @@ mod create_vault_tests {
             &info,
             info.sender.clone(),
             None,
-            destinations.clone(),
+            vec![],
             DENOM_UOSMO.to_string(),
             None,
             None,
@@ -1075,6 +1224,9 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            Some(FundingStrategyParams::AuthzPull {
+                swap_denom: DENOM_STAKE.to_string(),
+            }),
         )
         .unwrap();
 
@@ -1082,14 +1234,58 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
-        assert_eq!(vault.destinations, destinations);
+        assert_eq!(vault.balance, Coin::new(0, DENOM_STAKE));
+        assert_eq!(vault.deposited_amount, Coin::new(0, DENOM_STAKE));
+        assert_eq!(
+            vault.funding_strategy,
+            FundingStrategy::AuthzPull {
+                consecutive_failures: 0
+            }
+        );
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.is_empty());
     }
 
     #[test]
-    fn should_create_swap_adjustment_strategy() {
+    fn with_staking_rewards_funding_for_another_owner_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let mut info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            Addr::unchecked("victim"),
+            None,
+            vec![],
+            DENOM_STAKE.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            Some(FundingStrategyParams::StakingRewards {}),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_staking_rewards_funding_should_create_empty_bond_denom_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
@@ -1097,14 +1293,16 @@ mod create_vault_tests {
 
         create_pair_handler(
             deps.as_mut(),
-            info.clone(),
+            info,
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+        let info = mock_info(USER, &[]);
 
         create_vault_handler(
             deps.as_mut(),
@@ -1113,15 +1311,16 @@ mod create_vault_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UOSMO.to_string(),
+            DENOM_STAKE.to_string(),
             None,
             None,
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            Some(FundingStrategyParams::StakingRewards {}),
         )
         .unwrap();
 
@@ -1129,14 +1328,17 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
+        assert_eq!(vault.balance, Coin::new(0, DENOM_UOSMO));
         assert_eq!(
-            vault.swap_adjustment_strategy,
-            Some(SwapAdjustmentStrategy::default())
+            vault.funding_strategy,
+            FundingStrategy::StakingRewards {
+                consecutive_failures: 0
+            }
         );
     }
 
     #[test]
-    fn should_save_no_performance_assessment_strategy_when_none_provided() {
+    fn should_create_vault() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1148,12 +1350,15 @@ mod create_vault_tests {
         create_pair_handler(
             deps.as_mut(),
             info.clone(),
-            pair.base_denom,
-            pair.quote_denom,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
+        let swap_amount = Uint128::new(100000);
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
         create_vault_handler(
@@ -1167,15 +1372,12 @@ mod create_vault_tests {
             None,
             None,
             None,
-            Uint128::new(100000),
+            swap_amount,
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
-            Some(SwapAdjustmentStrategyParams::WeightedScale {
-                base_receive_amount: Uint128::new(100000),
-                multiplier: Decimal::percent(200),
-                increase_only: false,
-            }),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1183,11 +1385,41 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
-        assert_eq!(vault.performance_assessment_strategy, None);
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            vault,
+            Vault {
+                minimum_receive_amount: None,
+                label: None,
+                id: Uint128::new(1),
+                owner: info.sender,
+                destinations: vec![Destination::default()],
+                created_at: env.block.time,
+                status: VaultStatus::Scheduled,
+                time_interval: TimeInterval::Daily,
+                balance: info.funds[0].clone(),
+                slippage_tolerance: config.default_slippage_tolerance,
+                swap_amount,
+                target_denom: DENOM_UOSMO.to_string(),
+                started_at: None,
+                deposited_amount: info.funds[0].clone(),
+                escrow_level: Decimal::zero(),
+                swapped_amount: Coin::new(0, DENOM_STAKE.to_string()),
+                received_amount: Coin::new(0, DENOM_UOSMO.to_string()),
+                escrowed_amount: Coin::new(0, DENOM_UOSMO.to_string()),
+                swap_adjustment_strategy: None,
+                performance_assessment_strategy: None,
+                funding_strategy: FundingStrategy::Deposit,
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
+                }),
+            }
+        );
     }
 
     #[test]
-    fn should_create_performance_assessment_strategy() {
+    fn should_publish_deposit_event() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1202,6 +1434,8 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1221,26 +1455,31 @@ mod create_vault_tests {
             Uint128::new(100000),
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
-            .unwrap()
-            .vault;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
+                .unwrap()
+                .events;
 
-        assert_eq!(
-            vault.performance_assessment_strategy,
-            Some(PerformanceAssessmentStrategy::CompareToStandardDca {
-                swapped_amount: Coin::new(0, vault.balance.denom),
-                received_amount: Coin::new(0, DENOM_UOSMO),
-            })
-        );
+        assert!(events.contains(
+            &EventBuilder::new(
+                Uint128::one(),
+                env.block,
+                EventData::DcaVaultFundsDeposited {
+                    amount: info.funds[0].clone()
+                },
+            )
+            .build(1),
+        ))
     }
 
     #[test]
-    fn with_large_deposit_should_select_longer_duration_model() {
+    fn for_different_owner_should_succeed() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1255,16 +1494,19 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
-        info = mock_info(USER, &[Coin::new(1000000000, DENOM_STAKE)]);
+        let owner = Addr::unchecked(USER);
+        info = mock_info(ADMIN, &[Coin::new(100000, DENOM_STAKE)]);
 
         create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
-            info.sender.clone(),
+            owner,
             None,
             vec![],
             DENOM_UOSMO.to_string(),
@@ -1274,8 +1516,9 @@ mod create_vault_tests {
             Uint128::new(100000),
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1283,19 +1526,11 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
-        assert_eq!(
-            vault
-                .swap_adjustment_strategy
-                .map(|strategy| match strategy {
-                    SwapAdjustmentStrategy::RiskWeightedAverage { model_id, .. } => model_id,
-                    _ => panic!("unexpected swap adjustment strategy"),
-                }),
-            Some(90)
-        );
+        assert_eq!(vault.owner, Addr::unchecked(USER));
     }
 
     #[test]
-    fn with_no_target_time_should_execute_vault() {
+    fn with_multiple_destinations_should_succeed() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1310,42 +1545,59 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        let response = create_vault_handler(
+        let destinations = vec![
+            Destination {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked("dest-1"),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
+            },
+            Destination {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked("dest-2"),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
+            },
+        ];
+
+        create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
             info.sender.clone(),
             None,
-            vec![],
+            destinations.clone(),
             DENOM_UOSMO.to_string(),
             None,
             None,
             None,
             Uint128::new(100000),
             TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
             None,
-            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
-            Some(SwapAdjustmentStrategyParams::default()),
         )
         .unwrap();
 
-        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            funds: vec![],
-            msg: to_binary(&ExecuteMsg::ExecuteTrigger {
-                trigger_id: Uint128::one()
-            })
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
             .unwrap()
-        })));
+            .vault;
+
+        assert_eq!(vault.destinations, destinations);
     }
 
     #[test]
-    fn should_set_appropriate_escrow_level_for_compare_dca_performance_assessment_strategy() {
+    fn should_create_swap_adjustment_strategy() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1360,6 +1612,8 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1381,6 +1635,7 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
         )
         .unwrap();
 
@@ -1388,16 +1643,14 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
-        let config = get_config(deps.as_ref().storage).unwrap();
-
         assert_eq!(
-            vault.escrow_level,
-            config.risk_weighted_average_escrow_level
+            vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::default())
         );
     }
 
     #[test]
-    fn should_set_appropriate_escrow_level_for_no_performance_assessment_strategy() {
+    fn should_create_moving_average_swap_adjustment_strategy() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1412,6 +1665,8 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1432,6 +1687,13 @@ mod create_vault_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            Some(SwapAdjustmentStrategyParams::MovingAverage {
+                lookback: 7,
+                resolution: 3600,
+                sensitivity: Decimal::percent(150),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            }),
             None,
         )
         .unwrap();
@@ -1440,11 +1702,20 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
-        assert_eq!(vault.escrow_level, Decimal::zero());
+        assert_eq!(
+            vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::MovingAverage {
+                lookback: 7,
+                resolution: 3600,
+                sensitivity: Decimal::percent(150),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            })
+        );
     }
 
     #[test]
-    fn invoking_contract_callback_with_unauthorised_msg_fails() {
+    fn with_moving_average_lookback_too_short_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1459,6 +1730,8 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1470,16 +1743,7 @@ mod create_vault_tests {
             &info,
             info.sender.clone(),
             None,
-            vec![Destination {
-                address: env.contract.address,
-                allocation: Decimal::percent(100),
-                msg: Some(
-                    to_binary(&ExecuteMsg::DisburseEscrow {
-                        vault_id: Uint128::one(),
-                    })
-                    .unwrap(),
-                ),
-            }],
+            vec![],
             DENOM_UOSMO.to_string(),
             None,
             None,
@@ -1488,18 +1752,28 @@ mod create_vault_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            Some(SwapAdjustmentStrategyParams::MovingAverage {
+                lookback: 1,
+                resolution: 3600,
+                sensitivity: Decimal::percent(150),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            }),
             None,
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: Cannot invoke provided destination callback against the DCA contract"
+            format!(
+                "Error: moving average lookback must be between 2 and {}",
+                PRICE_HISTORY_CAPACITY
+            )
         );
     }
 
     #[test]
-    fn invoking_contract_callback_with_authorised_msg_succeeds() {
+    fn with_moving_average_resolution_of_zero_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let mut info = mock_info(ADMIN, &[]);
@@ -1514,27 +1788,821 @@ mod create_vault_tests {
             pair.base_denom,
             pair.quote_denom,
             pair.route,
+            None,
+            None,
         )
         .unwrap();
 
         info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
 
-        create_vault_handler(
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(SwapAdjustmentStrategyParams::MovingAverage {
+                lookback: 7,
+                resolution: 0,
+                sensitivity: Decimal::percent(150),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            }),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: moving average resolution must be between 1 and {} seconds",
+                MAX_MOVING_AVERAGE_RESOLUTION_SECONDS
+            )
+        );
+    }
+
+    #[test]
+    fn with_value_averaging_sells_allowed_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(SwapAdjustmentStrategyParams::ValueAveraging {
+                target_increment: Uint128::new(100000),
+                max_swap_multiple: Decimal::percent(300),
+                allow_sells: true,
+            }),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: value averaging sells are not supported as received funds are sent to vault destinations"
+        );
+    }
+
+    #[test]
+    fn with_volatility_scaled_windows_exceeding_twap_history_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(SwapAdjustmentStrategyParams::VolatilityScaled {
+                window_seconds: 24 * 60 * 60,
+                windows: 3,
+                target_volatility: Decimal::percent(2),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            }),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: volatility scaled windows must each span at least 60 seconds and together no more than {} seconds",
+                TWAP_HISTORY_RETENTION_SECONDS
+            )
+        );
+    }
+
+    #[test]
+    fn should_save_no_performance_assessment_strategy_when_none_provided() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(100000),
+                multiplier: Decimal::percent(200),
+                increase_only: false,
+                curve: None,
+                min_swap_amount: None,
+                max_swap_amount: None,
+            }),
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.performance_assessment_strategy, None);
+    }
+
+    #[test]
+    fn should_create_performance_assessment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.performance_assessment_strategy,
+            Some(PerformanceAssessmentStrategy::CompareToStandardDca {
+                swapped_amount: Coin::new(0, vault.balance.denom),
+                received_amount: Coin::new(0, DENOM_UOSMO),
+                performance_fee: Some(PerformanceFee {
+                    rate: Decimal::percent(20),
+                    high_water_mark: None,
+                    settled_added_value: None,
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn should_snapshot_performance_fee_rate_for_benchmark_with_high_water_mark() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        save_performance_fee_rate(deps.as_mut().storage, Decimal::percent(10)).unwrap();
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToBenchmark {
+                benchmark: PerformanceBenchmark::LumpSum,
+                high_water_mark: true,
+            }),
+            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+        )
+        .unwrap();
+
+        save_performance_fee_rate(deps.as_mut().storage, Decimal::percent(30)).unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.performance_assessment_strategy,
+            Some(PerformanceAssessmentStrategy::CompareToLumpSum {
+                start_price: None,
+                performance_fee: PerformanceFee {
+                    rate: Decimal::percent(10),
+                    high_water_mark: Some(Uint128::zero()),
+                    settled_added_value: None,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn with_large_deposit_should_select_longer_duration_model() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(1000000000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault
+                .swap_adjustment_strategy
+                .map(|strategy| match strategy {
+                    SwapAdjustmentStrategy::RiskWeightedAverage { model_id, .. } => model_id,
+                    _ => panic!("unexpected swap adjustment strategy"),
+                }),
+            Some(90)
+        );
+    }
+
+    #[test]
+    fn with_no_target_time_should_execute_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let response = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            funds: vec![],
+            msg: to_binary(&ExecuteMsg::ExecuteTrigger {
+                trigger_id: Uint128::one()
+            })
+            .unwrap()
+        })));
+    }
+
+    #[test]
+    fn should_set_appropriate_escrow_level_for_compare_dca_performance_assessment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::default()),
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            vault.escrow_level,
+            config.risk_weighted_average_escrow_level
+        );
+    }
+
+    #[test]
+    fn should_set_appropriate_escrow_level_for_no_performance_assessment_strategy() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.escrow_level, Decimal::zero());
+    }
+
+    #[test]
+    fn invoking_contract_callback_with_execute_contract_destination_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![Destination {
+                address: env.contract.address,
+                allocation: Decimal::percent(100),
+                action: DestinationAction::ExecuteContract {
+                    msg: to_binary(&ExecuteMsg::DisburseEscrow {
+                        vault_id: Uint128::one(),
+                    })
+                    .unwrap(),
+                },
+            }],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Cannot invoke provided destination callback against the DCA contract"
+        );
+    }
+
+    #[test]
+    fn with_delegate_destination_for_non_bond_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_UOSMO)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
+            }],
+            DENOM_STAKE.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: stake is not the bond denomination");
+    }
+
+    #[test]
+    fn with_delegate_to_validators_destination_with_unknown_validator_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
             &info,
             info.sender.clone(),
             None,
             vec![Destination {
-                address: env.contract.address.clone(),
+                address: Addr::unchecked(USER),
                 allocation: Decimal::percent(100),
-                msg: Some(
-                    to_binary(&ExecuteMsg::Deposit {
-                        address: Addr::unchecked(USER),
-                        vault_id: Uint128::one(),
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::DelegateToValidators {
+                    validators: vec![
+                        WeightedValidator {
+                            validator_address: Addr::unchecked(VALIDATOR),
+                            weight: Decimal::percent(50),
+                        },
+                        WeightedValidator {
+                            validator_address: Addr::unchecked("unknown-validator"),
+                            weight: Decimal::percent(50),
+                        },
+                    ],
+                    max_commission_rate: None,
+                },
+            }],
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: validator unknown-validator is invalid"
+        );
+    }
+
+    #[test]
+    fn with_provide_liquidity_destination_for_pool_without_target_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            info.clone(),
+            pair.base_denom,
+            pair.quote_denom,
+            pair.route,
+            None,
+            None,
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_UOSMO)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::ProvideLiquidity {
+                    pool_id: 1,
+                    duration: LockableDuration::OneDay,
+                    slippage_tolerance: None,
+                    superfluid_validator_address: None,
+                },
+            }],
+            DENOM_STAKE.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: pool 1 does not contain stake");
+    }
+
+    #[test]
+    fn with_deposit_to_vault_destination_owned_by_another_address_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                owner: Addr::unchecked("not-the-user"),
+                ..Vault::default()
+            },
+        );
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::DepositToVault {
+                    vault_id: destination_vault.id,
+                },
             }],
             DENOM_UOSMO.to_string(),
             None,
@@ -1545,6 +2613,56 @@ mod create_vault_tests {
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: destination vault {} is not owned by {}",
+                destination_vault.id, USER
+            )
+        );
+    }
+
+    #[test]
+    fn with_deposit_to_vault_destination_succeeds() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let destination_vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_STAKE)]);
+
+        let destinations = vec![Destination {
+            address: Addr::unchecked(USER),
+            allocation: Decimal::percent(100),
+            action: DestinationAction::DepositToVault {
+                vault_id: destination_vault.id,
+            },
+        }];
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            destinations.clone(),
+            DENOM_UOSMO.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1552,19 +2670,187 @@ mod create_vault_tests {
             .unwrap()
             .vault;
 
+        assert_eq!(vault.destinations, destinations);
+    }
+
+    fn save_pair_with_config(deps: DepsMut, config: PairConfig) {
+        save_pair(
+            deps.storage,
+            &Pair {
+                config: Some(config),
+                ..Pair::default()
+            },
+        )
+        .unwrap();
+    }
+
+    fn create_vault_with(
+        deps: DepsMut,
+        env: Env,
+        swap_amount: Uint128,
+        slippage_tolerance: Option<Decimal>,
+    ) -> Result<Response, ContractError> {
+        let info = mock_info(USER, &[Coin::new(1000000, DENOM_STAKE)]);
+
+        create_vault_handler(
+            deps,
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UOSMO.to_string(),
+            None,
+            slippage_tolerance,
+            None,
+            swap_amount,
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn with_disabled_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_pair_with_config(
+            deps.as_mut(),
+            PairConfig {
+                enabled: false,
+                minimum_swap_amount: None,
+                twap_period: None,
+                max_slippage_tolerance: None,
+            },
+        );
+
+        let err = create_vault_with(deps.as_mut(), env, Uint128::new(100000), None).unwrap_err();
+
         assert_eq!(
-            vault.destinations,
-            vec![Destination {
-                address: env.contract.address,
-                allocation: Decimal::percent(100),
-                msg: Some(
-                    to_binary(&ExecuteMsg::Deposit {
-                        address: Addr::unchecked(USER),
-                        vault_id: Uint128::one(),
-                    })
-                    .unwrap(),
-                ),
-            }]
+            err.to_string(),
+            "Error: pair for uosmo and stake is disabled"
+        );
+    }
+
+    #[test]
+    fn with_swap_amount_less_than_pair_minimum_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_pair_with_config(
+            deps.as_mut(),
+            PairConfig {
+                enabled: true,
+                minimum_swap_amount: Some(Uint128::new(200000)),
+                twap_period: None,
+                max_slippage_tolerance: None,
+            },
+        );
+
+        let err = create_vault_with(deps.as_mut(), env, Uint128::new(100000), None).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swap amount must be at least 200000 for this pair"
+        );
+    }
+
+    #[test]
+    fn with_swap_amount_below_global_minimum_but_above_pair_minimum_succeeds() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_pair_with_config(
+            deps.as_mut(),
+            PairConfig {
+                enabled: true,
+                minimum_swap_amount: Some(Uint128::new(1000)),
+                twap_period: None,
+                max_slippage_tolerance: None,
+            },
+        );
+
+        create_vault_with(deps.as_mut(), env, Uint128::new(10000), None).unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.swap_amount, Uint128::new(10000));
+    }
+
+    #[test]
+    fn with_slippage_tolerance_above_pair_maximum_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_pair_with_config(
+            deps.as_mut(),
+            PairConfig {
+                enabled: true,
+                minimum_swap_amount: None,
+                twap_period: None,
+                max_slippage_tolerance: Some(Decimal::percent(1)),
+            },
+        );
+
+        let err = create_vault_with(
+            deps.as_mut(),
+            env,
+            Uint128::new(100000),
+            Some(Decimal::percent(5)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: slippage tolerance must be less than or equal to 0.01 for this pair"
+        );
+    }
+
+    #[test]
+    fn without_slippage_tolerance_caps_default_at_pair_maximum() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let max_slippage_tolerance = Decimal::percent(1);
+
+        assert!(
+            get_config(deps.as_ref().storage)
+                .unwrap()
+                .default_slippage_tolerance
+                > max_slippage_tolerance
+        );
+
+        save_pair_with_config(
+            deps.as_mut(),
+            PairConfig {
+                enabled: true,
+                minimum_swap_amount: None,
+                twap_period: None,
+                max_slippage_tolerance: Some(max_slippage_tolerance),
+            },
         );
+
+        create_vault_with(deps.as_mut(), env, Uint128::new(100000), None).unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.slippage_tolerance, max_slippage_tolerance);
     }
 }
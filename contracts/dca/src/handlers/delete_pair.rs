@@ -0,0 +1,122 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_has_role;
+use crate::state::circuit_breakers::remove_circuit_breaker_state;
+use crate::state::pairs::{delete_pair, find_pair};
+use crate::state::vaults::get_active_vault_ids_by_denoms;
+use crate::types::role::Role;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn delete_pair_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_denom: String,
+    quote_denom: String,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
+
+    let pair = find_pair(deps.storage, [base_denom, quote_denom])?;
+
+    if !get_active_vault_ids_by_denoms(deps.storage, pair.denoms(), 1)?.is_empty() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "cannot delete pair for {} and {} while active vaults reference it",
+                pair.base_denom, pair.quote_denom
+            ),
+        });
+    }
+
+    delete_pair(deps.storage, pair.denoms());
+    remove_circuit_breaker_state(deps.storage, pair.denoms());
+
+    Ok(Response::new()
+        .add_attribute("delete_pair", "true")
+        .add_attribute("base_denom", pair.base_denom)
+        .add_attribute("quote_denom", pair.quote_denom)
+        .add_attribute("route", format!("{:?}", pair.route)))
+}
+
+#[cfg(test)]
+mod delete_pair_tests {
+    use super::*;
+    use crate::state::pairs::save_pair;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use crate::types::pair::Pair;
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let err = delete_pair_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_active_vaults_referencing_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        let err = delete_pair_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot delete pair for stake and uosmo while active vaults reference it"
+        );
+    }
+
+    #[test]
+    fn with_only_inactive_vaults_referencing_pair_deletes_pair() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        delete_pair_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+        )
+        .unwrap();
+
+        assert!(find_pair(deps.as_ref().storage, vault.denoms()).is_err());
+    }
+}
@@ -6,6 +6,7 @@ use crate::helpers::validation::{
 };
 use crate::helpers::vault::get_risk_weighted_average_model_id;
 use crate::state::events::create_event;
+use crate::state::risk_weighted_average_models::get_risk_weighted_average_model;
 use crate::state::triggers::save_trigger;
 use crate::state::vaults::{get_vault, update_vault};
 use crate::types::event::{EventBuilder, EventData};
@@ -52,27 +53,26 @@ pub fn deposit_handler(
         vault.status = VaultStatus::Active
     }
 
-    vault.swap_adjustment_strategy =
-        vault
-            .swap_adjustment_strategy
-            .clone()
-            .map(|swap_adjustment_strategy| match swap_adjustment_strategy {
-                SwapAdjustmentStrategy::RiskWeightedAverage {
-                    base_denom,
-                    position_type,
-                    ..
-                } => SwapAdjustmentStrategy::RiskWeightedAverage {
-                    model_id: get_risk_weighted_average_model_id(
-                        &env.block.time,
-                        &vault.balance,
-                        &vault.swap_amount,
-                        &vault.time_interval,
-                    ),
-                    base_denom,
-                    position_type,
-                },
-                _ => swap_adjustment_strategy,
+    if let Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+        base_denom,
+        position_type,
+        ..
+    }) = vault.swap_adjustment_strategy.clone()
+    {
+        if let Some(model) = get_risk_weighted_average_model(deps.storage, &base_denom)? {
+            vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id: get_risk_weighted_average_model_id(
+                    &model,
+                    &env.block.time,
+                    &vault.balance,
+                    &vault.swap_amount,
+                    &vault.time_interval,
+                ),
+                base_denom,
+                position_type,
             });
+        }
+    }
 
     update_vault(deps.storage, vault.clone())?;
 
@@ -133,7 +133,7 @@ mod dposit_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let deposit_amount = Coin::new(TEN.into(), DENOM_UOSMO);
-        let info = mock_info(ADMIN, &[deposit_amount.clone()]);
+        let info = mock_info(ADMIN, std::slice::from_ref(&deposit_amount));
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
@@ -162,7 +162,7 @@ mod dposit_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let deposit_amount = Coin::new(TEN.into(), DENOM_UOSMO);
-        let info = mock_info(ADMIN, &[deposit_amount.clone()]);
+        let info = mock_info(ADMIN, std::slice::from_ref(&deposit_amount));
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
@@ -477,7 +477,7 @@ mod dposit_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let deposit_amount = Coin::new(ONE_HUNDRED.into(), DENOM_UOSMO);
-        let info = mock_info(ADMIN, &[deposit_amount.clone()]);
+        let info = mock_info(ADMIN, std::slice::from_ref(&deposit_amount));
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
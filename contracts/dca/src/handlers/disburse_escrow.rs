@@ -3,7 +3,7 @@ use crate::{
     helpers::{
         coin::{empty_of, subtract},
         disbursement::get_disbursement_messages,
-        fees::{get_fee_messages, get_performance_fee},
+        fees::{get_added_value, get_fee_messages, get_performance_fee},
         price::query_belief_price,
         validation::assert_sender_is_executor,
     },
@@ -50,8 +50,11 @@ pub fn disburse_escrow_handler(
 
     let due_date = get_disburse_escrow_task_due_date(deps.storage, vault.id)?;
 
+    let is_finished = vault.should_not_continue();
+    let is_checkpoint = !is_finished && !vault.is_cancelled();
+
     if let Some(due_date) = due_date {
-        if env.block.time < due_date {
+        if env.block.time < due_date && !is_finished {
             return Err(ContractError::CustomError {
                 val: "Escrow is not available to be disbursed yet".to_string(),
             });
@@ -63,8 +66,16 @@ pub fn disburse_escrow_handler(
     let performance_fee = get_performance_fee(&vault, current_price)?;
     let amount_to_disburse = subtract(&vault.escrowed_amount, &performance_fee)?;
 
+    let added_value = match vault.performance_assessment_strategy {
+        Some(_) => get_added_value(&vault, current_price)?,
+        None => Uint128::zero(),
+    };
+
     let vault = Vault {
         escrowed_amount: empty_of(vault.escrowed_amount),
+        performance_assessment_strategy: vault
+            .performance_assessment_strategy
+            .map(|strategy| strategy.with_settled_added_value(added_value)),
         ..vault
     };
 
@@ -75,9 +86,16 @@ pub fn disburse_escrow_handler(
         EventBuilder::new(
             vault.id,
             env.block.clone(),
-            EventData::DcaVaultEscrowDisbursed {
-                amount_disbursed: amount_to_disburse.clone(),
-                performance_fee: performance_fee.clone(),
+            if is_checkpoint {
+                EventData::DcaVaultEscrowCheckpoint {
+                    amount_released: amount_to_disburse.clone(),
+                    performance_fee: performance_fee.clone(),
+                }
+            } else {
+                EventData::DcaVaultEscrowDisbursed {
+                    amount_disbursed: amount_to_disburse.clone(),
+                    performance_fee: performance_fee.clone(),
+                }
             },
         ),
     )?;
@@ -89,6 +107,7 @@ pub fn disburse_escrow_handler(
     Ok(response
         .add_submessages(get_disbursement_messages(
             deps.storage,
+            &env.contract.address,
             &vault,
             amount_to_disburse.amount,
         )?)
@@ -98,7 +117,8 @@ pub fn disburse_escrow_handler(
             vault.target_denom,
         )?)
         .add_attribute("performance_fee", format!("{:?}", performance_fee))
-        .add_attribute("escrow_disbursed", format!("{:?}", amount_to_disburse)))
+        .add_attribute("escrow_disbursed", format!("{:?}", amount_to_disburse))
+        .add_attribute("escrow_checkpoint", is_checkpoint.to_string()))
 }
 
 #[cfg(test)]
@@ -119,14 +139,14 @@ mod disburse_escrow_tests {
         types::{
             destination::Destination,
             event::{Event, EventData},
-            performance_assessment_strategy::PerformanceAssessmentStrategy,
+            performance_assessment_strategy::{PerformanceAssessmentStrategy, PerformanceFee},
             swap_adjustment_strategy::SwapAdjustmentStrategy,
             vault::{Vault, VaultStatus},
         },
     };
     use cosmwasm_std::{
         testing::{mock_env, mock_info},
-        to_binary, BankMsg, Coin, Decimal, StdError, SubMsg, Uint128,
+        to_binary, Attribute, BankMsg, Coin, Decimal, StdError, SubMsg, Uint128,
     };
     use osmosis_std::types::osmosis::twap::v1beta1::ArithmeticTwapResponse;
 
@@ -233,6 +253,7 @@ mod disburse_escrow_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                         received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -272,6 +293,7 @@ mod disburse_escrow_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                         received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -319,6 +341,7 @@ mod disburse_escrow_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                         received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -375,6 +398,7 @@ mod disburse_escrow_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                         received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -389,6 +413,194 @@ mod disburse_escrow_tests {
         assert_eq!(vault.escrowed_amount, Coin::new(0, vault.target_denom));
     }
 
+    #[test]
+    fn raises_high_water_mark_to_added_value() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_STAKE),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_STAKE),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                        received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: Some(PerformanceFee {
+                            rate: Decimal::percent(20),
+                            high_water_mark: Some(Uint128::zero()),
+                            settled_added_value: None,
+                        }),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+        let current_price =
+            query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom()).unwrap();
+
+        disburse_escrow_handler(deps.as_mut(), &env, info, vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault
+                .performance_assessment_strategy
+                .clone()
+                .unwrap()
+                .high_water_mark(),
+            Some((TEN + ONE) * current_price - TEN * current_price)
+        );
+
+        let performance_fee = get_performance_fee(
+            &Vault {
+                escrowed_amount: vault.escrowed_amount,
+                ..updated_vault
+            },
+            current_price,
+        )
+        .unwrap();
+
+        assert_eq!(performance_fee.amount, Uint128::zero());
+    }
+
+    #[test]
+    fn when_vault_is_finished_ignores_pending_escrow_checkpoint() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                escrowed_amount: Coin::new((TEN * Decimal::percent(5)).into(), DENOM_STAKE),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                        performance_fee: PerformanceFee {
+                            rate: Decimal::percent(20),
+                            high_water_mark: None,
+                            settled_added_value: None,
+                        },
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+
+        let response = disburse_escrow_handler(deps.as_mut(), &env, info, vault.id).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("escrow_checkpoint", "false")));
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn when_vault_is_still_running_publishes_escrow_checkpoint_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
+                received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                escrowed_amount: Coin::new((ONE * Decimal::percent(5)).into(), DENOM_STAKE),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                        performance_fee: PerformanceFee {
+                            rate: Decimal::percent(20),
+                            high_water_mark: None,
+                            settled_added_value: None,
+                        },
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.minus_seconds(10),
+        )
+        .unwrap();
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+        let current_price =
+            query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom()).unwrap();
+        let performance_fee = get_performance_fee(&vault, current_price).unwrap();
+
+        let response = disburse_escrow_handler(deps.as_mut(), &env, info, vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(&Event {
+            id: 1,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultEscrowCheckpoint {
+                amount_released: subtract(&vault.escrowed_amount, &performance_fee).unwrap(),
+                performance_fee,
+            }
+        }));
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("escrow_checkpoint", "true")));
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.escrowed_amount,
+            Coin::new(0, vault.target_denom.clone())
+        );
+        assert_eq!(
+            updated_vault
+                .performance_assessment_strategy
+                .unwrap()
+                .fee_baseline(),
+            get_added_value(&vault, current_price).unwrap()
+        );
+    }
+
     #[test]
     fn deletes_disburse_escrow_task() {
         let mut deps = calc_mock_dependencies();
@@ -410,6 +622,7 @@ mod disburse_escrow_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                         received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -1,3 +1,4 @@
+use crate::constants::ESCROW_CHECKPOINT_INTERVAL_SECONDS;
 use crate::error::ContractError;
 use crate::helpers::coin::{add_to, subtract};
 use crate::helpers::disbursement::get_disbursement_messages;
@@ -5,6 +6,9 @@ use crate::helpers::fees::{get_automation_fee_rate, get_fee_messages, get_swap_f
 use crate::helpers::math::checked_mul;
 use crate::msg::ExecuteMsg;
 use crate::state::cache::{SWAP_CACHE, VAULT_CACHE};
+use crate::state::disburse_escrow_tasks::{
+    get_disburse_escrow_task_due_date, save_disburse_escrow_task,
+};
 use crate::state::events::create_event;
 use crate::state::triggers::delete_trigger;
 use crate::state::vaults::{get_vault, update_vault};
@@ -69,14 +73,38 @@ pub fn disburse_funds_handler(
 
             vault.escrowed_amount = add_to(vault.escrowed_amount, amount_to_escrow);
 
-            if vault.balance.amount.is_zero() {
+            let has_performance_fee = vault
+                .performance_assessment_strategy
+                .as_ref()
+                .is_some_and(|strategy| strategy.performance_fee().is_some());
+
+            if amount_to_escrow > Uint128::zero()
+                && has_performance_fee
+                && get_disburse_escrow_task_due_date(deps.storage, vault.id)?.is_none()
+            {
+                save_disburse_escrow_task(
+                    deps.storage,
+                    vault.id,
+                    env.block
+                        .time
+                        .plus_seconds(ESCROW_CHECKPOINT_INTERVAL_SECONDS),
+                )?;
+            }
+
+            if vault.balance.amount.is_zero() && !vault.is_pull_funded() {
                 vault.status = VaultStatus::Inactive;
             }
 
             update_vault(deps.storage, vault.clone())?;
 
             sub_msgs.append(
-                &mut get_disbursement_messages(deps.storage, &vault, total_after_total_fee)?.into(),
+                &mut get_disbursement_messages(
+                    deps.storage,
+                    &env.contract.address,
+                    &vault,
+                    total_after_total_fee,
+                )?
+                .into(),
             );
 
             create_event(
@@ -157,7 +185,8 @@ mod disburse_funds_tests {
             destination::Destination,
             event::{Event, EventBuilder, EventData, ExecutionSkippedReason},
             fee_collector::FeeCollector,
-            performance_assessment_strategy::PerformanceAssessmentStrategy,
+            funding_strategy::FundingStrategy,
+            performance_assessment_strategy::{PerformanceAssessmentStrategy, PerformanceFee},
             position_type::PositionType,
             swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
             vault::{Vault, VaultStatus},
@@ -165,7 +194,7 @@ mod disburse_funds_tests {
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
+        BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult, Timestamp, Uint128,
     };
     use std::{cmp::min, str::FromStr};
 
@@ -218,17 +247,21 @@ mod disburse_funds_tests {
 
         let automation_fee = get_config(&deps.storage).unwrap().automation_fee_percent;
 
-        let automation_fees = vault.destinations.iter().filter(|d| d.msg.is_some()).fold(
-            Coin::new(0, vault.target_denom.clone()),
-            |mut accum, destination| {
-                let allocation_amount =
-                    checked_mul(receive_amount - fee, destination.allocation).unwrap();
-                let allocation_automation_fee =
-                    checked_mul(allocation_amount, automation_fee).unwrap();
-                accum.amount = accum.amount.checked_add(allocation_automation_fee).unwrap();
-                accum
-            },
-        );
+        let automation_fees = vault
+            .destinations
+            .iter()
+            .filter(|d| d.action.is_automated())
+            .fold(
+                Coin::new(0, vault.target_denom.clone()),
+                |mut accum, destination| {
+                    let allocation_amount =
+                        checked_mul(receive_amount - fee, destination.allocation).unwrap();
+                    let allocation_automation_fee =
+                        checked_mul(allocation_amount, automation_fee).unwrap();
+                    accum.amount = accum.amount.checked_add(allocation_automation_fee).unwrap();
+                    accum
+                },
+            );
 
         let disbursal_amount = receive_amount - fee - automation_fees.amount;
 
@@ -343,16 +376,17 @@ mod disburse_funds_tests {
         let swap_fee = config.default_swap_fee_percent * receive_amount;
         let total_after_swap_fee = receive_amount - swap_fee;
 
-        let automation_fee = vault.destinations.iter().filter(|d| d.msg.is_some()).fold(
-            Uint128::zero(),
-            |acc, destination| {
+        let automation_fee = vault
+            .destinations
+            .iter()
+            .filter(|d| d.action.is_automated())
+            .fold(Uint128::zero(), |acc, destination| {
                 let allocation_amount =
                     checked_mul(total_after_swap_fee, destination.allocation).unwrap();
                 let allocation_automation_fee =
                     checked_mul(allocation_amount, config.automation_fee_percent).unwrap();
                 acc.checked_add(allocation_automation_fee).unwrap()
-            },
-        );
+            });
 
         for fee_collector in config.fee_collectors.iter() {
             assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
@@ -530,7 +564,7 @@ mod disburse_funds_tests {
         vault
             .destinations
             .iter()
-            .filter(|d| d.msg.is_some())
+            .filter(|d| d.action.is_automated())
             .for_each(|destination| {
                 let allocation_amount =
                     checked_mul(receive_amount - fee, destination.allocation).unwrap();
@@ -649,6 +683,142 @@ mod disburse_funds_tests {
         assert_ne!(escrow_amount, Uint128::zero());
     }
 
+    #[test]
+    fn with_succcesful_swap_with_escrow_level_schedules_escrow_checkpoint() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination::default()],
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                        performance_fee: PerformanceFee {
+                            rate: Decimal::percent(20),
+                            high_water_mark: None,
+                            settled_added_value: None,
+                        },
+                    },
+                ),
+                escrow_level: Decimal::percent(5),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(10000, vault.target_denom.clone())],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let due_date = get_disburse_escrow_task_due_date(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(
+            due_date,
+            Some(Timestamp::from_seconds(
+                env.block
+                    .time
+                    .plus_seconds(ESCROW_CHECKPOINT_INTERVAL_SECONDS)
+                    .seconds()
+            ))
+        );
+    }
+
+    #[test]
+    fn with_succcesful_swap_with_escrow_checkpoint_scheduled_keeps_due_date() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination::default()],
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                        performance_fee: PerformanceFee {
+                            rate: Decimal::percent(20),
+                            high_water_mark: None,
+                            settled_added_value: None,
+                        },
+                    },
+                ),
+                escrow_level: Decimal::percent(5),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(10000, vault.target_denom.clone())],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let due_date = get_disburse_escrow_task_due_date(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(
+            due_date,
+            Some(Timestamp::from_seconds(
+                env.block.time.plus_seconds(10).seconds()
+            ))
+        );
+    }
+
     #[test]
     fn with_succcesful_swap_publishes_dca_execution_completed_event() {
         let mut deps = mock_dependencies();
@@ -1163,6 +1333,58 @@ mod disburse_funds_tests {
         assert_eq!(vault.status, VaultStatus::Inactive);
     }
 
+    #[test]
+    fn for_pull_funded_vault_with_no_remaining_funds_keeps_vault_active() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(ONE.into(), DENOM_UOSMO),
+                swap_amount: ONE,
+                funding_strategy: FundingStrategy::AuthzPull {
+                    consecutive_failures: 0,
+                },
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(1000000, vault.target_denom.clone())],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let vault = get_vault(&deps.storage, vault.id).unwrap();
+        assert!(vault.balance.amount.is_zero());
+        assert_eq!(vault.status, VaultStatus::Active);
+    }
+
     #[test]
     fn for_non_standard_dca_vault_with_failed_swap_publishes_slippage_tolerance_exceeded_event() {
         let mut deps = mock_dependencies();
@@ -1289,6 +1511,7 @@ mod disburse_funds_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                         received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -1349,6 +1572,7 @@ mod disburse_funds_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                         received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -1409,6 +1633,7 @@ mod disburse_funds_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new((ONE / TWO_MICRONS).into(), DENOM_UOSMO),
                         received_amount: Coin::new((ONE / TWO_MICRONS).into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -0,0 +1,276 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_config_is_valid, assert_denom_exists, assert_fee_level_is_valid,
+        assert_performance_fee_percent_is_valid, assert_sender_can_manage_config_change,
+    },
+    state::config::{
+        create_custom_fee, get_config, get_queued_config_change, remove_custom_fee,
+        remove_queued_config_change, save_config_change_delay, save_performance_fee_rate,
+        update_config,
+    },
+    types::config::{Config, ConfigChange},
+};
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+
+pub fn execute_queued_config_change_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let queued_change =
+        get_queued_config_change(deps.storage, id)?.ok_or(ContractError::CustomError {
+            val: format!("queued config change {} does not exist", id),
+        })?;
+
+    assert_sender_can_manage_config_change(deps.storage, &info.sender, &queued_change.change)?;
+
+    if env.block.time < queued_change.executable_at {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "queued config change {} cannot be executed until {}",
+                id, queued_change.executable_at
+            ),
+        });
+    }
+
+    match queued_change.change.clone() {
+        ConfigChange::UpdateConfig {
+            executors,
+            fee_collectors,
+            default_swap_fee_percent,
+            weighted_scale_swap_fee_percent,
+            automation_fee_percent,
+            risk_weighted_average_escrow_level,
+            config_change_delay_seconds,
+            performance_fee_percent,
+        } => {
+            let existing_config = get_config(deps.storage)?;
+
+            let config = Config {
+                executors: executors.unwrap_or(existing_config.executors.clone()),
+                fee_collectors: fee_collectors.unwrap_or(existing_config.fee_collectors.clone()),
+                default_swap_fee_percent: default_swap_fee_percent
+                    .unwrap_or(existing_config.default_swap_fee_percent),
+                weighted_scale_swap_fee_percent: weighted_scale_swap_fee_percent
+                    .unwrap_or(existing_config.weighted_scale_swap_fee_percent),
+                automation_fee_percent: automation_fee_percent
+                    .unwrap_or(existing_config.automation_fee_percent),
+                risk_weighted_average_escrow_level: risk_weighted_average_escrow_level
+                    .unwrap_or(existing_config.risk_weighted_average_escrow_level),
+                ..existing_config
+            };
+
+            assert_config_is_valid(deps.as_ref(), &config)?;
+            update_config(deps.storage, config)?;
+
+            if let Some(config_change_delay_seconds) = config_change_delay_seconds {
+                save_config_change_delay(deps.storage, config_change_delay_seconds)?;
+            }
+
+            if let Some(performance_fee_percent) = performance_fee_percent {
+                assert_performance_fee_percent_is_valid(performance_fee_percent)?;
+                save_performance_fee_rate(deps.storage, performance_fee_percent)?;
+            }
+        }
+        ConfigChange::CreateCustomSwapFee {
+            denom,
+            swap_fee_percent,
+        } => {
+            assert_denom_exists(deps.storage, denom.clone())?;
+            assert_fee_level_is_valid(&swap_fee_percent)?;
+            create_custom_fee(deps.storage, denom, swap_fee_percent)?;
+        }
+        ConfigChange::RemoveCustomSwapFee { denom } => {
+            remove_custom_fee(deps.storage, denom);
+        }
+    }
+
+    remove_queued_config_change(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("execute_queued_config_change", "true")
+        .add_event(
+            Event::new("config_change_executed")
+                .add_attribute("id", id.to_string())
+                .add_attribute("change", format!("{:?}", queued_change.change)),
+        ))
+}
+
+#[cfg(test)]
+mod execute_queued_config_change_tests {
+    use super::*;
+    use crate::{
+        handlers::{
+            create_custom_swap_fee::create_custom_swap_fee_handler,
+            update_config::update_config_handler,
+        },
+        state::{
+            config::{get_custom_fee, get_queued_config_changes, save_config_change_delay},
+            pairs::save_pair,
+        },
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UOSMO, USER},
+        },
+        types::pair::Pair,
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Decimal,
+    };
+
+    fn queue_swap_fee_update(mut deps: DepsMut, env: Env) -> u64 {
+        save_config_change_delay(deps.storage, 3600).unwrap();
+
+        update_config_handler(
+            deps.branch(),
+            env,
+            mock_info(ADMIN, &[]),
+            None,
+            None,
+            Some(Decimal::percent(2)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        get_queued_config_changes(deps.storage).unwrap()[0].id
+    }
+
+    #[test]
+    fn with_unknown_id_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err =
+            execute_queued_config_change_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), 1)
+                .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: queued config change 1 does not exist"
+        );
+    }
+
+    #[test]
+    fn before_delay_has_passed_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let id = queue_swap_fee_update(deps.as_mut(), env.clone());
+
+        let err = execute_queued_config_change_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: queued config change {} cannot be executed until {}",
+                id,
+                env.block.time.plus_seconds(3600)
+            )
+        );
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let id = queue_swap_fee_update(deps.as_mut(), env.clone());
+
+        env.block.time = env.block.time.plus_seconds(3600);
+
+        let err =
+            execute_queued_config_change_handler(deps.as_mut(), env, mock_info(USER, &[]), id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn after_delay_has_passed_applies_config_change() {
+        let mut deps = calc_mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let id = queue_swap_fee_update(deps.as_mut(), env.clone());
+
+        assert_ne!(
+            get_config(deps.as_ref().storage)
+                .unwrap()
+                .default_swap_fee_percent,
+            Decimal::percent(2)
+        );
+
+        env.block.time = env.block.time.plus_seconds(3600);
+
+        execute_queued_config_change_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), id)
+            .unwrap();
+
+        assert_eq!(
+            get_config(deps.as_ref().storage)
+                .unwrap()
+                .default_swap_fee_percent,
+            Decimal::percent(2)
+        );
+        assert!(get_queued_config_changes(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn after_delay_has_passed_applies_custom_fee_change() {
+        let mut deps = calc_mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_pair(deps.as_mut().storage, &Pair::default()).unwrap();
+        save_config_change_delay(deps.as_mut().storage, 3600).unwrap();
+
+        create_custom_swap_fee_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_custom_fee(deps.as_ref().storage, DENOM_UOSMO.to_string()).unwrap(),
+            None
+        );
+
+        env.block.time = env.block.time.plus_seconds(3600);
+
+        execute_queued_config_change_handler(deps.as_mut(), env, mock_info(ADMIN, &[]), 1).unwrap();
+
+        assert_eq!(
+            get_custom_fee(deps.as_ref().storage, DENOM_UOSMO.to_string()).unwrap(),
+            Some(Decimal::percent(1))
+        );
+    }
+}
@@ -1,16 +1,28 @@
 use crate::constants::AFTER_SWAP_REPLY_ID;
 use crate::error::ContractError;
-use crate::helpers::price::query_belief_price;
+use crate::helpers::funding::{
+    create_claim_staking_rewards_message, create_pull_funds_message, get_amount_to_pull,
+    get_invalid_grant_reason, record_failed_pull, MsgWithdrawDelegatorReward, MSG_SEND_TYPE_URL,
+};
+use crate::helpers::price::{get_base_denom_price, query_market_and_belief_prices, query_price};
 use crate::helpers::swaps::create_swap_message;
 use crate::helpers::time::get_next_target_time;
 use crate::helpers::validation::{assert_contract_is_not_paused, assert_target_time_is_in_past};
-use crate::helpers::vault::{get_swap_amount, simulate_standard_dca_execution};
+use crate::helpers::vault::{
+    get_swap_amount, get_value_averaging_values, simulate_standard_dca_execution,
+};
 use crate::msg::ExecuteMsg;
-use crate::state::cache::{SwapCache, SWAP_CACHE, VAULT_CACHE};
+use crate::state::cache::{
+    StakingRewardsCache, SwapCache, PULL_FUNDS_CACHE, STAKING_REWARDS_CACHE, SWAP_CACHE,
+    VAULT_CACHE,
+};
+use crate::state::circuit_breakers::{is_pair_paused, save_circuit_breaker_state};
 use crate::state::events::create_event;
 use crate::state::pairs::find_pair;
+use crate::state::price_history::record_price;
 use crate::state::triggers::{delete_trigger, save_trigger};
 use crate::state::vaults::{get_vault, update_vault};
+use crate::types::circuit_breaker::CircuitBreakerState;
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
 use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
 use crate::types::trigger::{Trigger, TriggerConfiguration};
@@ -20,7 +32,7 @@ use cosmwasm_std::{to_binary, Decimal, ReplyOn, SubMsg, WasmMsg};
 use cosmwasm_std::{DepsMut, Env, Response, Uint128};
 
 pub fn execute_trigger_handler(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     trigger_id: Uint128,
 ) -> Result<Response, ContractError> {
@@ -78,7 +90,8 @@ pub fn execute_trigger_handler(
 
     let pair = find_pair(deps.storage, vault.denoms())?;
 
-    let belief_price = query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom())?;
+    let (market_price, belief_price) =
+        query_market_and_belief_prices(&deps.as_ref(), &env, &pair, vault.get_swap_denom())?;
 
     create_event(
         deps.storage,
@@ -95,6 +108,26 @@ pub fn execute_trigger_handler(
 
     response = response.add_attribute("belief_price", belief_price.to_string());
 
+    let base_denom_price = get_base_denom_price(&pair, vault.get_swap_denom(), market_price);
+
+    let response = execute_vault(deps.branch(), env.clone(), vault, belief_price, response)?;
+
+    // Recorded after the vault has been executed, and moving averages only use closed candles,
+    // so they never include the price they are compared against.
+    if let Some(base_denom_price) = base_denom_price {
+        record_price(deps.storage, &pair, base_denom_price, env.block.time)?;
+    }
+
+    Ok(response)
+}
+
+fn execute_vault(
+    deps: DepsMut,
+    env: Env,
+    mut vault: Vault,
+    belief_price: Decimal,
+    mut response: Response,
+) -> Result<Response, ContractError> {
     if let Some(SwapAdjustmentStrategy::RiskWeightedAverage { .. }) = vault.swap_adjustment_strategy
     {
         (vault, response) = simulate_standard_dca_execution(
@@ -108,8 +141,7 @@ pub fn execute_trigger_handler(
     }
 
     let should_execute_again = vault.is_active()
-        || vault.performance_assessment_strategy.clone().map_or(
-            false,
+        || vault.performance_assessment_strategy.as_ref().is_some_and(
             |performance_assessment_strategy| {
                 performance_assessment_strategy.should_continue(&vault)
             },
@@ -145,21 +177,193 @@ pub fn execute_trigger_handler(
         return Ok(response.add_attribute("execution_skipped", "vault_is_inactive"));
     }
 
-    let adjusted_swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault)?;
+    if vault.is_pull_funded() {
+        if vault.price_threshold_exceeded(belief_price)? {
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    vault.id,
+                    env.block,
+                    EventData::DcaVaultExecutionSkipped {
+                        reason: ExecutionSkippedReason::PriceThresholdExceeded {
+                            price: belief_price,
+                        },
+                    },
+                ),
+            )?;
 
-    if adjusted_swap_amount.amount.is_zero() {
+            return Ok(response.add_attribute("execution_skipped", "price_threshold_exceeded"));
+        }
+
+        if vault.funding_strategy.is_staking_rewards() {
+            let delegations = deps.querier.query_all_delegations(vault.owner.clone())?;
+
+            if delegations.is_empty() {
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(
+                        vault.id,
+                        env.block,
+                        EventData::DcaVaultExecutionSkipped {
+                            reason: ExecutionSkippedReason::NoStakingRewardsClaimed,
+                        },
+                    ),
+                )?;
+
+                return Ok(response.add_attribute("execution_skipped", "no_delegations_found"));
+            }
+
+            if let Some(reason) = [MsgWithdrawDelegatorReward::TYPE_URL, MSG_SEND_TYPE_URL]
+                .into_iter()
+                .find_map(|msg_type_url| {
+                    get_invalid_grant_reason(&deps.querier, &env, &vault.owner, msg_type_url)
+                })
+            {
+                record_failed_pull(deps.storage, &env, vault, reason)?;
+
+                return Ok(response.add_attribute("execution_skipped", "invalid_funding_grant"));
+            }
+
+            VAULT_CACHE.save(deps.storage, &vault.id)?;
+
+            STAKING_REWARDS_CACHE.save(
+                deps.storage,
+                &StakingRewardsCache {
+                    owner_balance: deps
+                        .querier
+                        .query_balance(&vault.owner, vault.get_swap_denom())?,
+                },
+            )?;
+
+            return Ok(
+                response.add_submessage(create_claim_staking_rewards_message(
+                    &env,
+                    &vault,
+                    delegations
+                        .iter()
+                        .map(|delegation| delegation.validator.clone())
+                        .collect(),
+                )),
+            );
+        }
+
+        let amount_to_pull = get_amount_to_pull(&vault);
+
+        if !amount_to_pull.amount.is_zero() {
+            if let Some(reason) =
+                get_invalid_grant_reason(&deps.querier, &env, &vault.owner, MSG_SEND_TYPE_URL)
+            {
+                record_failed_pull(deps.storage, &env, vault, reason)?;
+
+                return Ok(response.add_attribute("execution_skipped", "invalid_funding_grant"));
+            }
+
+            let owner_balance = deps
+                .querier
+                .query_balance(&vault.owner, vault.get_swap_denom())?;
+
+            if owner_balance.amount < amount_to_pull.amount {
+                record_failed_pull(
+                    deps.storage,
+                    &env,
+                    vault,
+                    ExecutionSkippedReason::InsufficientFunderBalance,
+                )?;
+
+                return Ok(
+                    response.add_attribute("execution_skipped", "insufficient_funder_balance")
+                );
+            }
+
+            VAULT_CACHE.save(deps.storage, &vault.id)?;
+            PULL_FUNDS_CACHE.save(deps.storage, &amount_to_pull)?;
+
+            return Ok(response
+                .add_attribute("pulled_amount", amount_to_pull.to_string())
+                .add_submessage(create_pull_funds_message(&env, &vault, amount_to_pull)));
+        }
+    }
+
+    swap_vault_balance(deps, &env, vault, belief_price, response)
+}
+
+pub fn swap_vault_balance(
+    deps: DepsMut,
+    env: &Env,
+    vault: Vault,
+    belief_price: Decimal,
+    response: Response,
+) -> Result<Response, ContractError> {
+    let pair = find_pair(deps.storage, vault.denoms())?;
+
+    if !pair.is_enabled() {
         create_event(
             deps.storage,
             EventBuilder::new(
                 vault.id,
-                env.block,
+                env.block.clone(),
                 EventData::DcaVaultExecutionSkipped {
-                    reason: ExecutionSkippedReason::SwapAmountAdjustedToZero,
+                    reason: ExecutionSkippedReason::PairDisabled,
                 },
             ),
         )?;
 
-        return Ok(response.add_attribute("execution_skipped", "swap_amount_adjusted_to_zero"));
+        return Ok(response.add_attribute("execution_skipped", "pair_disabled"));
+    }
+
+    if is_pair_paused(deps.storage, pair.denoms())? {
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                vault.id,
+                env.block.clone(),
+                EventData::DcaVaultExecutionSkipped {
+                    reason: ExecutionSkippedReason::PairPaused,
+                },
+            ),
+        )?;
+
+        return Ok(response.add_attribute("execution_skipped", "pair_paused"));
+    }
+
+    let adjusted_swap_amount = get_swap_amount(&deps.as_ref(), env, &vault)?;
+
+    if adjusted_swap_amount.amount.is_zero() {
+        let (reason, attribute) = match vault.swap_adjustment_strategy {
+            Some(SwapAdjustmentStrategy::ValueAveraging {
+                target_increment, ..
+            }) => {
+                let (current_value, target_value) = get_value_averaging_values(
+                    &vault,
+                    env.block.time,
+                    belief_price,
+                    target_increment,
+                );
+
+                (
+                    ExecutionSkippedReason::ValueAveragingTargetReached {
+                        current_value,
+                        target_value,
+                    },
+                    "value_averaging_target_reached",
+                )
+            }
+            _ => (
+                ExecutionSkippedReason::SwapAmountAdjustedToZero,
+                "swap_amount_adjusted_to_zero",
+            ),
+        };
+
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                vault.id,
+                env.block.clone(),
+                EventData::DcaVaultExecutionSkipped { reason },
+            ),
+        )?;
+
+        return Ok(response.add_attribute("execution_skipped", attribute));
     }
 
     if vault.price_threshold_exceeded(belief_price)? {
@@ -167,7 +371,7 @@ pub fn execute_trigger_handler(
             deps.storage,
             EventBuilder::new(
                 vault.id,
-                env.block,
+                env.block.clone(),
                 EventData::DcaVaultExecutionSkipped {
                     reason: ExecutionSkippedReason::PriceThresholdExceeded {
                         price: belief_price,
@@ -179,6 +383,62 @@ pub fn execute_trigger_handler(
         return Ok(response.add_attribute("execution_skipped", "price_threshold_exceeded"));
     };
 
+    if let Some(circuit_breaker) = pair.circuit_breaker.clone() {
+        let spot_price = query_price(&deps.querier, env, &pair, &adjusted_swap_amount)?;
+
+        let deviation = if spot_price > belief_price {
+            (spot_price - belief_price) / belief_price
+        } else {
+            (belief_price - spot_price) / belief_price
+        };
+
+        if deviation > circuit_breaker.max_deviation {
+            save_circuit_breaker_state(
+                deps.storage,
+                pair.denoms(),
+                &CircuitBreakerState {
+                    tripped_at: env.block.time,
+                    spot_price,
+                    belief_price,
+                    deviation,
+                    paused: circuit_breaker.auto_pause,
+                },
+            )?;
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    vault.id,
+                    env.block.clone(),
+                    EventData::DcaVaultCircuitBreakerTripped {
+                        base_denom: pair.base_denom.clone(),
+                        quote_denom: pair.quote_denom.clone(),
+                        spot_price,
+                        belief_price,
+                        deviation,
+                        paused: circuit_breaker.auto_pause,
+                    },
+                ),
+            )?;
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    vault.id,
+                    env.block.clone(),
+                    EventData::DcaVaultExecutionSkipped {
+                        reason: ExecutionSkippedReason::PriceDeviationTooHigh {
+                            spot_price,
+                            belief_price,
+                        },
+                    },
+                ),
+            )?;
+
+            return Ok(response.add_attribute("execution_skipped", "price_deviation_too_high"));
+        }
+    }
+
     VAULT_CACHE.save(deps.storage, &vault.id)?;
 
     SWAP_CACHE.save(
@@ -201,10 +461,10 @@ pub fn execute_trigger_handler(
 
     Ok(response.add_submessage(create_swap_message(
         &deps.querier,
-        &env,
+        env,
         &pair,
         adjusted_swap_amount,
-        vault.slippage_tolerance,
+        pair.capped_slippage_tolerance(vault.slippage_tolerance),
         belief_price,
         adjusted_minimum_receive_amount,
         Some(AFTER_SWAP_REPLY_ID),
@@ -218,18 +478,25 @@ mod execute_trigger_tests {
     use crate::constants::{ONE, ONE_MICRON, SWAP_FEE_RATE, TEN, TWO_MICRONS};
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
     use crate::helpers::fees::{get_automation_fee_rate, get_swap_fee_rate};
+    use crate::helpers::price::query_belief_price;
     use crate::helpers::vault::get_swap_amount;
     use crate::msg::ExecuteMsg;
+    use crate::state::circuit_breakers::get_circuit_breaker_state;
     use crate::state::config::update_config;
+    use crate::state::pairs::{find_pair, save_pair};
+    use crate::state::price_history::get_latest_price_samples;
     use crate::state::swap_adjustments::update_swap_adjustment;
     use crate::state::triggers::delete_trigger;
     use crate::state::vaults::get_vault;
     use crate::tests::helpers::{instantiate_contract, setup_vault};
     use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO};
+    use crate::types::circuit_breaker::CircuitBreaker;
     use crate::types::config::Config;
     use crate::types::event::{Event, EventData, ExecutionSkippedReason};
+    use crate::types::pair::{Pair, PairConfig};
     use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
     use crate::types::position_type::PositionType;
+    use crate::types::price_history::PriceSample;
     use crate::types::swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy};
     use crate::types::trigger::TriggerConfiguration;
     use crate::types::vault::{Vault, VaultStatus};
@@ -443,6 +710,31 @@ mod execute_trigger_tests {
         }));
     }
 
+    #[test]
+    fn records_base_denom_price_sample() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        save_pair(deps.as_mut().storage, &Pair::default()).unwrap();
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        assert_eq!(
+            get_latest_price_samples(deps.as_ref().storage, &pair, 10).unwrap(),
+            vec![PriceSample {
+                price: Decimal::one(),
+                timestamp: env.block.time,
+            }]
+        );
+    }
+
     #[test]
     fn for_non_standard_dca_should_simulate_execution() {
         let mut deps = calc_mock_dependencies();
@@ -485,6 +777,7 @@ mod execute_trigger_tests {
             match performance_assessment_strategy.clone() {
                 PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. } =>
                     swapped_amount,
+                _ => panic!("unexpected performance assessment strategy"),
             },
             Coin::new(vault.swap_amount.into(), vault.get_swap_denom()),
         );
@@ -493,6 +786,7 @@ mod execute_trigger_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     received_amount, ..
                 } => received_amount,
+                _ => panic!("unexpected performance assessment strategy"),
             },
             Coin::new(received_amount_after_fee.into(), vault.target_denom)
         );
@@ -517,6 +811,7 @@ mod execute_trigger_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                         received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -550,6 +845,9 @@ mod execute_trigger_tests {
                     base_receive_amount: ONE,
                     multiplier: Decimal::percent(500),
                     increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -581,6 +879,51 @@ mod execute_trigger_tests {
         }));
     }
 
+    #[test]
+    fn with_value_averaging_target_reached_should_publish_execution_skipped_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                started_at: Some(env.block.time),
+                received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_increment: ONE,
+                    max_swap_multiple: Decimal::percent(200),
+                    allow_sells: false,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        let belief_price = Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap();
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::ValueAveragingTargetReached {
+                    current_value: TEN * belief_price,
+                    target_value: ONE,
+                }
+            }
+        }));
+    }
+
     #[test]
     fn with_swap_adjusted_to_zero_should_not_send_swap_message() {
         let mut deps = calc_mock_dependencies();
@@ -597,6 +940,9 @@ mod execute_trigger_tests {
                     base_receive_amount: ONE,
                     multiplier: Decimal::percent(500),
                     increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -853,6 +1199,7 @@ mod execute_trigger_tests {
             match performance_assessment_strategy.clone() {
                 PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. } =>
                     swapped_amount,
+                _ => panic!("unexpected performance assessment strategy"),
             },
             Coin::new(vault.swap_amount.into(), vault.get_swap_denom()),
         );
@@ -861,6 +1208,7 @@ mod execute_trigger_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     received_amount, ..
                 } => received_amount,
+                _ => panic!("unexpected performance assessment strategy"),
             },
             Coin::new(received_amount_after_fee.into(), vault.target_denom)
         );
@@ -887,6 +1235,7 @@ mod execute_trigger_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                         received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -933,6 +1282,7 @@ mod execute_trigger_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                         received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -1055,6 +1405,7 @@ mod execute_trigger_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                         received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -1110,6 +1461,7 @@ mod execute_trigger_tests {
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                         received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                        performance_fee: None,
                     },
                 ),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -1361,4 +1713,216 @@ mod execute_trigger_tests {
             reply_on: ReplyOn::Always,
         }))
     }
+
+    fn with_circuit_breaker(deps: DepsMut, vault: &Vault, auto_pause: bool) -> Pair {
+        let pair = Pair {
+            circuit_breaker: Some(CircuitBreaker {
+                max_deviation: Decimal::percent(10),
+                auto_pause,
+            }),
+            ..find_pair(deps.storage, vault.denoms()).unwrap()
+        };
+
+        save_pair(deps.storage, &pair).unwrap();
+
+        pair
+    }
+
+    #[test]
+    fn with_spot_price_within_circuit_breaker_deviation_should_execute_swap() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let pair = with_circuit_breaker(deps.as_mut(), &vault, false);
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            get_circuit_breaker_state(deps.as_ref().storage, pair.denoms()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn with_spot_price_deviating_beyond_circuit_breaker_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let pair = with_circuit_breaker(deps.as_mut(), &vault, false);
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
+                to_binary(&EstimateSwapExactAmountInResponse {
+                    token_out_amount: (ONE / Uint128::new(2)).to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| matches!(
+            event.data,
+            EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::PriceDeviationTooHigh { .. }
+            }
+        )));
+
+        assert!(events.iter().any(|event| matches!(
+            event.data,
+            EventData::DcaVaultCircuitBreakerTripped { paused: false, .. }
+        )));
+
+        let state = get_circuit_breaker_state(deps.as_ref().storage, pair.denoms())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(state.spot_price, Decimal::from_str("2").unwrap());
+        assert!(!state.paused);
+    }
+
+    #[test]
+    fn with_auto_pause_circuit_breaker_tripped_should_skip_other_vaults_on_pair() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        with_circuit_breaker(deps.as_mut(), &vault, true);
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
+                to_binary(&EstimateSwapExactAmountInResponse {
+                    token_out_amount: (ONE / Uint128::new(2)).to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        let other_vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        deps.querier
+            .update_stargate(|_, _| Err(StdError::generic_err("message not customised")));
+
+        let response = execute_trigger_handler(deps.as_mut(), env, other_vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), other_vault.id, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::PairPaused
+            }));
+    }
+
+    #[test]
+    fn with_disabled_pair_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        save_pair(
+            deps.as_mut().storage,
+            &Pair {
+                config: Some(PairConfig {
+                    enabled: false,
+                    minimum_swap_amount: None,
+                    twap_period: None,
+                    max_slippage_tolerance: None,
+                }),
+                ..pair
+            },
+        )
+        .unwrap();
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::PairDisabled
+            }));
+    }
+
+    #[test]
+    fn with_pair_max_slippage_tolerance_should_cap_swap_slippage_tolerance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(10),
+                ..Vault::default()
+            },
+        );
+
+        let pair = Pair {
+            config: Some(PairConfig {
+                enabled: true,
+                minimum_swap_amount: None,
+                twap_period: None,
+                max_slippage_tolerance: Some(Decimal::percent(1)),
+            }),
+            ..find_pair(deps.as_ref().storage, vault.denoms()).unwrap()
+        };
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let belief_price =
+            query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom()).unwrap();
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        assert!(response.messages.contains(
+            &create_swap_message(
+                &deps.as_ref().querier,
+                &env,
+                &pair,
+                get_swap_amount(&deps.as_ref(), &env, &vault).unwrap(),
+                Decimal::percent(1),
+                belief_price,
+                None,
+                Some(AFTER_SWAP_REPLY_ID),
+                Some(ReplyOn::Always),
+            )
+            .unwrap()
+        ));
+    }
 }
@@ -1,7 +1,8 @@
-use cosmwasm_std::{DepsMut, Response, Uint128};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
 
 use crate::{
     error::ContractError,
+    helpers::validation::assert_sender_is_admin,
     state::{
         pairs::find_pair,
         vaults::{get_vault, update_vault},
@@ -9,7 +10,13 @@ use crate::{
     types::swap_adjustment_strategy::SwapAdjustmentStrategy,
 };
 
-pub fn fix_position_type(deps: DepsMut, vault_id: Uint128) -> Result<Response, ContractError> {
+pub fn fix_position_type(
+    deps: DepsMut,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
     let mut vault = get_vault(deps.storage, vault_id)?;
 
     let mut response = Response::new()
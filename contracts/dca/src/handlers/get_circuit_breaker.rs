@@ -0,0 +1,17 @@
+use crate::msg::CircuitBreakerResponse;
+use crate::state::circuit_breakers::get_circuit_breaker_state;
+use crate::state::pairs::find_pair;
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_circuit_breaker_handler(
+    deps: Deps,
+    base_denom: String,
+    quote_denom: String,
+) -> StdResult<CircuitBreakerResponse> {
+    let pair = find_pair(deps.storage, [base_denom, quote_denom])?;
+
+    Ok(CircuitBreakerResponse {
+        state: get_circuit_breaker_state(deps.storage, pair.denoms())?,
+        circuit_breaker: pair.circuit_breaker,
+    })
+}
@@ -1,8 +1,28 @@
-use crate::{msg::ConfigResponse, state::config::get_config};
+use crate::{
+    msg::{ConfigResponse, PendingAdminResponse, PendingConfigChangesResponse},
+    state::config::{
+        get_config, get_config_change_delay, get_pending_admin, get_performance_fee_rate,
+        get_queued_config_changes,
+    },
+};
 use cosmwasm_std::{Deps, StdResult};
 
 pub fn get_config_handler(deps: Deps) -> StdResult<ConfigResponse> {
     Ok(ConfigResponse {
         config: get_config(deps.storage)?,
+        performance_fee_percent: get_performance_fee_rate(deps.storage)?,
+    })
+}
+
+pub fn get_pending_admin_handler(deps: Deps) -> StdResult<PendingAdminResponse> {
+    Ok(PendingAdminResponse {
+        pending_admin: get_pending_admin(deps.storage)?,
+    })
+}
+
+pub fn get_pending_config_changes_handler(deps: Deps) -> StdResult<PendingConfigChangesResponse> {
+    Ok(PendingConfigChangesResponse {
+        delay_seconds: get_config_change_delay(deps.storage)?,
+        changes: get_queued_config_changes(deps.storage)?,
     })
 }
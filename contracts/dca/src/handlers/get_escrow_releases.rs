@@ -0,0 +1,166 @@
+use crate::{
+    msg::EscrowReleasesResponse,
+    state::{
+        disburse_escrow_tasks::{
+            get_disburse_escrow_task_due_date, get_scheduled_disburse_escrow_tasks,
+        },
+        vaults::get_vault,
+    },
+    types::escrow_release::EscrowRelease,
+};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_escrow_releases_handler(
+    deps: Deps,
+    vault_id: Option<Uint128>,
+    limit: Option<u16>,
+) -> StdResult<EscrowReleasesResponse> {
+    let tasks = match vault_id {
+        Some(vault_id) => get_disburse_escrow_task_due_date(deps.storage, vault_id)?
+            .map_or(vec![], |due_date| vec![(vault_id, due_date)]),
+        None => get_scheduled_disburse_escrow_tasks(deps.storage, limit)?,
+    };
+
+    let releases = tasks
+        .into_iter()
+        .map(|(vault_id, due_date)| {
+            get_vault(deps.storage, vault_id).map(|vault| EscrowRelease {
+                vault_id,
+                due_date,
+                escrowed_amount: vault.escrowed_amount,
+            })
+        })
+        .collect::<StdResult<Vec<EscrowRelease>>>()?;
+
+    Ok(EscrowReleasesResponse { releases })
+}
+
+#[cfg(test)]
+mod get_escrow_releases_tests {
+    use super::get_escrow_releases_handler;
+    use crate::{
+        constants::{ONE, TEN},
+        state::disburse_escrow_tasks::save_disburse_escrow_task,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, DENOM_STAKE},
+        },
+        types::{escrow_release::EscrowRelease, vault::Vault},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Coin, Timestamp, Uint128,
+    };
+
+    #[test]
+    fn returns_scheduled_releases_in_due_date_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault_1 = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        let vault_2 = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                escrowed_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault_1.id,
+            env.block.time.plus_seconds(20),
+        )
+        .unwrap();
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault_2.id,
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+
+        let response = get_escrow_releases_handler(deps.as_ref(), None, None).unwrap();
+
+        assert_eq!(
+            response.releases,
+            vec![
+                EscrowRelease {
+                    vault_id: vault_2.id,
+                    due_date: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
+                    escrowed_amount: vault_2.escrowed_amount,
+                },
+                EscrowRelease {
+                    vault_id: vault_1.id,
+                    due_date: Timestamp::from_seconds(env.block.time.plus_seconds(20).seconds()),
+                    escrowed_amount: vault_1.escrowed_amount,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn with_vault_id_returns_only_that_vaults_release() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id + Uint128::one(),
+            env.block.time.plus_seconds(5),
+        )
+        .unwrap();
+
+        let response = get_escrow_releases_handler(deps.as_ref(), Some(vault.id), None).unwrap();
+
+        assert_eq!(
+            response.releases,
+            vec![EscrowRelease {
+                vault_id: vault.id,
+                due_date: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
+                escrowed_amount: vault.escrowed_amount,
+            }]
+        );
+    }
+
+    #[test]
+    fn with_vault_id_and_no_scheduled_release_returns_nothing() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let response = get_escrow_releases_handler(deps.as_ref(), Some(vault.id), None).unwrap();
+
+        assert!(response.releases.is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{
+    constants::LATEST_STORAGE_VERSION,
+    msg::MigrationStatusResponse,
+    state::migrations::{get_migration_progress, get_storage_version},
+};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_migration_status_handler(deps: Deps) -> StdResult<MigrationStatusResponse> {
+    Ok(MigrationStatusResponse {
+        storage_version: get_storage_version(deps.storage)?,
+        latest_storage_version: LATEST_STORAGE_VERSION,
+        progress: get_migration_progress(deps.storage)?,
+    })
+}
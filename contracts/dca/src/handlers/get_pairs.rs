@@ -1,4 +1,8 @@
-use crate::{msg::PairsResponse, state::pairs::get_pairs, types::pair::Pair};
+use crate::{
+    msg::PairsResponse,
+    state::pairs::{get_pairs, get_pairs_by_denom},
+    types::pair::Pair,
+};
 use cosmwasm_std::{Deps, StdResult};
 
 pub fn get_pairs_handler(
@@ -11,15 +15,22 @@ pub fn get_pairs_handler(
     })
 }
 
+pub fn get_pairs_by_denom_handler(deps: Deps, denom: String) -> StdResult<PairsResponse> {
+    Ok(PairsResponse {
+        pairs: get_pairs_by_denom(deps.storage, denom),
+    })
+}
+
 #[cfg(test)]
 mod get_pairs_tests {
     use crate::{
         contract::query,
         handlers::create_pair::create_pair_handler,
         msg::{PairsResponse, QueryMsg},
+        state::pairs::save_pair,
         tests::{
             helpers::instantiate_contract,
-            mocks::{calc_mock_dependencies, ADMIN},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UATOM, DENOM_UION},
         },
         types::pair::Pair,
     };
@@ -44,6 +55,8 @@ mod get_pairs_tests {
             pair.base_denom.clone(),
             pair.quote_denom.clone(),
             pair.route.clone(),
+            None,
+            None,
         )
         .unwrap();
 
@@ -83,4 +96,36 @@ mod get_pairs_tests {
 
         assert_eq!(response.pairs.len(), 0);
     }
+
+    #[test]
+    fn get_pairs_by_denom_should_only_return_pairs_containing_denom() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let pair = Pair::default();
+
+        let other_pair = Pair {
+            base_denom: DENOM_UATOM.to_string(),
+            quote_denom: DENOM_UION.to_string(),
+            ..Pair::default()
+        };
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+        save_pair(deps.as_mut().storage, &other_pair).unwrap();
+
+        let binary = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::GetPairsByDenom {
+                denom: DENOM_STAKE.to_string(),
+            },
+        )
+        .unwrap();
+        let response = from_binary::<PairsResponse>(&binary).unwrap();
+
+        assert_eq!(response.pairs, vec![pair]);
+    }
 }
@@ -0,0 +1,194 @@
+use crate::{
+    helpers::{price::get_price_candles, validation::assert_price_history_range_is_valid},
+    msg::PriceHistoryResponse,
+    state::{pairs::find_pair, price_history::get_price_samples},
+    types::price_history::PriceSample,
+};
+use cosmwasm_std::{Deps, StdResult, Timestamp};
+
+pub fn get_price_history_handler(
+    deps: Deps,
+    base_denom: String,
+    quote_denom: String,
+    from: Option<Timestamp>,
+    to: Option<Timestamp>,
+    resolution: u64,
+) -> StdResult<PriceHistoryResponse> {
+    assert_price_history_range_is_valid(from, to, resolution)?;
+
+    let pair = find_pair(deps.storage, [base_denom, quote_denom])?;
+
+    let samples = get_price_samples(deps.storage, &pair)?
+        .into_iter()
+        .filter(|sample| {
+            from.is_none_or(|from| sample.timestamp >= from)
+                && to.is_none_or(|to| sample.timestamp <= to)
+        })
+        .collect::<Vec<PriceSample>>();
+
+    Ok(PriceHistoryResponse {
+        base_denom: pair.base_denom,
+        quote_denom: pair.quote_denom,
+        candles: get_price_candles(&samples, resolution),
+    })
+}
+
+#[cfg(test)]
+mod get_price_history_tests {
+    use super::*;
+    use crate::{
+        state::{pairs::save_pair, price_history::record_price},
+        types::{pair::Pair, price_history::PriceCandle},
+    };
+    use cosmwasm_std::{testing::mock_dependencies, Decimal, DepsMut};
+
+    fn setup_prices(deps: DepsMut, prices: &[(u64, u64)]) {
+        save_pair(deps.storage, &Pair::default()).unwrap();
+
+        for (seconds, price) in prices {
+            record_price(
+                deps.storage,
+                &Pair::default(),
+                Decimal::percent(*price),
+                Timestamp::from_seconds(*seconds),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn with_zero_resolution_fails() {
+        let deps = mock_dependencies();
+        let pair = Pair::default();
+
+        let err = get_price_history_handler(
+            deps.as_ref(),
+            pair.base_denom,
+            pair.quote_denom,
+            None,
+            None,
+            0,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: price history resolution must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_from_after_to_fails() {
+        let deps = mock_dependencies();
+        let pair = Pair::default();
+
+        let err = get_price_history_handler(
+            deps.as_ref(),
+            pair.base_denom,
+            pair.quote_denom,
+            Some(Timestamp::from_seconds(100)),
+            Some(Timestamp::from_seconds(50)),
+            60,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: price history from must not be after to"
+        );
+    }
+
+    #[test]
+    fn with_missing_pair_fails() {
+        let deps = mock_dependencies();
+        let pair = Pair::default();
+
+        get_price_history_handler(
+            deps.as_ref(),
+            pair.base_denom,
+            pair.quote_denom,
+            None,
+            None,
+            60,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn gets_candles_for_each_bucket_with_samples() {
+        let mut deps = mock_dependencies();
+        let pair = Pair::default();
+
+        setup_prices(
+            deps.as_mut(),
+            &[(60, 100), (70, 120), (80, 90), (110, 110), (300, 130)],
+        );
+
+        let response = get_price_history_handler(
+            deps.as_ref(),
+            pair.quote_denom.clone(),
+            pair.base_denom.clone(),
+            None,
+            None,
+            60,
+        )
+        .unwrap();
+
+        assert_eq!(response.base_denom, pair.base_denom);
+        assert_eq!(response.quote_denom, pair.quote_denom);
+        assert_eq!(
+            response.candles,
+            vec![
+                PriceCandle {
+                    open_time: Timestamp::from_seconds(60),
+                    open: Decimal::percent(100),
+                    high: Decimal::percent(120),
+                    low: Decimal::percent(90),
+                    close: Decimal::percent(110),
+                    samples: 4,
+                },
+                PriceCandle {
+                    open_time: Timestamp::from_seconds(300),
+                    open: Decimal::percent(130),
+                    high: Decimal::percent(130),
+                    low: Decimal::percent(130),
+                    close: Decimal::percent(130),
+                    samples: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn gets_candles_within_range() {
+        let mut deps = mock_dependencies();
+        let pair = Pair::default();
+
+        setup_prices(
+            deps.as_mut(),
+            &[(60, 100), (70, 120), (80, 90), (110, 110), (300, 130)],
+        );
+
+        let response = get_price_history_handler(
+            deps.as_ref(),
+            pair.base_denom,
+            pair.quote_denom,
+            Some(Timestamp::from_seconds(70)),
+            Some(Timestamp::from_seconds(80)),
+            60,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.candles,
+            vec![PriceCandle {
+                open_time: Timestamp::from_seconds(60),
+                open: Decimal::percent(120),
+                high: Decimal::percent(120),
+                low: Decimal::percent(90),
+                close: Decimal::percent(90),
+                samples: 2,
+            }]
+        );
+    }
+}
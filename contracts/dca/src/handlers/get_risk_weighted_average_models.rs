@@ -0,0 +1,13 @@
+use crate::{
+    msg::RiskWeightedAverageModelsResponse,
+    state::risk_weighted_average_models::get_risk_weighted_average_models,
+};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_risk_weighted_average_models_handler(
+    deps: Deps,
+) -> StdResult<RiskWeightedAverageModelsResponse> {
+    Ok(RiskWeightedAverageModelsResponse {
+        models: get_risk_weighted_average_models(deps.storage)?,
+    })
+}
@@ -0,0 +1,16 @@
+use crate::msg::{RoleMembersResponse, RolesResponse};
+use crate::state::roles::{get_role_members, get_roles};
+use crate::types::role::Role;
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_roles_handler(deps: Deps, address: Addr) -> StdResult<RolesResponse> {
+    Ok(RolesResponse {
+        roles: get_roles(deps.storage, &address),
+    })
+}
+
+pub fn get_role_members_handler(deps: Deps, role: Role) -> StdResult<RoleMembersResponse> {
+    Ok(RoleMembersResponse {
+        addresses: get_role_members(deps.storage, &role)?,
+    })
+}
@@ -0,0 +1,133 @@
+use crate::{
+    msg::SolvencyReportResponse, state::vault_totals::get_all_vault_totals,
+    types::solvency::DenomSolvency,
+};
+use cosmwasm_std::{Deps, Env, StdResult};
+
+pub fn get_solvency_report_handler(deps: Deps, env: Env) -> StdResult<SolvencyReportResponse> {
+    let denoms = get_all_vault_totals(deps.storage)?
+        .into_iter()
+        .map(|(denom, totals)| {
+            let contract_balance = deps
+                .querier
+                .query_balance(env.contract.address.clone(), denom.clone())?
+                .amount;
+
+            Ok(DenomSolvency {
+                denom,
+                total_balance: totals.balance,
+                total_escrowed: totals.escrowed,
+                contract_balance,
+                surplus: contract_balance.saturating_sub(totals.liabilities()),
+                deficit: totals.liabilities().saturating_sub(contract_balance),
+            })
+        })
+        .collect::<StdResult<Vec<DenomSolvency>>>()?;
+
+    Ok(SolvencyReportResponse {
+        is_solvent: denoms.iter().all(|denom| denom.deficit.is_zero()),
+        denoms,
+    })
+}
+
+#[cfg(test)]
+mod get_solvency_report_tests {
+    use super::*;
+    use crate::{
+        state::vaults::update_vault,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO},
+        },
+        types::vault::Vault,
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Coin, Uint128,
+    };
+
+    #[test]
+    fn reports_surplus_and_deficit_per_denom() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(1000, DENOM_UOSMO),
+                escrowed_amount: Coin::new(100, DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![Coin::new(1200, DENOM_UOSMO), Coin::new(40, DENOM_STAKE)],
+        );
+
+        let response = get_solvency_report_handler(deps.as_ref(), env).unwrap();
+
+        assert!(!response.is_solvent);
+        assert_eq!(
+            response.denoms,
+            vec![
+                DenomSolvency {
+                    denom: DENOM_STAKE.to_string(),
+                    total_balance: Uint128::zero(),
+                    total_escrowed: Uint128::new(100),
+                    contract_balance: Uint128::new(40),
+                    surplus: Uint128::zero(),
+                    deficit: Uint128::new(60),
+                },
+                DenomSolvency {
+                    denom: DENOM_UOSMO.to_string(),
+                    total_balance: Uint128::new(1000),
+                    total_escrowed: Uint128::zero(),
+                    contract_balance: Uint128::new(1200),
+                    surplus: Uint128::new(200),
+                    deficit: Uint128::zero(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tracks_balance_changes_made_through_vault_updates() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(1000, DENOM_UOSMO),
+                ..Vault::default()
+            },
+        );
+
+        update_vault(
+            deps.as_mut().storage,
+            Vault {
+                balance: Coin::new(400, DENOM_UOSMO),
+                ..vault
+            },
+        )
+        .unwrap();
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![Coin::new(400, DENOM_UOSMO)],
+        );
+
+        let response = get_solvency_report_handler(deps.as_ref(), env).unwrap();
+
+        assert!(response.is_solvent);
+        assert_eq!(response.denoms[0].total_balance, Uint128::new(400));
+        assert_eq!(response.denoms[0].surplus, Uint128::zero());
+    }
+}
@@ -1,13 +1,255 @@
 use crate::{
-    state::swap_adjustments::get_swap_adjustment,
+    helpers::{
+        swap_adjustments::get_publisher_submission_statuses, validation::assert_page_limit_is_valid,
+    },
+    msg::{
+        SwapAdjustmentHistoryResponse, SwapAdjustmentPublishersResponse, SwapAdjustmentResponse,
+        SwapAdjustmentsResponse,
+    },
+    state::{
+        config::get_config,
+        swap_adjustments::{
+            get_swap_adjustment_aggregation, get_swap_adjustment_history,
+            get_swap_adjustment_status, get_swap_adjustment_statuses,
+        },
+    },
     types::swap_adjustment_strategy::SwapAdjustmentStrategy,
 };
-use cosmwasm_std::{Decimal, Deps, Env, StdResult};
+use cosmwasm_std::{Deps, Env, StdResult, Timestamp};
 
 pub fn get_swap_adjustment_handler(
-    deps: &Deps,
-    env: &Env,
+    deps: Deps,
+    env: Env,
     strategy: SwapAdjustmentStrategy,
-) -> StdResult<Decimal> {
-    Ok(get_swap_adjustment(deps.storage, strategy, env.block.time))
+) -> StdResult<SwapAdjustmentResponse> {
+    Ok(SwapAdjustmentResponse {
+        adjustment: get_swap_adjustment_status(deps.storage, strategy, env.block.time)?,
+    })
+}
+
+pub fn get_swap_adjustments_handler(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u16>,
+) -> StdResult<SwapAdjustmentsResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    Ok(SwapAdjustmentsResponse {
+        adjustments: get_swap_adjustment_statuses(
+            deps.storage,
+            env.block.time,
+            start_after,
+            limit.unwrap_or(get_config(deps.storage)?.default_page_limit),
+        )?,
+    })
+}
+
+pub fn get_swap_adjustment_history_handler(
+    deps: Deps,
+    strategy: SwapAdjustmentStrategy,
+    start_after: Option<Timestamp>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<SwapAdjustmentHistoryResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    Ok(SwapAdjustmentHistoryResponse {
+        history: get_swap_adjustment_history(
+            deps.storage,
+            &strategy,
+            start_after,
+            limit.unwrap_or(get_config(deps.storage)?.default_page_limit),
+            reverse.unwrap_or(false),
+        )?,
+    })
+}
+
+pub fn get_swap_adjustment_publishers_handler(
+    deps: Deps,
+    env: Env,
+    strategy: SwapAdjustmentStrategy,
+) -> StdResult<SwapAdjustmentPublishersResponse> {
+    let (publishers, median) =
+        get_publisher_submission_statuses(deps.storage, &strategy, env.block.time)?;
+
+    Ok(SwapAdjustmentPublishersResponse {
+        aggregation: get_swap_adjustment_aggregation(deps.storage)?,
+        median,
+        publishers,
+    })
+}
+
+#[cfg(test)]
+mod get_swap_adjustments_tests {
+    use super::*;
+    use crate::{
+        state::{
+            roles::grant_role,
+            swap_adjustments::{
+                save_swap_adjustment_aggregation, save_swap_adjustment_submission,
+                update_swap_adjustment,
+            },
+        },
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        types::{
+            position_type::PositionType,
+            role::Role,
+            swap_adjustment::{
+                PublisherSubmissionStatus, SwapAdjustmentAggregation, SwapAdjustmentSubmission,
+            },
+            swap_adjustment_strategy::BaseDenom,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Decimal,
+    };
+
+    #[test]
+    fn gets_fresh_and_stale_swap_adjustments() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let fresh_strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        let unmodelled_strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Ethereum,
+            position_type: PositionType::Enter,
+        };
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            fresh_strategy.clone(),
+            Decimal::percent(90),
+            env.block.time,
+        )
+        .unwrap();
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            unmodelled_strategy.clone(),
+            Decimal::percent(80),
+            env.block.time,
+        )
+        .unwrap();
+
+        let adjustments = get_swap_adjustments_handler(deps.as_ref(), env, None, None)
+            .unwrap()
+            .adjustments;
+
+        assert_eq!(adjustments.len(), 2);
+        assert_eq!(adjustments[0].key, fresh_strategy.key());
+        assert_eq!(adjustments[0].value, Decimal::percent(90));
+        assert!(!adjustments[0].is_stale);
+        assert_eq!(adjustments[1].key, unmodelled_strategy.key());
+        assert_eq!(adjustments[1].value, Decimal::one());
+        assert_eq!(adjustments[1].published_value, Some(Decimal::percent(80)));
+        assert!(adjustments[1].is_stale);
+    }
+
+    #[test]
+    fn gets_swap_adjustments_after_start_after() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            strategy.clone(),
+            Decimal::percent(90),
+            env.block.time,
+        )
+        .unwrap();
+
+        let adjustments =
+            get_swap_adjustments_handler(deps.as_ref(), env, Some(strategy.key()), None)
+                .unwrap()
+                .adjustments;
+
+        assert!(adjustments.is_empty());
+    }
+
+    #[test]
+    fn gets_publisher_submission_statuses() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_swap_adjustment_aggregation(
+            deps.as_mut().storage,
+            &Some(SwapAdjustmentAggregation {
+                quorum: 1,
+                max_deviation: Decimal::percent(20),
+            }),
+        )
+        .unwrap();
+
+        let publisher = Addr::unchecked("publisher-1");
+        let idle_publisher = Addr::unchecked("publisher-2");
+
+        for address in [&publisher, &idle_publisher] {
+            grant_role(
+                deps.as_mut().storage,
+                &Role::SwapAdjustmentPublisher,
+                address,
+            )
+            .unwrap();
+        }
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        save_swap_adjustment_submission(
+            deps.as_mut().storage,
+            &strategy,
+            &publisher,
+            Decimal::percent(90),
+            env.block.time,
+        )
+        .unwrap();
+
+        let response =
+            get_swap_adjustment_publishers_handler(deps.as_ref(), env.clone(), strategy).unwrap();
+
+        assert_eq!(response.median, Some(Decimal::percent(90)));
+        assert_eq!(
+            response.publishers,
+            vec![
+                PublisherSubmissionStatus {
+                    publisher,
+                    submission: Some(SwapAdjustmentSubmission {
+                        value: Decimal::percent(90),
+                        submitted_at: env.block.time,
+                    }),
+                    is_fresh: true,
+                    is_outlier: false,
+                },
+                PublisherSubmissionStatus {
+                    publisher: idle_publisher,
+                    submission: None,
+                    is_fresh: false,
+                    is_outlier: false,
+                },
+            ]
+        );
+    }
 }
@@ -25,10 +25,12 @@ pub fn get_vault_performance_handler(
                 vault_id
             ),
         }),
-        |_| {
+        |strategy| {
             Ok(VaultPerformanceResponse {
                 fee: get_performance_fee(&vault, current_price)?,
                 factor: get_performance_factor(&vault, current_price)?,
+                fee_rate: strategy.performance_fee_rate(),
+                high_water_mark: strategy.high_water_mark(),
             })
         },
     )
@@ -44,13 +46,14 @@ mod get_vault_performance_tests {
             mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO},
         },
         types::{
-            performance_assessment_strategy::PerformanceAssessmentStrategy,
-            swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
+            performance_assessment_strategy::{PerformanceAssessmentStrategy, PerformanceFee},
+            swap_adjustment_strategy::SwapAdjustmentStrategy,
+            vault::Vault,
         },
     };
     use cosmwasm_std::{
         testing::{mock_env, mock_info},
-        Coin, Decimal,
+        Coin, Decimal, Uint128,
     };
 
     #[test]
@@ -82,6 +85,7 @@ mod get_vault_performance_tests {
         let performance_assessment_strategy = PerformanceAssessmentStrategy::CompareToStandardDca {
             swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
             received_amount: Coin::new(standard_received_amount.into(), DENOM_STAKE),
+            performance_fee: None,
         };
 
         let vault = setup_vault(
@@ -110,4 +114,42 @@ mod get_vault_performance_tests {
             )
         );
     }
+
+    #[test]
+    fn returns_snapshotted_fee_rate_and_high_water_mark() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                deposited_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_STAKE),
+                escrowed_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                        performance_fee: PerformanceFee {
+                            rate: Decimal::percent(10),
+                            high_water_mark: Some(Uint128::new(100)),
+                            settled_added_value: None,
+                        },
+                    },
+                ),
+                escrow_level: Decimal::percent(10),
+                ..Vault::default()
+            },
+        );
+
+        let response = get_vault_performance_handler(deps.as_ref(), &env, vault.id).unwrap();
+
+        assert_eq!(response.fee_rate, Decimal::percent(10));
+        assert_eq!(response.high_water_mark, Some(Uint128::new(100)));
+        assert!(response.factor > Decimal::one());
+        assert!(response.fee.amount > Uint128::zero());
+    }
 }
@@ -0,0 +1,117 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{assert_address_is_valid, assert_sender_is_admin};
+use crate::state::roles::grant_role;
+use crate::types::role::Role;
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+
+pub fn grant_role_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_address_is_valid(deps.as_ref(), &address, "role member")?;
+
+    grant_role(deps.storage, &role, &address)?;
+
+    Ok(Response::new()
+        .add_attribute("grant_role", "true")
+        .add_attribute("role", role.key())
+        .add_attribute("address", address))
+}
+
+#[cfg(test)]
+mod grant_role_tests {
+    use super::*;
+    use crate::handlers::create_custom_swap_fee::create_custom_swap_fee_handler;
+    use crate::handlers::update_swap_adjustment_handler::update_swap_adjustment_handler;
+    use crate::state::roles::has_role;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, USER};
+    use crate::types::position_type::PositionType;
+    use crate::types::swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy};
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = grant_role_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            Role::FeeManager,
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn grants_role() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        grant_role_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Role::FeeManager,
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        assert!(has_role(
+            deps.as_ref().storage,
+            &Role::FeeManager,
+            &Addr::unchecked(USER)
+        ));
+    }
+
+    #[test]
+    fn swap_adjustment_publisher_cannot_update_fees() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        grant_role_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Role::SwapAdjustmentPublisher,
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        update_swap_adjustment_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id: 30,
+                base_denom: BaseDenom::Bitcoin,
+                position_type: PositionType::Enter,
+            },
+            Decimal::percent(125),
+        )
+        .unwrap();
+
+        let err = create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            DENOM_STAKE.to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+}
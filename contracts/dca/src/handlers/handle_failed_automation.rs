@@ -5,9 +5,12 @@ use crate::{
         events::create_event,
         vaults::get_vault,
     },
-    types::event::{EventBuilder, EventData},
+    types::{
+        destination::DestinationAction,
+        event::{EventBuilder, EventData},
+    },
 };
-use cosmwasm_std::{BankMsg, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult};
+use cosmwasm_std::{BankMsg, Coin, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult};
 
 pub fn handle_failed_automation_handler(
     deps: DepsMut,
@@ -24,8 +27,58 @@ pub fn handle_failed_automation_handler(
     let destination_num = vault.destinations.len() - cache.len();
 
     Ok(match reply.result {
-        SubMsgResult::Ok(_) => Response::new()
-            .add_attribute(format!("destination_msg_{}", destination_num), "succeeded"),
+        SubMsgResult::Ok(_) => {
+            let amount = entry
+                .funds
+                .first()
+                .cloned()
+                .unwrap_or_else(|| Coin::new(0, vault.target_denom.clone()));
+
+            let event_data = match entry.destination.action {
+                DestinationAction::Send => None,
+                DestinationAction::ExecuteContract { .. } => {
+                    Some(EventData::DcaVaultContractExecuted {
+                        contract_address: entry.destination.address,
+                        amount,
+                    })
+                }
+                DestinationAction::Delegate { validator_address } => {
+                    Some(EventData::DcaVaultFundsDelegated {
+                        delegator_address: entry.destination.address,
+                        validator_address,
+                        amount,
+                    })
+                }
+                DestinationAction::DelegateToValidators { validators, .. } => {
+                    Some(EventData::DcaVaultFundsDelegatedToValidators {
+                        delegator_address: entry.destination.address,
+                        validators,
+                        amount,
+                    })
+                }
+                DestinationAction::ProvideLiquidity {
+                    pool_id, duration, ..
+                } => Some(EventData::DcaVaultLiquidityProvided {
+                    provider_address: entry.destination.address,
+                    pool_id,
+                    duration,
+                    amount,
+                }),
+                DestinationAction::DepositToVault { vault_id } => {
+                    Some(EventData::DcaVaultFundsDepositedToVault { vault_id, amount })
+                }
+            };
+
+            if let Some(event_data) = event_data {
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(vault_id, env.block, event_data),
+                )?;
+            }
+
+            Response::new()
+                .add_attribute(format!("destination_msg_{}", destination_num), "succeeded")
+        }
         SubMsgResult::Err(_) => {
             create_event(
                 deps.storage,
@@ -59,10 +112,10 @@ mod handle_failed_automation_handler_tests {
         state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
         tests::{
             helpers::{instantiate_contract, setup_vault},
-            mocks::ADMIN,
+            mocks::{ADMIN, VALIDATOR},
         },
         types::{
-            destination::Destination,
+            destination::{Destination, DestinationAction},
             event::{EventBuilder, EventData},
             vault::Vault,
         },
@@ -79,23 +132,25 @@ mod handle_failed_automation_handler_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        let callback = to_binary(&WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: to_binary("test").unwrap(),
+            funds: vec![],
+        })
+        .unwrap();
+
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
         ];
 
@@ -108,7 +163,13 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(
+            deps.as_mut().storage,
+            &env.contract.address,
+            &vault,
+            vault.swap_amount,
+        )
+        .unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -133,7 +194,7 @@ mod handle_failed_automation_handler_tests {
                 msg: SubMsg::reply_always(
                     WasmMsg::Execute {
                         contract_addr: destinations[1].address.to_string(),
-                        msg: destinations[1].msg.clone().unwrap(),
+                        msg: callback.clone(),
                         funds: vec![Coin::new(
                             (vault.swap_amount * destinations[1].allocation).into(),
                             vault.target_denom.clone()
@@ -145,6 +206,7 @@ mod handle_failed_automation_handler_tests {
                     (vault.swap_amount * destinations[1].allocation).into(),
                     vault.target_denom
                 )],
+                destination: destinations[1].clone(),
             }])
         );
     }
@@ -154,23 +216,25 @@ mod handle_failed_automation_handler_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        let callback = to_binary(&WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: to_binary("test").unwrap(),
+            funds: vec![],
+        })
+        .unwrap();
+
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
         ];
 
@@ -183,7 +247,13 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(
+            deps.as_mut().storage,
+            &env.contract.address,
+            &vault,
+            vault.swap_amount,
+        )
+        .unwrap();
 
         let response = handle_failed_automation_handler(
             deps.as_mut(),
@@ -206,23 +276,25 @@ mod handle_failed_automation_handler_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        let callback = to_binary(&WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: to_binary("test").unwrap(),
+            funds: vec![],
+        })
+        .unwrap();
+
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
         ];
 
@@ -235,7 +307,13 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(
+            deps.as_mut().storage,
+            &env.contract.address,
+            &vault,
+            vault.swap_amount,
+        )
+        .unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -257,7 +335,7 @@ mod handle_failed_automation_handler_tests {
                 msg: SubMsg::reply_always(
                     WasmMsg::Execute {
                         contract_addr: destinations[1].address.to_string(),
-                        msg: destinations[1].msg.clone().unwrap(),
+                        msg: callback.clone(),
                         funds: vec![Coin::new(
                             (vault.swap_amount * destinations[1].allocation).into(),
                             vault.target_denom.clone()
@@ -269,6 +347,7 @@ mod handle_failed_automation_handler_tests {
                     (vault.swap_amount * destinations[1].allocation).into(),
                     vault.target_denom
                 )],
+                destination: destinations[1].clone(),
             }])
         );
     }
@@ -280,30 +359,27 @@ mod handle_failed_automation_handler_tests {
 
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
+        let callback = to_binary(&WasmMsg::Execute {
+            contract_addr: "contract2".to_string(),
+            msg: to_binary("test").unwrap(),
+            funds: vec![],
+        })
+        .unwrap();
+
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("contract1"),
                 allocation: Decimal::percent(30),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
             Destination {
                 address: Addr::unchecked("contract2"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
         ];
 
@@ -316,7 +392,13 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(
+            deps.as_mut().storage,
+            &env.contract.address,
+            &vault,
+            vault.swap_amount,
+        )
+        .unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -341,7 +423,7 @@ mod handle_failed_automation_handler_tests {
                     msg: SubMsg::reply_always(
                         WasmMsg::Execute {
                             contract_addr: destinations[0].address.to_string(),
-                            msg: destinations[0].msg.clone().unwrap(),
+                            msg: callback.clone(),
                             funds: vec![Coin::new(
                                 (vault.swap_amount * destinations[0].allocation).into(),
                                 vault.target_denom.clone()
@@ -366,30 +448,27 @@ mod handle_failed_automation_handler_tests {
 
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
+        let callback = to_binary(&WasmMsg::Execute {
+            contract_addr: "contract2".to_string(),
+            msg: to_binary("test").unwrap(),
+            funds: vec![],
+        })
+        .unwrap();
+
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("contract1"),
                 allocation: Decimal::percent(30),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
             Destination {
                 address: Addr::unchecked("contract2"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract2".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
         ];
 
@@ -402,7 +481,13 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(
+            deps.as_mut().storage,
+            &env.contract.address,
+            &vault,
+            vault.swap_amount,
+        )
+        .unwrap();
 
         let response = handle_failed_automation_handler(
             deps.as_mut(),
@@ -425,4 +510,66 @@ mod handle_failed_automation_handler_tests {
             })]
         );
     }
+
+    #[test]
+    fn creates_funds_delegated_event_on_success() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("delegator"),
+                    allocation: Decimal::percent(100),
+                    action: DestinationAction::Delegate {
+                        validator_address: Addr::unchecked(VALIDATOR),
+                    },
+                }],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(
+            deps.as_mut().storage,
+            &env.contract.address,
+            &vault,
+            vault.swap_amount,
+        )
+        .unwrap();
+
+        handle_failed_automation_handler(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events[0],
+            EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultFundsDelegated {
+                    delegator_address: Addr::unchecked("delegator"),
+                    validator_address: Addr::unchecked(VALIDATOR),
+                    amount: Coin::new(vault.swap_amount.into(), vault.target_denom),
+                },
+            )
+            .build(1)
+        )
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    constants::LATEST_STORAGE_VERSION,
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
     helpers::validation::{
@@ -9,7 +10,7 @@ use crate::{
         assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_twap_period_is_valid,
     },
     msg::InstantiateMsg,
-    state::config::update_config,
+    state::{config::update_config, migrations::save_storage_version},
     types::config::Config,
 };
 use cosmwasm_std::{DepsMut, Response};
@@ -50,6 +51,7 @@ pub fn instantiate_handler(deps: DepsMut, msg: InstantiateMsg) -> Result<Respons
     )?;
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    save_storage_version(deps.storage, LATEST_STORAGE_VERSION)?;
 
     Ok(Response::new()
         .add_attribute("instantiate", "true")
@@ -1,12 +1,21 @@
 use crate::{
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
+    helpers::{
+        migrations::{run_migration_batch, start_migration},
+        validation::assert_migration_limit_is_valid,
+    },
     msg::MigrateMsg,
+    state::config::{get_config, save_contract_address},
 };
-use cosmwasm_std::{DepsMut, Response, StdError};
+use cosmwasm_std::{DepsMut, Env, Response, StdError};
 use cw2::{get_contract_version, set_contract_version};
 
-pub fn migrate_handler(deps: DepsMut, msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate_handler(
+    deps: DepsMut,
+    env: Env,
+    msg: MigrateMsg,
+) -> Result<Response, ContractError> {
     let contract_version = get_contract_version(deps.storage)?;
 
     if contract_version.contract != CONTRACT_NAME {
@@ -18,9 +27,166 @@ pub fn migrate_handler(deps: DepsMut, msg: MigrateMsg) -> Result<Response, Contr
         return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
     }
 
+    let limit = msg
+        .limit
+        .unwrap_or(get_config(deps.storage)?.default_page_limit);
+
+    assert_migration_limit_is_valid(limit)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    save_contract_address(deps.storage, &env.contract.address)?;
+
+    start_migration(deps.storage)?;
+    let progress = run_migration_batch(deps.storage, limit)?;
 
     Ok(Response::new()
         .add_attribute("migrate", "true")
-        .add_attribute("msg", format!("{:#?}", msg)))
+        .add_attribute("msg", format!("{:#?}", msg))
+        .add_attribute("migration_complete", progress.is_none().to_string()))
+}
+
+#[cfg(test)]
+mod migrate_tests {
+    use super::*;
+    use crate::{
+        constants::LATEST_STORAGE_VERSION,
+        handlers::continue_migration::continue_migration_handler,
+        helpers::migrations::start_migration,
+        state::{
+            migrations::{get_migration_progress, get_storage_version, save_storage_version},
+            vault_totals::get_vault_totals,
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::ADMIN,
+        },
+        types::{migration::MigrationProgress, solvency::VaultTotals, vault::Vault},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Uint128,
+    };
+
+    #[test]
+    fn with_limit_of_zero_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err =
+            migrate_handler(deps.as_mut(), mock_env(), MigrateMsg { limit: Some(0) }).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: migration limit must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_latest_storage_version_completes_immediately() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        migrate_handler(deps.as_mut(), mock_env(), MigrateMsg { limit: None }).unwrap();
+
+        assert_eq!(
+            get_storage_version(deps.as_ref().storage).unwrap(),
+            LATEST_STORAGE_VERSION
+        );
+        assert_eq!(get_migration_progress(deps.as_ref().storage).unwrap(), None);
+    }
+
+    #[test]
+    fn with_older_storage_version_resumes_over_multiple_calls() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for id in 1..=3 {
+            setup_vault(
+                deps.as_mut(),
+                env.clone(),
+                Vault {
+                    id: Uint128::new(id),
+                    ..Vault::default()
+                },
+            );
+        }
+
+        save_storage_version(deps.as_mut().storage, 1).unwrap();
+
+        migrate_handler(deps.as_mut(), mock_env(), MigrateMsg { limit: Some(2) }).unwrap();
+
+        assert_eq!(get_storage_version(deps.as_ref().storage).unwrap(), 1);
+        assert_eq!(
+            get_migration_progress(deps.as_ref().storage).unwrap(),
+            Some(MigrationProgress {
+                version: 2,
+                cursor: Some(2),
+            })
+        );
+
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert_eq!(get_storage_version(deps.as_ref().storage).unwrap(), 2);
+        assert_eq!(
+            get_migration_progress(deps.as_ref().storage).unwrap(),
+            Some(MigrationProgress {
+                version: 3,
+                cursor: None,
+            })
+        );
+
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert_eq!(get_storage_version(deps.as_ref().storage).unwrap(), 3);
+        assert_eq!(
+            get_migration_progress(deps.as_ref().storage).unwrap(),
+            Some(MigrationProgress {
+                version: 4,
+                cursor: None,
+            })
+        );
+
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert_eq!(
+            get_storage_version(deps.as_ref().storage).unwrap(),
+            LATEST_STORAGE_VERSION
+        );
+        assert_eq!(get_migration_progress(deps.as_ref().storage).unwrap(), None);
+    }
+
+    #[test]
+    fn backfills_vault_totals_for_existing_vaults() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_storage_version(deps.as_mut().storage, 2).unwrap();
+        start_migration(deps.as_mut().storage).unwrap();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        setup_vault(deps.as_mut(), env, Vault::default());
+
+        assert_eq!(
+            get_vault_totals(deps.as_ref().storage, vault.balance.denom.clone()).unwrap(),
+            VaultTotals::default()
+        );
+
+        continue_migration_handler(deps.as_mut(), mock_info(ADMIN, &[]), None).unwrap();
+
+        assert_eq!(
+            get_vault_totals(deps.as_ref().storage, vault.balance.denom.clone())
+                .unwrap()
+                .balance,
+            vault.balance.amount * Uint128::new(2)
+        );
+    }
 }
@@ -1,31 +1,58 @@
+pub mod accept_admin;
+pub mod cancel_admin_proposal;
+pub mod cancel_queued_config_change;
 pub mod cancel_vault;
+pub mod continue_migration;
 pub mod create_custom_swap_fee;
 pub mod create_pair;
 pub mod create_pairs;
 pub mod create_vault;
+pub mod delete_pair;
 pub mod deposit;
 pub mod disburse_escrow;
 pub mod disburse_funds;
+pub mod execute_queued_config_change;
 pub mod execute_trigger;
 pub mod fix_position_type;
+pub mod get_circuit_breaker;
 pub mod get_config;
 pub mod get_custom_swap_fees;
 pub mod get_disburse_escrow_tasks;
+pub mod get_escrow_releases;
 pub mod get_events;
 pub mod get_events_by_resource_id;
+pub mod get_migration_status;
 pub mod get_pairs;
+pub mod get_price_history;
+pub mod get_risk_weighted_average_models;
+pub mod get_roles;
+pub mod get_solvency_report;
 pub mod get_swap_adjustment;
 pub mod get_time_trigger_ids;
 pub mod get_vault;
 pub mod get_vault_performance;
 pub mod get_vaults;
 pub mod get_vaults_by_address;
+pub mod grant_role;
 pub mod handle_failed_automation;
 pub mod instantiate;
 pub mod migrate;
+pub mod propose_new_admin;
+pub mod receive_pulled_funds;
+pub mod receive_staking_rewards;
+pub mod recover_surplus;
 pub mod remove_custom_swap_fee;
+pub mod remove_risk_weighted_average_model;
+pub mod reset_circuit_breaker;
+pub mod revoke_role;
+pub mod set_paused;
+pub mod set_risk_weighted_average_model;
+pub mod set_swap_adjustment_aggregation;
 pub mod update_config;
+pub mod update_pair_config;
+pub mod update_pair_route;
 pub mod update_swap_adjustment_handler;
+pub mod update_swap_adjustments;
 pub mod update_vault;
 pub mod z_delegate;
 pub mod z_provide_liquidity;
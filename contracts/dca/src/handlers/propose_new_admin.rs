@@ -0,0 +1,109 @@
+use crate::constants::ADMIN_PROPOSAL_EXPIRY_SECONDS;
+use crate::error::ContractError;
+use crate::helpers::validation::{assert_address_is_valid, assert_sender_is_admin};
+use crate::state::config::{get_config, save_pending_admin};
+use crate::types::config::PendingAdmin;
+use cosmwasm_std::{Addr, DepsMut, Env, Event, MessageInfo, Response};
+
+pub fn propose_new_admin_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender.clone())?;
+    assert_address_is_valid(deps.as_ref(), &address, "admin")?;
+
+    if address == get_config(deps.storage)?.admin {
+        return Err(ContractError::CustomError {
+            val: format!("{} is already the admin", address),
+        });
+    }
+
+    let pending_admin = PendingAdmin {
+        address: address.clone(),
+        expires_at: env.block.time.plus_seconds(ADMIN_PROPOSAL_EXPIRY_SECONDS),
+    };
+
+    save_pending_admin(deps.storage, &pending_admin)?;
+
+    Ok(Response::new()
+        .add_attribute("propose_new_admin", "true")
+        .add_event(
+            Event::new("admin_proposed")
+                .add_attribute("admin", info.sender)
+                .add_attribute("proposed_admin", address)
+                .add_attribute("expires_at", pending_admin.expires_at.to_string()),
+        ))
+}
+
+#[cfg(test)]
+mod propose_new_admin_tests {
+    use super::*;
+    use crate::state::config::get_pending_admin;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = propose_new_admin_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_current_admin_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = propose_new_admin_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(ADMIN),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: admin is already the admin");
+    }
+
+    #[test]
+    fn saves_pending_admin() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let response = propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_pending_admin(deps.as_ref().storage).unwrap(),
+            Some(PendingAdmin {
+                address: Addr::unchecked(USER),
+                expires_at: env.block.time.plus_seconds(ADMIN_PROPOSAL_EXPIRY_SECONDS),
+            })
+        );
+
+        assert_eq!(response.events[0].ty, "admin_proposed");
+    }
+}
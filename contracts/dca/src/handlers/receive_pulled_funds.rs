@@ -0,0 +1,411 @@
+use crate::error::ContractError;
+use crate::handlers::execute_trigger::swap_vault_balance;
+use crate::helpers::coin::add_to;
+use crate::helpers::funding::record_failed_pull;
+use crate::helpers::price::query_belief_price;
+use crate::state::cache::{PULL_FUNDS_CACHE, VAULT_CACHE};
+use crate::state::events::create_event;
+use crate::state::pairs::find_pair;
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use cosmwasm_std::{DepsMut, Env, Reply, Response, SubMsgResult};
+
+pub fn receive_pulled_funds_handler(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let vault_id = VAULT_CACHE.load(deps.storage)?;
+    let mut vault = get_vault(deps.storage, vault_id)?;
+
+    let response = Response::new()
+        .add_attribute("receive_pulled_funds", "true")
+        .add_attribute("vault_id", vault.id);
+
+    match reply.result {
+        SubMsgResult::Ok(_) => {
+            let amount_pulled = PULL_FUNDS_CACHE.load(deps.storage)?;
+
+            vault.balance = add_to(vault.balance, amount_pulled.amount);
+            vault.deposited_amount = add_to(vault.deposited_amount, amount_pulled.amount);
+            vault.funding_strategy = vault.funding_strategy.with_consecutive_failures(0);
+
+            update_vault(deps.storage, vault.clone())?;
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    vault.id,
+                    env.block.clone(),
+                    EventData::DcaVaultFundsDeposited {
+                        amount: amount_pulled.clone(),
+                    },
+                ),
+            )?;
+
+            let pair = find_pair(deps.storage, vault.denoms())?;
+            let belief_price =
+                query_belief_price(&deps.as_ref(), &env, &pair, vault.get_swap_denom())?;
+
+            swap_vault_balance(
+                deps,
+                &env,
+                vault,
+                belief_price,
+                response.add_attribute("pulled_amount", amount_pulled.to_string()),
+            )
+        }
+        SubMsgResult::Err(_) => {
+            let vault = record_failed_pull(
+                deps.storage,
+                &env,
+                vault,
+                ExecutionSkippedReason::FundingFailed,
+            )?;
+
+            Ok(response
+                .add_attribute("execution_skipped", "pull_funds_failed")
+                .add_attribute("vault_status", format!("{:?}", vault.status)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod receive_pulled_funds_tests {
+    use super::*;
+    use crate::constants::{AFTER_PULL_FUNDS_REPLY_ID, AFTER_SWAP_REPLY_ID, ONE, TEN};
+    use crate::handlers::execute_trigger::execute_trigger_handler;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::helpers::authz::create_authz_exec_message;
+    use crate::state::cache::SWAP_CACHE;
+    use crate::state::triggers::get_trigger;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UOSMO};
+    use crate::types::event::{EventData, ExecutionSkippedReason};
+    use crate::types::funding_strategy::FundingStrategy;
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{to_binary, Coin, StdError, SubMsg, SubMsgResponse, Uint128};
+    use osmosis_std::shim::Timestamp as ProtoTimestamp;
+    use osmosis_std::types::cosmos::authz::v1beta1::{Grant, QueryGrantsResponse};
+    use osmosis_std::types::cosmos::bank::v1beta1::MsgSend;
+    use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+    fn pull_funded_vault() -> Vault {
+        Vault {
+            status: VaultStatus::Active,
+            balance: Coin::new(0, DENOM_UOSMO),
+            deposited_amount: Coin::new(0, DENOM_UOSMO),
+            swap_amount: ONE,
+            funding_strategy: FundingStrategy::AuthzPull {
+                consecutive_failures: 0,
+            },
+            ..Vault::default()
+        }
+    }
+
+    fn failed_pull_reply(error: &str) -> Reply {
+        Reply {
+            id: AFTER_PULL_FUNDS_REPLY_ID,
+            result: SubMsgResult::Err(error.to_string()),
+        }
+    }
+
+    #[test]
+    fn execute_trigger_should_pull_swap_amount_from_owner() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), pull_funded_vault());
+
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new(TEN.into(), DENOM_UOSMO)],
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            create_authz_exec_message(
+                env.contract.address.clone(),
+                String::from("/cosmos.bank.v1beta1.MsgSend"),
+                MsgSend {
+                    from_address: vault.owner.to_string(),
+                    to_address: env.contract.address.to_string(),
+                    amount: vec![ProtoCoin {
+                        denom: DENOM_UOSMO.to_string(),
+                        amount: ONE.to_string(),
+                    }],
+                },
+            ),
+            AFTER_PULL_FUNDS_REPLY_ID,
+        )));
+    }
+
+    #[test]
+    fn execute_trigger_with_insufficient_owner_balance_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), pull_funded_vault());
+
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new((ONE - Uint128::one()).into(), DENOM_UOSMO)],
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::InsufficientFunderBalance
+            }));
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.funding_strategy,
+            FundingStrategy::AuthzPull {
+                consecutive_failures: 1
+            }
+        );
+    }
+
+    #[test]
+    fn with_successful_pull_should_credit_vault_and_swap() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                funding_strategy: FundingStrategy::AuthzPull {
+                    consecutive_failures: 2,
+                },
+                ..pull_funded_vault()
+            },
+        );
+
+        VAULT_CACHE.save(deps.as_mut().storage, &vault.id).unwrap();
+        PULL_FUNDS_CACHE
+            .save(deps.as_mut().storage, &Coin::new(ONE.into(), DENOM_UOSMO))
+            .unwrap();
+
+        let response = receive_pulled_funds_handler(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: AFTER_PULL_FUNDS_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.balance, Coin::new(ONE.into(), DENOM_UOSMO));
+        assert_eq!(
+            updated_vault.deposited_amount,
+            Coin::new(ONE.into(), DENOM_UOSMO)
+        );
+        assert_eq!(
+            updated_vault.funding_strategy,
+            FundingStrategy::AuthzPull {
+                consecutive_failures: 0
+            }
+        );
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].id, AFTER_SWAP_REPLY_ID);
+        assert!(SWAP_CACHE.load(deps.as_ref().storage).is_ok());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultFundsDeposited {
+                amount: Coin::new(ONE.into(), DENOM_UOSMO)
+            }));
+    }
+
+    #[test]
+    fn execute_trigger_with_expired_grant_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), pull_funded_vault());
+
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new(TEN.into(), DENOM_UOSMO)],
+        );
+
+        let expiration = env.block.time.minus_seconds(1).seconds() as i64;
+
+        deps.querier.update_stargate(move |path, _| match path {
+            "/cosmos.authz.v1beta1.Query/Grants" => to_binary(&QueryGrantsResponse {
+                grants: vec![Grant {
+                    authorization: None,
+                    expiration: Some(ProtoTimestamp {
+                        seconds: expiration,
+                        nanos: 0,
+                    }),
+                }],
+                pagination: None,
+            }),
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::FundingGrantExpired
+            }));
+    }
+
+    #[test]
+    fn execute_trigger_with_missing_grant_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), pull_funded_vault());
+
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new(TEN.into(), DENOM_UOSMO)],
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/cosmos.authz.v1beta1.Query/Grants" => to_binary(&QueryGrantsResponse {
+                grants: vec![],
+                pagination: None,
+            }),
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::FundingGrantNotFound
+            }));
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert_eq!(
+            updated_vault.funding_strategy,
+            FundingStrategy::AuthzPull {
+                consecutive_failures: 1
+            }
+        );
+    }
+
+    #[test]
+    fn with_failed_pull_should_publish_funding_failed_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), pull_funded_vault());
+
+        VAULT_CACHE.save(deps.as_mut().storage, &vault.id).unwrap();
+
+        receive_pulled_funds_handler(
+            deps.as_mut(),
+            env,
+            failed_pull_reply("codespace: sdk, code: 5"),
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::FundingFailed
+            }));
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert_eq!(updated_vault.balance, Coin::new(0, DENOM_UOSMO));
+    }
+
+    #[test]
+    fn with_repeated_failures_should_make_vault_inactive() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                funding_strategy: FundingStrategy::AuthzPull {
+                    consecutive_failures: 2,
+                },
+                ..pull_funded_vault()
+            },
+        );
+
+        VAULT_CACHE.save(deps.as_mut().storage, &vault.id).unwrap();
+
+        receive_pulled_funds_handler(
+            deps.as_mut(),
+            env,
+            failed_pull_reply("codespace: authz, code: 2"),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(
+            updated_vault.funding_strategy,
+            FundingStrategy::AuthzPull {
+                consecutive_failures: 0
+            }
+        );
+        assert!(get_trigger(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .is_none());
+    }
+}
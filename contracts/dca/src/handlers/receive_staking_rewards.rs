@@ -0,0 +1,363 @@
+use crate::error::ContractError;
+use crate::helpers::coin::subtract;
+use crate::helpers::funding::{create_pull_funds_message, record_failed_pull};
+use crate::state::cache::{PULL_FUNDS_CACHE, STAKING_REWARDS_CACHE, VAULT_CACHE};
+use crate::state::events::create_event;
+use crate::state::vaults::get_vault;
+use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use cosmwasm_std::{DepsMut, Env, Reply, Response, SubMsgResult};
+
+/// Measures the rewards claimed into the owner's wallet by comparing their balance against the
+/// snapshot taken before the claim, then pulls the claimed amount into the vault.
+pub fn receive_staking_rewards_handler(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let vault_id = VAULT_CACHE.load(deps.storage)?;
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    let response = Response::new()
+        .add_attribute("receive_staking_rewards", "true")
+        .add_attribute("vault_id", vault.id);
+
+    match reply.result {
+        SubMsgResult::Ok(_) => {
+            let staking_rewards_cache = STAKING_REWARDS_CACHE.load(deps.storage)?;
+
+            let owner_balance = deps
+                .querier
+                .query_balance(&vault.owner, vault.get_swap_denom())?;
+
+            let rewards_claimed = subtract(&owner_balance, &staking_rewards_cache.owner_balance)?;
+
+            if rewards_claimed.amount.is_zero() {
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(
+                        vault.id,
+                        env.block,
+                        EventData::DcaVaultExecutionSkipped {
+                            reason: ExecutionSkippedReason::NoStakingRewardsClaimed,
+                        },
+                    ),
+                )?;
+
+                return Ok(
+                    response.add_attribute("execution_skipped", "no_staking_rewards_claimed")
+                );
+            }
+
+            PULL_FUNDS_CACHE.save(deps.storage, &rewards_claimed)?;
+
+            Ok(response
+                .add_attribute("rewards_claimed", rewards_claimed.to_string())
+                .add_submessage(create_pull_funds_message(&env, &vault, rewards_claimed)))
+        }
+        SubMsgResult::Err(_) => {
+            let vault = record_failed_pull(
+                deps.storage,
+                &env,
+                vault,
+                ExecutionSkippedReason::FundingFailed,
+            )?;
+
+            Ok(response
+                .add_attribute("execution_skipped", "claim_staking_rewards_failed")
+                .add_attribute("vault_status", format!("{:?}", vault.status)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod receive_staking_rewards_tests {
+    use super::*;
+    use crate::constants::{
+        AFTER_CLAIM_STAKING_REWARDS_REPLY_ID, AFTER_PULL_FUNDS_REPLY_ID, ONE, TEN,
+    };
+    use crate::handlers::execute_trigger::execute_trigger_handler;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::helpers::authz::{create_authz_exec_message, create_authz_exec_messages};
+    use crate::helpers::funding::MsgWithdrawDelegatorReward;
+    use crate::state::cache::StakingRewardsCache;
+    use crate::state::vaults::get_vault;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{
+        calc_mock_dependencies, ADMIN, DENOM_UOSMO, USER, VALIDATOR, VALIDATOR_2,
+    };
+    use crate::types::funding_strategy::FundingStrategy;
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{to_binary, Addr, Coin, FullDelegation, StdError, SubMsg, SubMsgResponse};
+    use osmosis_std::types::cosmos::authz::v1beta1::{
+        Grant, QueryGrantsRequest, QueryGrantsResponse,
+    };
+    use osmosis_std::types::cosmos::bank::v1beta1::MsgSend;
+    use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use prost::Message;
+
+    fn staking_rewards_vault() -> Vault {
+        Vault {
+            status: VaultStatus::Active,
+            balance: Coin::new(0, DENOM_UOSMO),
+            deposited_amount: Coin::new(0, DENOM_UOSMO),
+            swap_amount: TEN,
+            funding_strategy: FundingStrategy::StakingRewards {
+                consecutive_failures: 0,
+            },
+            ..Vault::default()
+        }
+    }
+
+    fn delegation(validator: &str) -> FullDelegation {
+        FullDelegation {
+            delegator: Addr::unchecked(USER),
+            validator: validator.to_string(),
+            amount: Coin::new(ONE.into(), DENOM_UOSMO),
+            can_redelegate: Coin::new(ONE.into(), DENOM_UOSMO),
+            accumulated_rewards: vec![],
+        }
+    }
+
+    fn successful_reply() -> Reply {
+        Reply {
+            id: AFTER_CLAIM_STAKING_REWARDS_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn execute_trigger_should_claim_rewards_from_all_delegations() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), staking_rewards_vault());
+
+        deps.querier
+            .update_delegations(&[delegation(VALIDATOR), delegation(VALIDATOR_2)]);
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new(ONE.into(), DENOM_UOSMO)],
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            create_authz_exec_messages(
+                env.contract.address,
+                String::from(MsgWithdrawDelegatorReward::TYPE_URL),
+                vec![
+                    MsgWithdrawDelegatorReward {
+                        delegator_address: USER.to_string(),
+                        validator_address: VALIDATOR.to_string(),
+                    },
+                    MsgWithdrawDelegatorReward {
+                        delegator_address: USER.to_string(),
+                        validator_address: VALIDATOR_2.to_string(),
+                    },
+                ],
+            ),
+            AFTER_CLAIM_STAKING_REWARDS_REPLY_ID,
+        )));
+
+        assert_eq!(
+            STAKING_REWARDS_CACHE.load(deps.as_ref().storage).unwrap(),
+            StakingRewardsCache {
+                owner_balance: Coin::new(ONE.into(), DENOM_UOSMO)
+            }
+        );
+    }
+
+    #[test]
+    fn execute_trigger_without_withdraw_rewards_grant_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), staking_rewards_vault());
+
+        deps.querier.update_delegations(&[delegation(VALIDATOR)]);
+        deps.querier.update_stargate(|path, data| match path {
+            "/cosmos.authz.v1beta1.Query/Grants" => {
+                let request = QueryGrantsRequest::decode(data.as_slice()).unwrap();
+                to_binary(&QueryGrantsResponse {
+                    grants: if request.msg_type_url == MsgWithdrawDelegatorReward::TYPE_URL {
+                        vec![]
+                    } else {
+                        vec![Grant {
+                            authorization: None,
+                            expiration: None,
+                        }]
+                    },
+                    pagination: None,
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::FundingGrantNotFound
+            }));
+    }
+
+    #[test]
+    fn execute_trigger_without_delegations_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), staking_rewards_vault());
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::NoStakingRewardsClaimed
+            }));
+    }
+
+    #[test]
+    fn with_claimed_rewards_should_pull_claimed_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), staking_rewards_vault());
+
+        VAULT_CACHE.save(deps.as_mut().storage, &vault.id).unwrap();
+        STAKING_REWARDS_CACHE
+            .save(
+                deps.as_mut().storage,
+                &StakingRewardsCache {
+                    owner_balance: Coin::new(ONE.into(), DENOM_UOSMO),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new((ONE + ONE).into(), DENOM_UOSMO)],
+        );
+
+        let response =
+            receive_staking_rewards_handler(deps.as_mut(), env.clone(), successful_reply())
+                .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                create_authz_exec_message(
+                    env.contract.address.clone(),
+                    String::from("/cosmos.bank.v1beta1.MsgSend"),
+                    MsgSend {
+                        from_address: vault.owner.to_string(),
+                        to_address: env.contract.address.to_string(),
+                        amount: vec![ProtoCoin {
+                            denom: DENOM_UOSMO.to_string(),
+                            amount: ONE.to_string(),
+                        }],
+                    },
+                ),
+                AFTER_PULL_FUNDS_REPLY_ID,
+            )]
+        );
+
+        assert_eq!(
+            PULL_FUNDS_CACHE.load(deps.as_ref().storage).unwrap(),
+            Coin::new(ONE.into(), DENOM_UOSMO)
+        );
+    }
+
+    #[test]
+    fn with_no_rewards_claimed_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), staking_rewards_vault());
+
+        VAULT_CACHE.save(deps.as_mut().storage, &vault.id).unwrap();
+        STAKING_REWARDS_CACHE
+            .save(
+                deps.as_mut().storage,
+                &StakingRewardsCache {
+                    owner_balance: Coin::new(ONE.into(), DENOM_UOSMO),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            vault.owner.clone(),
+            vec![Coin::new(ONE.into(), DENOM_UOSMO)],
+        );
+
+        let response =
+            receive_staking_rewards_handler(deps.as_mut(), env, successful_reply()).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::NoStakingRewardsClaimed
+            }));
+    }
+
+    #[test]
+    fn with_failed_claim_should_record_failure() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), staking_rewards_vault());
+
+        VAULT_CACHE.save(deps.as_mut().storage, &vault.id).unwrap();
+
+        receive_staking_rewards_handler(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: AFTER_CLAIM_STAKING_REWARDS_REPLY_ID,
+                result: SubMsgResult::Err("codespace: authz, code: 2".to_string()),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.funding_strategy,
+            FundingStrategy::StakingRewards {
+                consecutive_failures: 1
+            }
+        );
+    }
+}
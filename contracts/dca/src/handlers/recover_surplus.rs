@@ -0,0 +1,198 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_address_is_valid, assert_sender_is_admin},
+    state::{migrations::get_migration_progress, vault_totals::get_vault_totals},
+};
+use cosmwasm_std::{Addr, BankMsg, Coin, DepsMut, Env, Event, MessageInfo, Response};
+
+pub fn recover_surplus_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    recipient: Addr,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender.clone())?;
+    assert_address_is_valid(deps.as_ref(), &recipient, "recipient")?;
+
+    if get_migration_progress(deps.storage)?.is_some() {
+        return Err(ContractError::CustomError {
+            val: "cannot recover surplus while a migration is in progress".to_string(),
+        });
+    }
+
+    let liabilities = get_vault_totals(deps.storage, denom.clone())?.liabilities();
+
+    let contract_balance = deps
+        .querier
+        .query_balance(env.contract.address, denom.clone())?
+        .amount;
+
+    let surplus = contract_balance.saturating_sub(liabilities);
+
+    if surplus.is_zero() {
+        return Err(ContractError::CustomError {
+            val: format!("no surplus {} to recover", denom),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attribute("recover_surplus", "true")
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(surplus.into(), denom.clone())],
+        })
+        .add_event(
+            Event::new("surplus_recovered")
+                .add_attribute("denom", denom)
+                .add_attribute("amount", surplus.to_string())
+                .add_attribute("contract_balance", contract_balance.to_string())
+                .add_attribute("liabilities", liabilities.to_string())
+                .add_attribute("recipient", recipient)
+                .add_attribute("recovered_by", info.sender),
+        ))
+}
+
+#[cfg(test)]
+mod recover_surplus_tests {
+    use super::*;
+    use crate::{
+        helpers::migrations::start_migration,
+        state::migrations::save_storage_version,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, DENOM_STAKE, DENOM_UOSMO, USER},
+        },
+        types::vault::Vault,
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        SubMsg,
+    };
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = recover_surplus_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            DENOM_UOSMO.to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_no_surplus_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(1000, DENOM_UOSMO),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![Coin::new(1000, DENOM_UOSMO)],
+        );
+
+        let err = recover_surplus_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: no surplus uosmo to recover");
+    }
+
+    #[test]
+    fn with_migration_in_progress_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_storage_version(deps.as_mut().storage, 2).unwrap();
+        start_migration(deps.as_mut().storage).unwrap();
+
+        let err = recover_surplus_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot recover surplus while a migration is in progress"
+        );
+    }
+
+    #[test]
+    fn sends_only_balance_above_vault_liabilities() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(1000, DENOM_UOSMO),
+                escrowed_amount: Coin::new(50, DENOM_STAKE),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![Coin::new(1250, DENOM_UOSMO), Coin::new(80, DENOM_STAKE)],
+        );
+
+        let response = recover_surplus_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_STAKE.to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: USER.to_string(),
+                amount: vec![Coin::new(30, DENOM_STAKE)],
+            })]
+        );
+        assert!(response.events.contains(
+            &Event::new("surplus_recovered")
+                .add_attribute("denom", DENOM_STAKE)
+                .add_attribute("amount", "30")
+                .add_attribute("contract_balance", "80")
+                .add_attribute("liabilities", "50")
+                .add_attribute("recipient", USER)
+                .add_attribute("recovered_by", ADMIN)
+        ));
+    }
+}
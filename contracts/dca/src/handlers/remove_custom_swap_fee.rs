@@ -1,16 +1,19 @@
-use crate::helpers::validation::assert_sender_is_admin;
-use crate::state::config::get_custom_fee;
+use crate::helpers::validation::assert_sender_has_role;
+use crate::state::config::{get_config_change_delay, get_custom_fee, queue_config_change};
+use crate::types::config::ConfigChange;
+use crate::types::role::Role;
 use crate::{error::ContractError, state::config::remove_custom_fee};
-use cosmwasm_std::DepsMut;
+use cosmwasm_std::{DepsMut, Env};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{MessageInfo, Response};
 
 pub fn remove_custom_swap_fee_handler(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     denom: String,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_sender_has_role(deps.storage, &info.sender, Role::FeeManager)?;
 
     let fee = get_custom_fee(deps.storage, denom.clone())?;
 
@@ -20,6 +23,25 @@ pub fn remove_custom_swap_fee_handler(
         });
     }
 
+    let delay_seconds = get_config_change_delay(deps.storage)?;
+
+    if delay_seconds > 0 {
+        let queued_change = queue_config_change(
+            deps.storage,
+            ConfigChange::RemoveCustomSwapFee {
+                denom: denom.clone(),
+            },
+            env.block.time,
+            env.block.time.plus_seconds(delay_seconds),
+        )?;
+
+        return Ok(Response::new()
+            .add_attribute("remove_custom_swap_fee", "queued")
+            .add_attribute("denom", denom)
+            .add_attribute("queued_config_change_id", queued_change.id.to_string())
+            .add_attribute("executable_at", queued_change.executable_at.to_string()));
+    }
+
     remove_custom_fee(deps.storage, denom.clone());
 
     Ok(Response::new()
@@ -53,11 +75,12 @@ mod remove_custom_swap_fee_tests {
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env, info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let denom = DENOM_STAKE.to_string();
 
-        let err = remove_custom_swap_fee_handler(deps.as_mut(), info, denom).unwrap_err();
+        let err =
+            remove_custom_swap_fee_handler(deps.as_mut(), env.clone(), info, denom).unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -71,7 +94,7 @@ mod remove_custom_swap_fee_tests {
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env, info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let denom = DENOM_STAKE.to_string();
 
@@ -81,12 +104,16 @@ mod remove_custom_swap_fee_tests {
                 base_denom: denom.to_string(),
                 quote_denom: DENOM_STAKE.to_string(),
                 route: vec![1],
+                price_source: None,
+                circuit_breaker: None,
+                config: None,
             },
         )
         .unwrap();
 
         create_custom_swap_fee_handler(
             deps.as_mut(),
+            env.clone(),
             info.clone(),
             denom.clone(),
             Decimal::percent(1),
@@ -98,7 +125,7 @@ mod remove_custom_swap_fee_tests {
         assert_eq!(custom_fees.len(), 1);
         assert_eq!(custom_fees[0], (denom.clone(), Decimal::percent(1)));
 
-        remove_custom_swap_fee_handler(deps.as_mut(), info, denom).unwrap();
+        remove_custom_swap_fee_handler(deps.as_mut(), env.clone(), info, denom).unwrap();
 
         let custom_fees = get_custom_swap_fees_handler(deps.as_ref()).unwrap();
 
@@ -0,0 +1,145 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_is_admin,
+    state::{
+        risk_weighted_average_models::remove_risk_weighted_average_model,
+        vaults::get_active_vault_ids_by_risk_weighted_average_base_denom,
+    },
+    types::swap_adjustment_strategy::BaseDenom,
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn remove_risk_weighted_average_model_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_denom: BaseDenom,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    if !get_active_vault_ids_by_risk_weighted_average_base_denom(deps.storage, &base_denom, 1)?
+        .is_empty()
+    {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "cannot remove the {} risk weighted average model while active vaults reference it",
+                base_denom.key()
+            ),
+        });
+    }
+
+    remove_risk_weighted_average_model(deps.storage, &base_denom);
+
+    Ok(Response::new()
+        .add_attribute("remove_risk_weighted_average_model", "true")
+        .add_attribute("base_denom", base_denom.key()))
+}
+
+#[cfg(test)]
+mod remove_risk_weighted_average_model_tests {
+    use super::*;
+    use crate::{
+        state::risk_weighted_average_models::{
+            get_risk_weighted_average_model, save_risk_weighted_average_model,
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, USER},
+        },
+        types::{
+            position_type::PositionType, risk_weighted_average_model::RiskWeightedAverageModel,
+            swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
+        },
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = remove_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            BaseDenom::Atom,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_active_vaults_referencing_the_model_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_risk_weighted_average_model(
+            deps.as_mut().storage,
+            &RiskWeightedAverageModel {
+                base_denom: BaseDenom::Atom,
+                ..RiskWeightedAverageModel::bitcoin()
+            },
+        )
+        .unwrap();
+
+        setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                    model_id: 30,
+                    base_denom: BaseDenom::Atom,
+                    position_type: PositionType::Enter,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = remove_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Atom,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot remove the atom risk weighted average model while active vaults reference it"
+        );
+        assert!(
+            get_risk_weighted_average_model(deps.as_ref().storage, &BaseDenom::Atom)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn removes_model() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        save_risk_weighted_average_model(
+            deps.as_mut().storage,
+            &RiskWeightedAverageModel {
+                base_denom: BaseDenom::Atom,
+                ..RiskWeightedAverageModel::bitcoin()
+            },
+        )
+        .unwrap();
+
+        remove_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Atom,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_risk_weighted_average_model(deps.as_ref().storage, &BaseDenom::Atom).unwrap(),
+            None
+        );
+    }
+}
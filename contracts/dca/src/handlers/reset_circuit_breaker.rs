@@ -0,0 +1,130 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_has_role;
+use crate::state::circuit_breakers::{get_circuit_breaker_state, remove_circuit_breaker_state};
+use crate::state::pairs::find_pair;
+use crate::types::role::Role;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn reset_circuit_breaker_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_denom: String,
+    quote_denom: String,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
+
+    let pair = find_pair(deps.storage, [base_denom.clone(), quote_denom.clone()])?;
+
+    if get_circuit_breaker_state(deps.storage, pair.denoms())?.is_none() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "circuit breaker for {} and {} has not been tripped",
+                base_denom, quote_denom
+            ),
+        });
+    }
+
+    remove_circuit_breaker_state(deps.storage, pair.denoms());
+
+    Ok(Response::new()
+        .add_attribute("reset_circuit_breaker", "true")
+        .add_attribute("base_denom", base_denom)
+        .add_attribute("quote_denom", quote_denom))
+}
+
+#[cfg(test)]
+mod reset_circuit_breaker_tests {
+    use super::*;
+    use crate::handlers::get_circuit_breaker::get_circuit_breaker_handler;
+    use crate::state::circuit_breakers::save_circuit_breaker_state;
+    use crate::state::pairs::save_pair;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use crate::types::circuit_breaker::CircuitBreakerState;
+    use crate::types::pair::Pair;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    fn tripped_state() -> CircuitBreakerState {
+        CircuitBreakerState {
+            tripped_at: mock_env().block.time,
+            spot_price: Decimal::percent(150),
+            belief_price: Decimal::one(),
+            deviation: Decimal::percent(50),
+            paused: true,
+        }
+    }
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let err = reset_circuit_breaker_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_untripped_circuit_breaker_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let err = reset_circuit_breaker_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: circuit breaker for uosmo and stake has not been tripped"
+        );
+    }
+
+    #[test]
+    fn with_tripped_circuit_breaker_clears_state() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+        save_circuit_breaker_state(deps.as_mut().storage, pair.denoms(), &tripped_state()).unwrap();
+
+        reset_circuit_breaker_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.quote_denom.clone(),
+            pair.base_denom.clone(),
+        )
+        .unwrap();
+
+        let response =
+            get_circuit_breaker_handler(deps.as_ref(), pair.base_denom, pair.quote_denom).unwrap();
+
+        assert_eq!(response.state, None);
+    }
+}
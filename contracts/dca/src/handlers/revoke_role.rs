@@ -0,0 +1,82 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_is_admin;
+use crate::state::roles::{has_role, revoke_role};
+use crate::types::role::Role;
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+
+pub fn revoke_role_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    if !has_role(deps.storage, &role, &address) {
+        return Err(ContractError::CustomError {
+            val: format!("{} does not have the {:?} role", address, role),
+        });
+    }
+
+    revoke_role(deps.storage, &role, &address);
+
+    Ok(Response::new()
+        .add_attribute("revoke_role", "true")
+        .add_attribute("role", role.key())
+        .add_attribute("address", address))
+}
+
+#[cfg(test)]
+mod revoke_role_tests {
+    use super::*;
+    use crate::handlers::grant_role::grant_role_handler;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    #[test]
+    fn with_role_not_held_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = revoke_role_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Role::Pauser,
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: user does not have the Pauser role");
+    }
+
+    #[test]
+    fn revokes_role() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        grant_role_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Role::Pauser,
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        revoke_role_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Role::Pauser,
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        assert!(!has_role(
+            deps.as_ref().storage,
+            &Role::Pauser,
+            &Addr::unchecked(USER)
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::ContractError;
+use crate::helpers::validation::assert_sender_has_role;
+use crate::state::config::{get_config, update_config};
+use crate::types::config::Config;
+use crate::types::role::Role;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn set_paused_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::Pauser)?;
+
+    let config = get_config(deps.storage)?;
+
+    update_config(deps.storage, Config { paused, ..config })?;
+
+    Ok(Response::new()
+        .add_attribute("set_paused", "true")
+        .add_attribute("paused", paused.to_string()))
+}
+
+#[cfg(test)]
+mod set_paused_tests {
+    use super::*;
+    use crate::handlers::grant_role::grant_role_handler;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn without_pauser_role_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = set_paused_handler(deps.as_mut(), mock_info(USER, &[]), true).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_pauser_role_pauses_contract() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        grant_role_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            Role::Pauser,
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        set_paused_handler(deps.as_mut(), mock_info(USER, &[]), true).unwrap();
+
+        assert!(get_config(deps.as_ref().storage).unwrap().paused);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_risk_weighted_average_model_is_valid, assert_sender_is_admin},
+    state::risk_weighted_average_models::save_risk_weighted_average_model,
+    types::risk_weighted_average_model::RiskWeightedAverageModel,
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn set_risk_weighted_average_model_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    model: RiskWeightedAverageModel,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_risk_weighted_average_model_is_valid(&model)?;
+
+    save_risk_weighted_average_model(deps.storage, &model)?;
+
+    Ok(Response::new()
+        .add_attribute("set_risk_weighted_average_model", "true")
+        .add_attribute("base_denom", model.base_denom.key())
+        .add_attribute("model", format!("{:?}", model)))
+}
+
+#[cfg(test)]
+mod set_risk_weighted_average_model_tests {
+    use super::*;
+    use crate::{
+        handlers::{create_pair::create_pair_handler, create_vault::create_vault_handler},
+        state::{risk_weighted_average_models::get_risk_weighted_average_model, vaults::get_vault},
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, USER},
+        },
+        types::{
+            pair::Pair,
+            performance_assessment_strategy::PerformanceAssessmentStrategyParams,
+            risk_weighted_average_model::DurationBucket,
+            swap_adjustment_strategy::{
+                BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
+            },
+            time_interval::TimeInterval,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, Coin, Decimal, Uint128,
+    };
+
+    fn ethereum_model() -> RiskWeightedAverageModel {
+        RiskWeightedAverageModel {
+            base_denom: BaseDenom::Ethereum,
+            duration_buckets: vec![DurationBucket {
+                max_days: 60,
+                model_id: 20,
+            }],
+            default_model_id: 120,
+            min_adjustment: Decimal::percent(50),
+            max_adjustment: Decimal::percent(200),
+            ttl: 60 * 60,
+        }
+    }
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = set_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            ethereum_model(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_bounds_excluding_one_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = set_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            RiskWeightedAverageModel {
+                min_adjustment: Decimal::percent(110),
+                ..ethereum_model()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: risk weighted average model adjustment bounds must include 1"
+        );
+    }
+
+    #[test]
+    fn with_other_base_denom_symbol_containing_separator_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = set_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            RiskWeightedAverageModel {
+                base_denom: BaseDenom::Other("ATOM:30".to_string()),
+                ..ethereum_model()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: risk weighted average model base denom symbol must not be empty or contain ':'"
+        );
+    }
+
+    #[test]
+    fn with_unordered_duration_buckets_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = set_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            RiskWeightedAverageModel {
+                duration_buckets: vec![
+                    DurationBucket {
+                        max_days: 60,
+                        model_id: 20,
+                    },
+                    DurationBucket {
+                        max_days: 30,
+                        model_id: 10,
+                    },
+                ],
+                ..ethereum_model()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: risk weighted average model duration buckets must be in ascending order of max_days"
+        );
+    }
+
+    #[test]
+    fn saves_model() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        set_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            ethereum_model(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_risk_weighted_average_model(deps.as_ref().storage, &BaseDenom::Ethereum).unwrap(),
+            Some(ethereum_model())
+        );
+    }
+
+    #[test]
+    fn vaults_use_registered_model_buckets() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        create_pair_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+            pair.route.clone(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let create_vault = |deps: DepsMut| {
+            create_vault_handler(
+                deps,
+                env.clone(),
+                &mock_info(
+                    USER,
+                    &[Coin::new(Uint128::new(10000000).into(), DENOM_STAKE)],
+                ),
+                Addr::unchecked(USER),
+                None,
+                vec![],
+                DENOM_UOSMO.to_string(),
+                None,
+                None,
+                None,
+                Uint128::new(100000),
+                TimeInterval::Daily,
+                None,
+                Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+                Some(SwapAdjustmentStrategyParams::RiskWeightedAverage {
+                    base_denom: BaseDenom::Ethereum,
+                }),
+                None,
+            )
+        };
+
+        let err = create_vault(deps.as_mut()).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no risk weighted average model is registered for Ethereum"
+        );
+
+        set_risk_weighted_average_model_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            ethereum_model(),
+        )
+        .unwrap();
+
+        create_vault(deps.as_mut()).unwrap();
+
+        let vault = get_vault(deps.as_ref().storage, Uint128::one()).unwrap();
+
+        assert_eq!(
+            vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id: 120,
+                base_denom: BaseDenom::Ethereum,
+                position_type: pair.position_type(DENOM_STAKE.to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_sender_is_admin, assert_swap_adjustment_aggregation_is_valid},
+    state::swap_adjustments::save_swap_adjustment_aggregation,
+    types::swap_adjustment::SwapAdjustmentAggregation,
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn set_swap_adjustment_aggregation_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    aggregation: Option<SwapAdjustmentAggregation>,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    if let Some(aggregation) = &aggregation {
+        assert_swap_adjustment_aggregation_is_valid(aggregation)?;
+    }
+
+    save_swap_adjustment_aggregation(deps.storage, &aggregation)?;
+
+    Ok(Response::new()
+        .add_attribute("set_swap_adjustment_aggregation", "true")
+        .add_attribute("aggregation", format!("{:?}", aggregation)))
+}
+
+#[cfg(test)]
+mod set_swap_adjustment_aggregation_tests {
+    use super::*;
+    use crate::{
+        handlers::update_swap_adjustment_handler::update_swap_adjustment_handler,
+        state::swap_adjustments::get_swap_adjustment_aggregation,
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, USER},
+        },
+        types::{
+            position_type::PositionType,
+            swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Decimal,
+    };
+
+    fn aggregation() -> SwapAdjustmentAggregation {
+        SwapAdjustmentAggregation {
+            quorum: 2,
+            max_deviation: Decimal::percent(20),
+        }
+    }
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = set_swap_adjustment_aggregation_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            Some(aggregation()),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_zero_quorum_fails() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = set_swap_adjustment_aggregation_handler(
+            deps.as_mut(),
+            info,
+            Some(SwapAdjustmentAggregation {
+                quorum: 0,
+                ..aggregation()
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swap adjustment quorum must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_zero_max_deviation_fails() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = set_swap_adjustment_aggregation_handler(
+            deps.as_mut(),
+            info,
+            Some(SwapAdjustmentAggregation {
+                max_deviation: Decimal::zero(),
+                ..aggregation()
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swap adjustment max deviation must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn sets_and_clears_aggregation() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        set_swap_adjustment_aggregation_handler(deps.as_mut(), info.clone(), Some(aggregation()))
+            .unwrap();
+
+        assert_eq!(
+            get_swap_adjustment_aggregation(deps.as_ref().storage).unwrap(),
+            Some(aggregation())
+        );
+
+        set_swap_adjustment_aggregation_handler(deps.as_mut(), info, None).unwrap();
+
+        assert_eq!(
+            get_swap_adjustment_aggregation(deps.as_ref().storage).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn prevents_executors_from_publishing_directly() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        set_swap_adjustment_aggregation_handler(deps.as_mut(), info.clone(), Some(aggregation()))
+            .unwrap();
+
+        let err = update_swap_adjustment_handler(
+            deps.as_mut(),
+            env,
+            info,
+            SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id: 30,
+                base_denom: BaseDenom::Bitcoin,
+                position_type: PositionType::Enter,
+            },
+            Decimal::percent(90),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+}
@@ -1,20 +1,22 @@
 use crate::{
     error::ContractError,
     helpers::validation::{
-        assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
-        assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
-        assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
-        assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
-        assert_sender_is_admin, assert_slippage_tolerance_is_less_than_or_equal_to_one,
-        assert_twap_period_is_valid,
+        assert_config_is_valid, assert_performance_fee_percent_is_valid, assert_sender_is_admin,
+    },
+    state::config::{
+        get_config, get_config_change_delay, queue_config_change, save_config_change_delay,
+        save_performance_fee_rate, update_config,
+    },
+    types::{
+        config::{Config, ConfigChange},
+        fee_collector::FeeCollector,
     },
-    state::config::{get_config, update_config},
-    types::{config::Config, fee_collector::FeeCollector},
 };
-use cosmwasm_std::{Addr, Decimal, DepsMut, MessageInfo, Response};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, Response};
 
 pub fn update_config_handler(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     executors: Option<Vec<Addr>>,
     fee_collectors: Option<Vec<FeeCollector>>,
@@ -26,14 +28,20 @@ pub fn update_config_handler(
     risk_weighted_average_escrow_level: Option<Decimal>,
     twap_period: Option<u64>,
     default_slippage_tolerance: Option<Decimal>,
+    config_change_delay_seconds: Option<u64>,
+    performance_fee_percent: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     assert_sender_is_admin(deps.storage, info.sender)?;
     let existing_config = get_config(deps.storage)?;
 
     let config = Config {
-        admin: existing_config.admin,
-        executors: executors.unwrap_or(existing_config.executors),
-        fee_collectors: fee_collectors.unwrap_or(existing_config.fee_collectors),
+        admin: existing_config.admin.clone(),
+        executors: executors
+            .clone()
+            .unwrap_or(existing_config.executors.clone()),
+        fee_collectors: fee_collectors
+            .clone()
+            .unwrap_or(existing_config.fee_collectors.clone()),
         default_swap_fee_percent: default_swap_fee_percent
             .unwrap_or(existing_config.default_swap_fee_percent),
         weighted_scale_swap_fee_percent: weighted_scale_swap_fee_percent
@@ -49,32 +57,80 @@ pub fn update_config_handler(
             .unwrap_or(existing_config.default_slippage_tolerance),
     };
 
-    assert_fee_level_is_valid(&config.default_swap_fee_percent)?;
-    assert_fee_level_is_valid(&config.weighted_scale_swap_fee_percent)?;
-    assert_fee_level_is_valid(&config.automation_fee_percent)?;
-    assert_page_limit_is_valid(Some(config.default_page_limit))?;
-    assert_slippage_tolerance_is_less_than_or_equal_to_one(config.default_slippage_tolerance)?;
-    assert_twap_period_is_valid(config.twap_period)?;
-    assert_addresses_are_valid(deps.as_ref(), &config.executors, "executor")?;
-    assert_no_more_than_10_fee_collectors(&config.fee_collectors)?;
-    assert_fee_collector_addresses_are_valid(deps.as_ref(), &config.fee_collectors)?;
-    assert_fee_collector_allocations_add_up_to_one(&config.fee_collectors)?;
-    assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
-        config.risk_weighted_average_escrow_level,
+    assert_config_is_valid(deps.as_ref(), &config)?;
+
+    if let Some(performance_fee_percent) = performance_fee_percent {
+        assert_performance_fee_percent_is_valid(performance_fee_percent)?;
+    }
+
+    let delay_seconds = get_config_change_delay(deps.storage)?;
+
+    let has_sensitive_changes = executors.is_some()
+        || fee_collectors.is_some()
+        || default_swap_fee_percent.is_some()
+        || weighted_scale_swap_fee_percent.is_some()
+        || automation_fee_percent.is_some()
+        || risk_weighted_average_escrow_level.is_some()
+        || config_change_delay_seconds.is_some()
+        || performance_fee_percent.is_some();
+
+    if delay_seconds == 0 || !has_sensitive_changes {
+        if let Some(config_change_delay_seconds) = config_change_delay_seconds {
+            save_config_change_delay(deps.storage, config_change_delay_seconds)?;
+        }
+
+        if let Some(performance_fee_percent) = performance_fee_percent {
+            save_performance_fee_rate(deps.storage, performance_fee_percent)?;
+        }
+
+        let config = update_config(deps.storage, config)?;
+
+        return Ok(Response::default()
+            .add_attribute("update_config", "true")
+            .add_attribute("config", format!("{:?}", config)));
+    }
+
+    let config = update_config(
+        deps.storage,
+        Config {
+            executors: existing_config.executors,
+            fee_collectors: existing_config.fee_collectors,
+            default_swap_fee_percent: existing_config.default_swap_fee_percent,
+            weighted_scale_swap_fee_percent: existing_config.weighted_scale_swap_fee_percent,
+            automation_fee_percent: existing_config.automation_fee_percent,
+            risk_weighted_average_escrow_level: existing_config.risk_weighted_average_escrow_level,
+            ..config
+        },
     )?;
 
-    let config = update_config(deps.storage, config)?;
+    let queued_change = queue_config_change(
+        deps.storage,
+        ConfigChange::UpdateConfig {
+            executors,
+            fee_collectors,
+            default_swap_fee_percent,
+            weighted_scale_swap_fee_percent,
+            automation_fee_percent,
+            risk_weighted_average_escrow_level,
+            config_change_delay_seconds,
+            performance_fee_percent,
+        },
+        env.block.time,
+        env.block.time.plus_seconds(delay_seconds),
+    )?;
 
     Ok(Response::default()
         .add_attribute("update_config", "true")
-        .add_attribute("config", format!("{:?}", config)))
+        .add_attribute("config", format!("{:?}", config))
+        .add_attribute("queued_config_change_id", queued_change.id.to_string())
+        .add_attribute("executable_at", queued_change.executable_at.to_string()))
 }
 
 #[cfg(test)]
 mod update_config_tests {
     use super::*;
     use crate::{
-        state::config::get_config,
+        state::config::{get_config, get_performance_fee_rate, get_queued_config_changes},
         tests::{helpers::instantiate_contract, mocks::ADMIN},
     };
     use cosmwasm_std::{
@@ -94,6 +150,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -105,6 +162,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -123,15 +182,15 @@ mod update_config_tests {
 
         instantiate_contract(deps.as_mut(), mock_env(), info.clone());
 
-        let executors = Some(vec![
-            Addr::unchecked("executor-1"),
-            Addr::unchecked("executor-2"),
-        ]);
+        let executors = vec![Addr::unchecked("executor-1"), Addr::unchecked("executor-2")];
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
-            executors.clone(),
+            Some(executors.clone()),
+            None,
+            None,
             None,
             None,
             None,
@@ -146,7 +205,7 @@ mod update_config_tests {
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
-        assert_eq!(config.executors, executors.unwrap());
+        assert_eq!(config.executors, executors);
     }
 
     #[test]
@@ -158,6 +217,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -169,6 +229,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -186,6 +248,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -197,6 +260,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -212,6 +277,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -223,6 +289,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -240,6 +308,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -251,12 +320,78 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
         assert_eq!(err.to_string(), "Error: fee level cannot be larger than 5%")
     }
 
+    #[test]
+    fn update_performance_fee_percent_with_valid_value_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(10)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_performance_fee_rate(deps.as_ref().storage).unwrap(),
+            Decimal::percent(10)
+        );
+    }
+
+    #[test]
+    fn update_performance_fee_percent_more_than_50_percent_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(60)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: performance fee cannot be larger than 50%"
+        )
+    }
+
     #[test]
     fn update_fee_collectors_with_no_value_should_not_change_value() {
         let mut deps = mock_dependencies();
@@ -268,6 +403,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -279,6 +415,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -297,7 +435,7 @@ mod update_config_tests {
 
         instantiate_contract(deps.as_mut(), mock_env(), info.clone());
 
-        let fee_collectors = Some(vec![
+        let fee_collectors = vec![
             FeeCollector {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("0.9").unwrap(),
@@ -306,13 +444,16 @@ mod update_config_tests {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("0.1").unwrap(),
             },
-        ]);
+        ];
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
-            fee_collectors.clone(),
+            Some(fee_collectors.clone()),
+            None,
+            None,
             None,
             None,
             None,
@@ -326,7 +467,7 @@ mod update_config_tests {
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
-        assert_eq!(config.fee_collectors, fee_collectors.unwrap());
+        assert_eq!(config.fee_collectors, fee_collectors);
     }
 
     #[test]
@@ -338,6 +479,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             Some(vec![
@@ -358,6 +500,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -376,6 +520,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -387,6 +532,8 @@ mod update_config_tests {
             Some(Decimal::percent(19)),
             None,
             None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -407,6 +554,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -418,6 +566,8 @@ mod update_config_tests {
             Some(Decimal::percent(150)),
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -436,6 +586,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -447,6 +598,8 @@ mod update_config_tests {
             None,
             None,
             Some(Decimal::percent(150)),
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -465,6 +618,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             Some(vec![
@@ -482,6 +636,8 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -500,6 +656,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -511,9 +668,104 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
         assert_eq!(err.to_string(), "Error: limit cannot be less than 30.")
     }
+
+    #[test]
+    fn with_delay_queues_sensitive_changes_and_applies_the_rest() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        save_config_change_delay(deps.as_mut().storage, 3600).unwrap();
+
+        let config_before_update = get_config(deps.as_ref().storage).unwrap();
+
+        update_config_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            None,
+            None,
+            Some(Decimal::percent(2)),
+            None,
+            None,
+            Some(50),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.default_page_limit, 50);
+        assert_eq!(
+            config.default_swap_fee_percent,
+            config_before_update.default_swap_fee_percent
+        );
+
+        let queued_changes = get_queued_config_changes(deps.as_ref().storage).unwrap();
+
+        assert_eq!(queued_changes.len(), 1);
+        assert_eq!(
+            queued_changes[0].executable_at,
+            env.block.time.plus_seconds(3600)
+        );
+        assert_eq!(
+            queued_changes[0].change,
+            ConfigChange::UpdateConfig {
+                executors: None,
+                fee_collectors: None,
+                default_swap_fee_percent: Some(Decimal::percent(2)),
+                weighted_scale_swap_fee_percent: None,
+                automation_fee_percent: None,
+                risk_weighted_average_escrow_level: None,
+                config_change_delay_seconds: None,
+                performance_fee_percent: None,
+            }
+        );
+    }
+
+    #[test]
+    fn with_delay_applies_paused_immediately() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+        save_config_change_delay(deps.as_mut().storage, 3600).unwrap();
+
+        update_config_handler(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(get_config(deps.as_ref().storage).unwrap().paused);
+        assert!(get_queued_config_changes(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+    }
 }
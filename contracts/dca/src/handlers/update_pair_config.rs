@@ -0,0 +1,149 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{assert_pair_config_is_valid, assert_sender_has_role};
+use crate::state::pairs::{find_pair, save_pair};
+use crate::types::pair::{Pair, PairConfig};
+use crate::types::role::Role;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn update_pair_config_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_denom: String,
+    quote_denom: String,
+    config: Option<PairConfig>,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
+
+    if let Some(config) = &config {
+        assert_pair_config_is_valid(config)?;
+    }
+
+    let pair = find_pair(deps.storage, [base_denom, quote_denom])?;
+
+    save_pair(
+        deps.storage,
+        &Pair {
+            config: config.clone(),
+            ..pair.clone()
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("update_pair_config", "true")
+        .add_attribute("base_denom", pair.base_denom)
+        .add_attribute("quote_denom", pair.quote_denom)
+        .add_attribute("config", format!("{:?}", config)))
+}
+
+#[cfg(test)]
+mod update_pair_config_tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, USER};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Decimal, Uint128};
+
+    fn pair_config() -> PairConfig {
+        PairConfig {
+            enabled: false,
+            minimum_swap_amount: Some(Uint128::new(1000)),
+            twap_period: Some(300),
+            max_slippage_tolerance: Some(Decimal::percent(2)),
+        }
+    }
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let err = update_pair_config_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+            Some(pair_config()),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_invalid_twap_period_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let err = update_pair_config_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+            Some(PairConfig {
+                twap_period: Some(10),
+                ..pair_config()
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: twap_period must be between 30 and 3600"
+        );
+    }
+
+    #[test]
+    fn with_missing_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        let err = update_pair_config_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.base_denom,
+            pair.quote_denom,
+            Some(pair_config()),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "dca::types::pair::Pair not found");
+    }
+
+    #[test]
+    fn updates_pair_config() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        update_pair_config_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            pair.quote_denom.clone(),
+            pair.base_denom.clone(),
+            Some(pair_config()),
+        )
+        .unwrap();
+
+        let updated_pair = find_pair(deps.as_ref().storage, pair.denoms()).unwrap();
+
+        assert_eq!(updated_pair.config, Some(pair_config()));
+        assert_eq!(updated_pair.route, pair.route);
+    }
+}
@@ -0,0 +1,254 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{
+    assert_route_can_be_estimated, assert_route_has_no_duplicate_entries,
+    assert_route_matches_denoms, assert_route_not_empty, assert_sender_has_role,
+};
+use crate::state::config::get_config;
+use crate::state::events::create_event;
+use crate::state::pairs::{find_pair, save_pair};
+use crate::state::vaults::get_active_vault_ids_by_denoms;
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::pair::Pair;
+use crate::types::role::Role;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn update_pair_route_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_denom: String,
+    quote_denom: String,
+    route: Vec<u64>,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &info.sender, Role::PairManager)?;
+    assert_route_not_empty(route.clone())?;
+    assert_route_has_no_duplicate_entries(route.clone())?;
+
+    let existing_pair = find_pair(deps.storage, [base_denom, quote_denom])?;
+
+    let pair = Pair {
+        route: route.clone(),
+        ..existing_pair.clone()
+    };
+
+    assert_route_matches_denoms(&deps.querier, &pair)?;
+    assert_route_can_be_estimated(&deps.querier, &env, &pair)?;
+
+    save_pair(deps.storage, &pair)?;
+
+    let limit = get_config(deps.storage)?.default_page_limit as usize;
+
+    let mut affected_vault_ids =
+        get_active_vault_ids_by_denoms(deps.storage, pair.denoms(), limit + 1)?;
+
+    let truncated = affected_vault_ids.len() > limit;
+
+    affected_vault_ids.truncate(limit);
+
+    for vault_id in affected_vault_ids.iter() {
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                *vault_id,
+                env.block.clone(),
+                EventData::DcaVaultPairRouteUpdated {
+                    base_denom: pair.base_denom.clone(),
+                    quote_denom: pair.quote_denom.clone(),
+                    old_route: existing_pair.route.clone(),
+                    new_route: route.clone(),
+                },
+            ),
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("update_pair_route", "true")
+        .add_attribute("base_denom", pair.base_denom)
+        .add_attribute("quote_denom", pair.quote_denom)
+        .add_attribute("old_route", format!("{:?}", existing_pair.route))
+        .add_attribute("new_route", format!("{:?}", route))
+        .add_attribute("affected_vaults", affected_vault_ids.len().to_string())
+        .add_attribute("affected_vaults_truncated", truncated.to_string()))
+}
+
+#[cfg(test)]
+mod update_pair_route_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::state::config::update_config;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UOSMO, USER};
+    use crate::types::config::Config;
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Attribute, StdError, Uint128};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = update_pair_route_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            DENOM_UOSMO.to_string(),
+            vault.target_denom,
+            vec![1, 4],
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_route_not_matching_denoms_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        assert!(update_pair_route_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            vault.target_denom,
+            vec![0],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn with_route_that_cannot_be_estimated_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
+                Ok(cosmwasm_std::Binary::default())
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let err = update_pair_route_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            vault.target_denom,
+            vec![1, 4],
+        )
+        .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("Error: failed to estimate a swap of"));
+    }
+
+    #[test]
+    fn updates_route_and_publishes_event_for_active_vaults() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let existing_pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        update_pair_route_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            vault.target_denom.clone(),
+            vec![1, 4],
+        )
+        .unwrap();
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        assert_eq!(pair.route, vec![1, 4]);
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultPairRouteUpdated {
+                base_denom: existing_pair.base_denom.clone(),
+                quote_denom: existing_pair.quote_denom.clone(),
+                old_route: existing_pair.route.clone(),
+                new_route: vec![1, 4],
+            }));
+    }
+
+    #[test]
+    fn publishes_events_for_at_most_one_page_of_vaults() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                default_page_limit: 1,
+                ..config
+            },
+        )
+        .unwrap();
+
+        let first_vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let second_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                id: Uint128::new(2),
+                ..Vault::default()
+            },
+        );
+
+        let response = update_pair_route_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UOSMO.to_string(),
+            first_vault.target_denom.clone(),
+            vec![1, 4],
+        )
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("affected_vaults", "1")));
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("affected_vaults_truncated", "true")));
+
+        let route_updated_events = |vault_id| {
+            get_events_by_resource_id_handler(deps.as_ref(), vault_id, None, None, None)
+                .unwrap()
+                .events
+                .into_iter()
+                .filter(|event| matches!(event.data, EventData::DcaVaultPairRouteUpdated { .. }))
+                .count()
+        };
+
+        assert_eq!(route_updated_events(first_vault.id), 1);
+        assert_eq!(route_updated_events(second_vault.id), 0);
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{
     error::ContractError,
-    helpers::validation::{assert_sender_is_executor, assert_swap_adjustment_value_is_valid},
-    state::swap_adjustments::update_swap_adjustment,
+    helpers::{
+        swap_adjustments::publish_swap_adjustment,
+        validation::{
+            assert_sender_can_publish_swap_adjustments, assert_swap_adjustment_value_is_valid,
+        },
+    },
     types::swap_adjustment_strategy::SwapAdjustmentStrategy,
 };
 use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response};
@@ -13,13 +17,21 @@ pub fn update_swap_adjustment_handler(
     strategy: SwapAdjustmentStrategy,
     value: Decimal,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_executor(deps.storage, &env, &info.sender)?;
-    assert_swap_adjustment_value_is_valid(&strategy, value)?;
-    update_swap_adjustment(deps.storage, strategy.clone(), value, env.block.time)?;
+    assert_sender_can_publish_swap_adjustments(deps.storage, &env, &info.sender)?;
+    assert_swap_adjustment_value_is_valid(deps.storage, &strategy, value)?;
+
+    let events = publish_swap_adjustment(
+        deps.storage,
+        env.block.time,
+        &info.sender,
+        strategy.clone(),
+        value,
+    )?;
 
     Ok(Response::new()
         .add_attribute("strategy", format!("{:?}", strategy))
-        .add_attribute("value", value.to_string()))
+        .add_attribute("value", value.to_string())
+        .add_events(events))
 }
 
 #[cfg(test)]
@@ -30,6 +42,7 @@ mod update_swap_adjustments_tests {
         tests::{helpers::instantiate_contract, mocks::ADMIN},
         types::{
             position_type::PositionType,
+            risk_weighted_average_model::RiskWeightedAverageModel,
             swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
         },
     };
@@ -96,8 +109,8 @@ mod update_swap_adjustments_tests {
             format!(
                 "Error: swap adjustment value for strategy {:?} must be between {} and {}",
                 strategy,
-                strategy.min_adjustment(),
-                strategy.max_adjustment()
+                strategy.min_adjustment(Some(&RiskWeightedAverageModel::bitcoin())),
+                strategy.max_adjustment(Some(&RiskWeightedAverageModel::bitcoin()))
             )
         );
     }
@@ -0,0 +1,183 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        swap_adjustments::publish_swap_adjustment,
+        validation::{
+            assert_sender_can_publish_swap_adjustments, assert_swap_adjustment_updates_are_valid,
+        },
+    },
+    types::swap_adjustment::SwapAdjustmentUpdate,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn update_swap_adjustments_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    adjustments: Vec<SwapAdjustmentUpdate>,
+) -> Result<Response, ContractError> {
+    assert_sender_can_publish_swap_adjustments(deps.storage, &env, &info.sender)?;
+    assert_swap_adjustment_updates_are_valid(deps.storage, &adjustments)?;
+
+    let mut response =
+        Response::new().add_attribute("updated_count", adjustments.len().to_string());
+
+    for adjustment in adjustments {
+        response = response.add_attribute(adjustment.strategy.key(), adjustment.value.to_string());
+        response = response.add_events(publish_swap_adjustment(
+            deps.storage,
+            env.block.time,
+            &info.sender,
+            adjustment.strategy,
+            adjustment.value,
+        )?);
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod update_swap_adjustments_tests {
+    use super::*;
+    use crate::{
+        state::swap_adjustments::{get_swap_adjustment, get_swap_adjustment_history},
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        types::{
+            position_type::PositionType,
+            swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Decimal,
+    };
+
+    fn strategy(model_id: u8, position_type: PositionType) -> SwapAdjustmentStrategy {
+        SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id,
+            base_denom: BaseDenom::Bitcoin,
+            position_type,
+        }
+    }
+
+    #[test]
+    fn with_non_executor_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = update_swap_adjustments_handler(
+            deps.as_mut(),
+            env,
+            mock_info("not-an-executor", &[]),
+            vec![SwapAdjustmentUpdate {
+                strategy: strategy(30, PositionType::Enter),
+                value: Decimal::percent(125),
+            }],
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_no_updates_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = update_swap_adjustments_handler(deps.as_mut(), env, info, vec![]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: must provide at least one swap adjustment update"
+        );
+    }
+
+    #[test]
+    fn with_one_invalid_value_updates_nothing() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        update_swap_adjustments_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            vec![
+                SwapAdjustmentUpdate {
+                    strategy: strategy(30, PositionType::Enter),
+                    value: Decimal::percent(125),
+                },
+                SwapAdjustmentUpdate {
+                    strategy: strategy(31, PositionType::Enter),
+                    value: Decimal::percent(125),
+                },
+            ],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            get_swap_adjustment(
+                deps.as_ref().storage,
+                strategy(30, PositionType::Enter),
+                env.block.time
+            ),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn updates_all_swap_adjustments() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let updates = vec![
+            SwapAdjustmentUpdate {
+                strategy: strategy(30, PositionType::Enter),
+                value: Decimal::percent(125),
+            },
+            SwapAdjustmentUpdate {
+                strategy: strategy(30, PositionType::Exit),
+                value: Decimal::percent(80),
+            },
+            SwapAdjustmentUpdate {
+                strategy: strategy(90, PositionType::Enter),
+                value: Decimal::percent(150),
+            },
+        ];
+
+        update_swap_adjustments_handler(deps.as_mut(), env.clone(), info, updates.clone()).unwrap();
+
+        for update in updates {
+            assert_eq!(
+                get_swap_adjustment(
+                    deps.as_ref().storage,
+                    update.strategy.clone(),
+                    env.block.time
+                ),
+                update.value
+            );
+            assert_eq!(
+                get_swap_adjustment_history(
+                    deps.as_ref().storage,
+                    &update.strategy,
+                    None,
+                    30,
+                    false
+                )
+                .unwrap()
+                .len(),
+                1
+            );
+        }
+    }
+}
@@ -1,24 +1,31 @@
 use crate::{
     error::ContractError,
     helpers::validation::{
-        assert_destination_allocations_add_up_to_one,
+        assert_destination_actions_are_valid, assert_destination_allocations_add_up_to_one,
         assert_destination_callback_addresses_are_valid, assert_destinations_limit_is_not_breached,
-        assert_label_is_no_longer_than_100_characters, assert_no_destination_allocations_are_zero,
-        assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_time_interval_is_valid,
-        assert_vault_is_not_cancelled, assert_weighted_scale_multiplier_is_no_more_than_10,
+        assert_label_is_no_longer_than_100_characters, assert_moving_average_params_are_valid,
+        assert_no_destination_allocations_are_zero,
+        assert_slippage_tolerance_is_less_than_or_equal_to_one,
+        assert_slippage_tolerance_is_within_pair_maximum, assert_time_interval_is_valid,
+        assert_value_averaging_params_are_valid, assert_vault_is_not_cancelled,
+        assert_volatility_scaled_params_are_valid, assert_weighted_scale_params_are_valid,
         asset_sender_is_vault_owner,
     },
-    state::vaults::{get_vault, update_vault},
+    state::{
+        pairs::find_pair,
+        vaults::{get_vault, update_vault},
+    },
     types::{
-        destination::Destination,
+        destination::{Destination, DestinationAction},
         swap_adjustment_strategy::{SwapAdjustmentStrategy, SwapAdjustmentStrategyParams},
         time_interval::TimeInterval,
     },
 };
-use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
 
 pub fn update_vault_handler(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     vault_id: Uint128,
     label: Option<String>,
@@ -50,12 +57,18 @@ pub fn update_vault_handler(
             destinations.push(Destination {
                 allocation: Decimal::percent(100),
                 address: vault.owner.clone(),
-                msg: None,
+                action: DestinationAction::Send,
             });
         }
 
         assert_destinations_limit_is_not_breached(&destinations)?;
         assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
+        assert_destination_actions_are_valid(
+            deps.as_ref(),
+            &env.contract.address,
+            &destinations,
+            vault.target_denom.clone(),
+        )?;
         assert_no_destination_allocations_are_zero(&destinations)?;
         assert_destination_allocations_add_up_to_one(&destinations)?;
 
@@ -65,6 +78,10 @@ pub fn update_vault_handler(
 
     if let Some(slippage_tolerance) = slippage_tolerance {
         assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)?;
+        assert_slippage_tolerance_is_within_pair_maximum(
+            &find_pair(deps.storage, vault.denoms())?,
+            slippage_tolerance,
+        )?;
         vault.slippage_tolerance = slippage_tolerance;
         response = response.add_attribute("slippage_tolerance", slippage_tolerance.to_string());
     }
@@ -85,13 +102,114 @@ pub fn update_vault_handler(
             base_receive_amount,
             multiplier,
             increase_only,
+            ref curve,
+            min_swap_amount,
+            max_swap_amount,
         }) => match vault.swap_adjustment_strategy {
             Some(SwapAdjustmentStrategy::WeightedScale { .. }) => {
-                assert_weighted_scale_multiplier_is_no_more_than_10(multiplier)?;
+                assert_weighted_scale_params_are_valid(
+                    multiplier,
+                    curve,
+                    min_swap_amount,
+                    max_swap_amount,
+                )?;
                 vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::WeightedScale {
                     base_receive_amount,
                     multiplier,
                     increase_only,
+                    curve: curve.clone(),
+                    min_swap_amount,
+                    max_swap_amount,
+                })
+            }
+            _ => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "cannot update swap adjustment strategy from {:?} to {:?}",
+                        vault.swap_adjustment_strategy, swap_adjustment_strategy
+                    ),
+                })
+            }
+        },
+        Some(SwapAdjustmentStrategyParams::MovingAverage {
+            lookback,
+            resolution,
+            sensitivity,
+            min_adjustment,
+            max_adjustment,
+        }) => match vault.swap_adjustment_strategy {
+            Some(SwapAdjustmentStrategy::MovingAverage { .. }) => {
+                assert_moving_average_params_are_valid(
+                    lookback,
+                    resolution,
+                    sensitivity,
+                    min_adjustment,
+                    max_adjustment,
+                )?;
+                vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback,
+                    resolution,
+                    sensitivity,
+                    min_adjustment,
+                    max_adjustment,
+                })
+            }
+            _ => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "cannot update swap adjustment strategy from {:?} to {:?}",
+                        vault.swap_adjustment_strategy, swap_adjustment_strategy
+                    ),
+                })
+            }
+        },
+        Some(SwapAdjustmentStrategyParams::ValueAveraging {
+            target_increment,
+            max_swap_multiple,
+            allow_sells,
+        }) => match vault.swap_adjustment_strategy {
+            Some(SwapAdjustmentStrategy::ValueAveraging { .. }) => {
+                assert_value_averaging_params_are_valid(
+                    target_increment,
+                    max_swap_multiple,
+                    allow_sells,
+                )?;
+                vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_increment,
+                    max_swap_multiple,
+                    allow_sells,
+                })
+            }
+            _ => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "cannot update swap adjustment strategy from {:?} to {:?}",
+                        vault.swap_adjustment_strategy, swap_adjustment_strategy
+                    ),
+                })
+            }
+        },
+        Some(SwapAdjustmentStrategyParams::VolatilityScaled {
+            window_seconds,
+            windows,
+            target_volatility,
+            min_adjustment,
+            max_adjustment,
+        }) => match vault.swap_adjustment_strategy {
+            Some(SwapAdjustmentStrategy::VolatilityScaled { .. }) => {
+                assert_volatility_scaled_params_are_valid(
+                    window_seconds,
+                    windows,
+                    target_volatility,
+                    min_adjustment,
+                    max_adjustment,
+                )?;
+                vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::VolatilityScaled {
+                    window_seconds,
+                    windows,
+                    target_volatility,
+                    min_adjustment,
+                    max_adjustment,
                 })
             }
             _ => {
@@ -122,16 +240,21 @@ pub fn update_vault_handler(
 mod update_vault_tests {
     use super::update_vault_handler;
     use crate::{
-        state::vaults::get_vault,
+        state::{
+            pairs::{find_pair, save_pair},
+            vaults::get_vault,
+        },
         tests::{
             helpers::{instantiate_contract, setup_vault},
-            mocks::{ADMIN, USER},
+            mocks::{ADMIN, USER, VALIDATOR},
         },
         types::{
-            destination::Destination,
+            destination::{Destination, DestinationAction},
+            pair::{Pair, PairConfig},
             position_type::PositionType,
             swap_adjustment_strategy::{
                 BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
+                WeightedScaleCurve, WeightedScaleTier,
             },
             time_interval::TimeInterval,
             vault::{Vault, VaultStatus},
@@ -152,6 +275,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -169,6 +293,50 @@ mod update_vault_tests {
         );
     }
 
+    #[test]
+    fn with_slippage_tolerance_larger_than_pair_maximum_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let pair = find_pair(deps.as_ref().storage, vault.denoms()).unwrap();
+
+        save_pair(
+            deps.as_mut().storage,
+            &Pair {
+                config: Some(PairConfig {
+                    enabled: true,
+                    minimum_swap_amount: None,
+                    twap_period: None,
+                    max_slippage_tolerance: Some(Decimal::percent(3)),
+                }),
+                ..pair
+            },
+        )
+        .unwrap();
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            Some(Decimal::percent(5)),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: slippage tolerance must be less than or equal to 0.03 for this pair"
+        );
+    }
+
     #[test]
     fn with_custom_time_interval_less_than_60_seconds_fails() {
         let mut deps = mock_dependencies();
@@ -179,6 +347,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -208,6 +377,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -244,6 +414,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -275,6 +446,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -299,13 +471,14 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
-                msg: None,
+                action: DestinationAction::Send,
             };
             11
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -333,17 +506,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
-                msg: None,
+                action: DestinationAction::Send,
             },
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -371,17 +545,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(51),
-                msg: None,
+                action: DestinationAction::Send,
             },
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -409,17 +584,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(100),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::zero(),
-                msg: None,
+                action: DestinationAction::Send,
             },
         ];
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -462,6 +638,7 @@ mod update_vault_tests {
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -505,10 +682,14 @@ mod update_vault_tests {
             base_receive_amount: Uint128::new(18277),
             multiplier: Decimal::percent(213),
             increase_only: false,
+            curve: None,
+            min_swap_amount: None,
+            max_swap_amount: None,
         });
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -539,10 +720,14 @@ mod update_vault_tests {
             base_receive_amount: Uint128::new(2732),
             multiplier: Decimal::percent(150),
             increase_only: false,
+            curve: None,
+            min_swap_amount: None,
+            max_swap_amount: None,
         });
 
         let err = update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -575,6 +760,9 @@ mod update_vault_tests {
                     base_receive_amount: Uint128::new(2732),
                     multiplier: Decimal::percent(150),
                     increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -588,10 +776,14 @@ mod update_vault_tests {
             base_receive_amount,
             multiplier,
             increase_only,
+            curve: None,
+            min_swap_amount: None,
+            max_swap_amount: None,
         });
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -611,10 +803,289 @@ mod update_vault_tests {
                 base_receive_amount,
                 multiplier,
                 increase_only,
+                curve: None,
+                min_swap_amount: None,
+                max_swap_amount: None,
             })
         );
     }
 
+    #[test]
+    fn updates_weighted_scale_curve_and_swap_amount_bounds() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: Uint128::new(2732),
+                    multiplier: Decimal::percent(150),
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let curve = Some(WeightedScaleCurve::Stepped {
+            tiers: vec![
+                WeightedScaleTier {
+                    price: Decimal::zero(),
+                    adjustment: Decimal::percent(200),
+                },
+                WeightedScaleTier {
+                    price: Decimal::from_ratio(8u128, 1u128),
+                    adjustment: Decimal::one(),
+                },
+                WeightedScaleTier {
+                    price: Decimal::from_ratio(12u128, 1u128),
+                    adjustment: Decimal::percent(50),
+                },
+            ],
+        });
+
+        update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(2732),
+                multiplier: Decimal::percent(150),
+                increase_only: false,
+                curve: curve.clone(),
+                min_swap_amount: None,
+                max_swap_amount: Some(Uint128::new(500000000)),
+            }),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::WeightedScale {
+                base_receive_amount: Uint128::new(2732),
+                multiplier: Decimal::percent(150),
+                increase_only: false,
+                curve,
+                min_swap_amount: None,
+                max_swap_amount: Some(Uint128::new(500000000)),
+            })
+        );
+    }
+
+    #[test]
+    fn with_weighted_scale_tiers_out_of_order_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: Uint128::new(2732),
+                    multiplier: Decimal::percent(150),
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(2732),
+                multiplier: Decimal::percent(150),
+                increase_only: false,
+                curve: Some(WeightedScaleCurve::Stepped {
+                    tiers: vec![
+                        WeightedScaleTier {
+                            price: Decimal::percent(120),
+                            adjustment: Decimal::percent(50),
+                        },
+                        WeightedScaleTier {
+                            price: Decimal::percent(80),
+                            adjustment: Decimal::percent(200),
+                        },
+                    ],
+                }),
+                min_swap_amount: None,
+                max_swap_amount: None,
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: weighted scale tier prices must be strictly increasing"
+        );
+    }
+
+    #[test]
+    fn with_weighted_scale_min_swap_amount_above_max_swap_amount_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: Uint128::new(2732),
+                    multiplier: Decimal::percent(150),
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(2732),
+                multiplier: Decimal::percent(150),
+                increase_only: false,
+                curve: None,
+                min_swap_amount: Some(Uint128::new(200)),
+                max_swap_amount: Some(Uint128::new(100)),
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: weighted scale min swap amount must be no more than max swap amount"
+        );
+    }
+
+    #[test]
+    fn updates_moving_average_swap_adjustment_strategy() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback: 7,
+                    resolution: 3600,
+                    sensitivity: Decimal::percent(150),
+                    min_adjustment: Decimal::percent(50),
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::MovingAverage {
+                lookback: 30,
+                resolution: 3600,
+                sensitivity: Decimal::percent(100),
+                min_adjustment: Decimal::percent(80),
+                max_adjustment: Decimal::percent(120),
+            }),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::MovingAverage {
+                lookback: 30,
+                resolution: 3600,
+                sensitivity: Decimal::percent(100),
+                min_adjustment: Decimal::percent(80),
+                max_adjustment: Decimal::percent(120),
+            })
+        );
+    }
+
+    #[test]
+    fn with_invalid_volatility_scaled_params_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            mock_env(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::VolatilityScaled {
+                    window_seconds: 60 * 60,
+                    windows: 12,
+                    target_volatility: Decimal::percent(1),
+                    min_adjustment: Decimal::percent(50),
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::VolatilityScaled {
+                window_seconds: 60 * 60,
+                windows: 12,
+                target_volatility: Decimal::zero(),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            }),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: volatility scaled target volatility must be greater than 0"
+        );
+    }
+
     #[test]
     fn updates_the_vault_label() {
         let mut deps = mock_dependencies();
@@ -625,6 +1096,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             label.clone(),
@@ -641,6 +1113,38 @@ mod update_vault_tests {
         assert_eq!(updated_vault.label, label);
     }
 
+    #[test]
+    fn with_delegate_destination_for_non_bond_denom_fails() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            Some(vec![Destination {
+                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
+            }]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: {} is not the bond denomination", vault.target_denom)
+        );
+    }
+
     #[test]
     fn updates_the_vault_destinations() {
         let mut deps = mock_dependencies();
@@ -651,17 +1155,18 @@ mod update_vault_tests {
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
-                msg: None,
+                action: DestinationAction::Send,
             },
         ];
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -687,6 +1192,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -706,7 +1212,7 @@ mod update_vault_tests {
             vec![Destination {
                 address: vault.owner,
                 allocation: Decimal::percent(100),
-                msg: None,
+                action: DestinationAction::Send,
             }]
         );
     }
@@ -721,6 +1227,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -747,6 +1254,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
@@ -775,6 +1283,7 @@ mod update_vault_tests {
 
         update_vault_handler(
             deps.as_mut(),
+            mock_env(),
             mock_info(USER, &[]),
             vault.id,
             None,
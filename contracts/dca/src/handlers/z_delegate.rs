@@ -1,28 +1,45 @@
 use crate::constants::AFTER_DELEGATION_REPLY_ID;
 use crate::helpers::authz::create_authz_exec_message;
 use crate::helpers::validation::{
-    assert_address_is_valid, assert_denom_is_bond_denom, assert_validator_is_valid,
+    assert_address_is_valid, assert_commission_rate_is_less_than_or_equal_to_one,
+    assert_denom_is_bond_denom, assert_sender_is_contract, assert_validator_is_valid,
+    assert_weighted_validators_are_valid,
 };
+use crate::state::cache::{DelegationCacheEntry, DELEGATION_CACHE};
+use crate::types::destination::WeightedValidator;
 use crate::{error::ContractError, helpers::validation::assert_exactly_one_asset};
-use cosmwasm_std::{Addr, BankMsg, Deps, Env, MessageInfo, Reply, Response, SubMsg, SubMsgResult};
-use osmosis_std::types::cosmos::base::v1beta1::Coin;
-use osmosis_std::types::cosmos::staking::v1beta1::MsgDelegate;
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, Decimal, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, SubMsgResult,
+    Uint128,
+};
+use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+use osmosis_std::types::cosmos::staking::v1beta1::{MsgBeginRedelegate, MsgDelegate};
+use std::collections::VecDeque;
 
 pub fn z_delegate_handler(
-    deps: Deps,
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
     delegator_address: Addr,
     validator_address: Addr,
 ) -> Result<Response, ContractError> {
     assert_exactly_one_asset(info.funds.clone())?;
-    assert_address_is_valid(deps, &delegator_address, "delegator address")?;
-    assert_validator_is_valid(deps, validator_address.to_string())?;
+    assert_address_is_valid(deps.as_ref(), &delegator_address, "delegator address")?;
+    assert_validator_is_valid(deps.as_ref(), validator_address.to_string())?;
 
     let amount_to_delegate = info.funds[0].clone();
 
     assert_denom_is_bond_denom(amount_to_delegate.denom.clone())?;
 
+    DELEGATION_CACHE.save(
+        deps.storage,
+        &VecDeque::from(vec![DelegationCacheEntry {
+            source_validator_address: None,
+            validator_address: validator_address.clone(),
+            amount: amount_to_delegate.clone(),
+        }]),
+    )?;
+
     Ok(Response::new()
         .add_attributes(vec![
             ("delegation", amount_to_delegate.to_string()),
@@ -34,31 +51,223 @@ pub fn z_delegate_handler(
                 to_address: delegator_address.to_string(),
                 amount: vec![amount_to_delegate.clone()],
             }),
-            SubMsg::reply_always(
-                create_authz_exec_message(
-                    env.contract.address,
-                    String::from("/cosmos.staking.v1beta1.MsgDelegate"),
-                    MsgDelegate {
-                        delegator_address: delegator_address.to_string(),
-                        validator_address: validator_address.to_string(),
-                        amount: Some(Coin {
-                            denom: amount_to_delegate.denom,
-                            amount: amount_to_delegate.amount.to_string(),
-                        }),
-                    },
-                ),
-                AFTER_DELEGATION_REPLY_ID,
+            delegate_message(
+                &env.contract.address,
+                &delegator_address,
+                &validator_address,
+                &amount_to_delegate,
             ),
         ]))
 }
 
-pub fn log_delegation_result(reply: Reply) -> Result<Response, ContractError> {
+/// Splits the received funds across the provided validators by weight. Validators that are
+/// no longer in the active set (i.e. jailed or unbonded) or that charge more than the max
+/// commission rate are skipped, and their weight is shared among the remaining validators.
+/// Any existing delegations to jailed validators in the set are redelegated to the
+/// highest weighted eligible validator.
+pub fn z_delegate_to_validators_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegator_address: Addr,
+    validators: Vec<WeightedValidator>,
+    max_commission_rate: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    assert_sender_is_contract(&info.sender, &env)?;
+    assert_exactly_one_asset(info.funds.clone())?;
+    assert_address_is_valid(deps.as_ref(), &delegator_address, "delegator address")?;
+    assert_weighted_validators_are_valid(deps.as_ref(), &validators)?;
+
+    if let Some(max_commission_rate) = max_commission_rate {
+        assert_commission_rate_is_less_than_or_equal_to_one(max_commission_rate)?;
+    }
+
+    let amount_to_delegate = info.funds[0].clone();
+
+    assert_denom_is_bond_denom(amount_to_delegate.denom.clone())?;
+
+    let active_validators = deps.querier.query_all_validators()?;
+
+    let mut jailed_validators = Vec::<WeightedValidator>::new();
+    let mut eligible_validators = Vec::<WeightedValidator>::new();
+
+    for weighted_validator in validators {
+        match active_validators
+            .iter()
+            .find(|validator| validator.address == weighted_validator.validator_address.as_str())
+        {
+            None => jailed_validators.push(weighted_validator),
+            Some(validator) => {
+                if max_commission_rate
+                    .is_none_or(|max_commission_rate| validator.commission <= max_commission_rate)
+                {
+                    eligible_validators.push(weighted_validator);
+                }
+            }
+        }
+    }
+
+    if eligible_validators.is_empty() {
+        return Err(ContractError::CustomError {
+            val: String::from("no eligible validators to delegate to"),
+        });
+    }
+
+    let total_eligible_weight = eligible_validators
+        .iter()
+        .fold(Decimal::zero(), |acc, validator| acc + validator.weight);
+
+    let mut delegations = Vec::<DelegationCacheEntry>::new();
+    let mut remaining_amount = amount_to_delegate.amount;
+
+    for (index, weighted_validator) in eligible_validators.iter().enumerate() {
+        let amount = if index == eligible_validators.len() - 1 {
+            remaining_amount
+        } else {
+            amount_to_delegate.amount.multiply_ratio(
+                weighted_validator.weight.atomics(),
+                total_eligible_weight.atomics(),
+            )
+        };
+
+        remaining_amount -= amount;
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        delegations.push(DelegationCacheEntry {
+            source_validator_address: None,
+            validator_address: weighted_validator.validator_address.clone(),
+            amount: Coin::new(amount.into(), amount_to_delegate.denom.clone()),
+        });
+    }
+
+    let redelegation_target =
+        eligible_validators
+            .iter()
+            .fold(eligible_validators[0].clone(), |max, validator| {
+                if validator.weight > max.weight {
+                    validator.clone()
+                } else {
+                    max
+                }
+            });
+
+    for jailed_validator in jailed_validators {
+        if let Some(delegation) = deps.querier.query_delegation(
+            delegator_address.clone(),
+            jailed_validator.validator_address.clone(),
+        )? {
+            if delegation.amount.amount > Uint128::zero() {
+                delegations.push(DelegationCacheEntry {
+                    source_validator_address: Some(jailed_validator.validator_address),
+                    validator_address: redelegation_target.validator_address.clone(),
+                    amount: delegation.amount,
+                });
+            }
+        }
+    }
+
+    DELEGATION_CACHE.save(deps.storage, &VecDeque::from(delegations.clone()))?;
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            ("delegation", amount_to_delegate.to_string()),
+            ("delegator", delegator_address.to_string()),
+        ])
+        .add_submessage(SubMsg::new(BankMsg::Send {
+            to_address: delegator_address.to_string(),
+            amount: vec![amount_to_delegate],
+        }))
+        .add_submessages(delegations.iter().map(|delegation| {
+            match delegation.source_validator_address.clone() {
+                Some(source_validator_address) => redelegate_message(
+                    &env.contract.address,
+                    &delegator_address,
+                    &source_validator_address,
+                    &delegation.validator_address,
+                    &delegation.amount,
+                ),
+                None => delegate_message(
+                    &env.contract.address,
+                    &delegator_address,
+                    &delegation.validator_address,
+                    &delegation.amount,
+                ),
+            }
+        })))
+}
+
+fn delegate_message(
+    contract_address: &Addr,
+    delegator_address: &Addr,
+    validator_address: &Addr,
+    amount: &Coin,
+) -> SubMsg {
+    SubMsg::reply_always(
+        create_authz_exec_message(
+            contract_address.clone(),
+            String::from("/cosmos.staking.v1beta1.MsgDelegate"),
+            MsgDelegate {
+                delegator_address: delegator_address.to_string(),
+                validator_address: validator_address.to_string(),
+                amount: Some(ProtoCoin {
+                    denom: amount.denom.clone(),
+                    amount: amount.amount.to_string(),
+                }),
+            },
+        ),
+        AFTER_DELEGATION_REPLY_ID,
+    )
+}
+
+fn redelegate_message(
+    contract_address: &Addr,
+    delegator_address: &Addr,
+    source_validator_address: &Addr,
+    destination_validator_address: &Addr,
+    amount: &Coin,
+) -> SubMsg {
+    SubMsg::reply_always(
+        create_authz_exec_message(
+            contract_address.clone(),
+            String::from("/cosmos.staking.v1beta1.MsgBeginRedelegate"),
+            MsgBeginRedelegate {
+                delegator_address: delegator_address.to_string(),
+                validator_src_address: source_validator_address.to_string(),
+                validator_dst_address: destination_validator_address.to_string(),
+                amount: Some(ProtoCoin {
+                    denom: amount.denom.clone(),
+                    amount: amount.amount.to_string(),
+                }),
+            },
+        ),
+        AFTER_DELEGATION_REPLY_ID,
+    )
+}
+
+pub fn log_delegation_result(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+    let mut cache = DELEGATION_CACHE.load(deps.storage)?;
+    let entry = cache.pop_front().expect("a delegation cache entry");
+    DELEGATION_CACHE.save(deps.storage, &cache)?;
+
     let result = match reply.result {
         SubMsgResult::Ok(_) => "success".to_string(),
         SubMsgResult::Err(_) => "failure".to_string(),
     };
 
-    Ok(Response::new().add_attribute("delegate_result", result))
+    Ok(match entry.source_validator_address {
+        Some(source_validator_address) => Response::new()
+            .add_attribute("redelegation", entry.amount.to_string())
+            .add_attribute("source_validator", source_validator_address)
+            .add_attribute("validator", entry.validator_address)
+            .add_attribute("redelegate_result", result),
+        None => Response::new()
+            .add_attribute("delegation", entry.amount.to_string())
+            .add_attribute("validator", entry.validator_address)
+            .add_attribute("delegate_result", result),
+    })
 }
 
 #[cfg(test)]
@@ -66,10 +275,10 @@ mod z_delegate_tests {
     use super::*;
     use crate::{
         helpers::authz::create_authz_exec_message,
-        tests::mocks::{DENOM_STAKE, DENOM_UOSMO, USER, VALIDATOR},
+        tests::mocks::{calc_mock_dependencies, DENOM_STAKE, DENOM_UOSMO, USER, VALIDATOR},
     };
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_env, mock_info},
+        testing::{mock_env, mock_info},
         Addr, Attribute, BankMsg, Coin, SubMsg, SubMsgResponse,
     };
     use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
@@ -80,7 +289,7 @@ mod z_delegate_tests {
         let info = mock_info(USER, &[]);
 
         let response = z_delegate_handler(
-            mock_dependencies().as_ref(),
+            calc_mock_dependencies().as_mut(),
             mock_env(),
             info,
             Addr::unchecked(USER),
@@ -102,7 +311,7 @@ mod z_delegate_tests {
         );
 
         let response = z_delegate_handler(
-            mock_dependencies().as_ref(),
+            calc_mock_dependencies().as_mut(),
             mock_env(),
             info,
             Addr::unchecked(USER),
@@ -116,15 +325,34 @@ mod z_delegate_tests {
         );
     }
 
+    #[test]
+    fn with_unknown_validator_fails() {
+        let info = mock_info(USER, &[Coin::new(100, DENOM_UOSMO)]);
+
+        let response = z_delegate_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            info,
+            Addr::unchecked(USER),
+            Addr::unchecked("unknown-validator"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            response.to_string(),
+            "Error: validator unknown-validator is invalid",
+        );
+    }
+
     #[test]
     fn sends_bank_message() {
         let amount_to_delegate = Coin::new(100, DENOM_UOSMO);
-        let info = mock_info(USER, &[amount_to_delegate.clone()]);
+        let info = mock_info(USER, std::slice::from_ref(&amount_to_delegate));
 
         let delegator_address = Addr::unchecked(info.sender.clone());
 
         let response = z_delegate_handler(
-            mock_dependencies().as_ref(),
+            calc_mock_dependencies().as_mut(),
             mock_env(),
             info,
             delegator_address.clone(),
@@ -141,13 +369,13 @@ mod z_delegate_tests {
     #[test]
     fn sends_delegate_message() {
         let amount_to_delegate = Coin::new(100, DENOM_UOSMO);
-        let info = mock_info(USER, &[amount_to_delegate.clone()]);
+        let info = mock_info(USER, std::slice::from_ref(&amount_to_delegate));
 
         let delegator_address = Addr::unchecked(info.sender.clone());
         let validator_address = Addr::unchecked(VALIDATOR);
 
         let response = z_delegate_handler(
-            mock_dependencies().as_ref(),
+            calc_mock_dependencies().as_mut(),
             mock_env(),
             info,
             delegator_address.clone(),
@@ -174,15 +402,32 @@ mod z_delegate_tests {
 
     #[test]
     fn logs_the_delegate_result_on_success() {
-        let response = log_delegation_result(Reply {
-            id: AFTER_DELEGATION_REPLY_ID,
-            result: cosmwasm_std::SubMsgResult::Ok(SubMsgResponse {
-                events: vec![],
-                data: None,
-            }),
-        })
+        let mut deps = calc_mock_dependencies();
+
+        z_delegate_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            Addr::unchecked(VALIDATOR),
+        )
+        .unwrap();
+
+        let response = log_delegation_result(
+            deps.as_mut(),
+            Reply {
+                id: AFTER_DELEGATION_REPLY_ID,
+                result: cosmwasm_std::SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
         .unwrap();
 
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("validator", VALIDATOR)));
         assert!(response
             .attributes
             .contains(&Attribute::new("delegate_result", "success")));
@@ -190,14 +435,350 @@ mod z_delegate_tests {
 
     #[test]
     fn logs_the_delegate_result_on_failure() {
-        let response = log_delegation_result(Reply {
-            id: AFTER_DELEGATION_REPLY_ID,
-            result: cosmwasm_std::SubMsgResult::Err("error code 4".to_string()),
-        })
+        let mut deps = calc_mock_dependencies();
+
+        z_delegate_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            Addr::unchecked(VALIDATOR),
+        )
+        .unwrap();
+
+        let response = log_delegation_result(
+            deps.as_mut(),
+            Reply {
+                id: AFTER_DELEGATION_REPLY_ID,
+                result: cosmwasm_std::SubMsgResult::Err("error code 4".to_string()),
+            },
+        )
         .unwrap();
 
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("validator", VALIDATOR)));
         assert!(response
             .attributes
             .contains(&Attribute::new("delegate_result", "failure")));
     }
 }
+
+#[cfg(test)]
+mod z_delegate_to_validators_tests {
+    use super::*;
+    use crate::tests::mocks::{
+        calc_mock_dependencies, DENOM_STAKE, DENOM_UOSMO, HIGH_COMMISSION_VALIDATOR, USER,
+        VALIDATOR, VALIDATOR_2,
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
+        Addr, Attribute, BankMsg, Coin, Decimal, FullDelegation, SubMsg,
+    };
+
+    fn weighted_validator(validator_address: &str, weight: u64) -> WeightedValidator {
+        WeightedValidator {
+            validator_address: Addr::unchecked(validator_address),
+            weight: Decimal::percent(weight),
+        }
+    }
+
+    #[test]
+    fn with_sender_other_than_contract_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(USER, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![weighted_validator(VALIDATOR, 100)],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_non_bond_denom_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_STAKE)]),
+            Addr::unchecked(USER),
+            vec![weighted_validator(VALIDATOR, 100)],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: stake is not the bond denomination");
+    }
+
+    #[test]
+    fn with_weights_not_adding_up_to_one_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 50),
+                weighted_validator(VALIDATOR_2, 40),
+            ],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: validator weights must add up to 1");
+    }
+
+    #[test]
+    fn with_weights_overflowing_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                WeightedValidator {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                    weight: Decimal::MAX,
+                },
+                weighted_validator(VALIDATOR_2, 100),
+            ],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: validator weights must add up to 1");
+    }
+
+    #[test]
+    fn with_duplicate_validators_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 50),
+                weighted_validator(VALIDATOR, 50),
+            ],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: validator {} can only be provided once", VALIDATOR)
+        );
+    }
+
+    #[test]
+    fn with_unknown_validator_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 50),
+                weighted_validator("unknown-validator", 50),
+            ],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: validator unknown-validator is invalid"
+        );
+    }
+
+    #[test]
+    fn splits_delegation_by_weight() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        let response = z_delegate_to_validators_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 70),
+                weighted_validator(VALIDATOR_2, 30),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: USER.to_string(),
+                    amount: vec![Coin::new(100, DENOM_UOSMO)],
+                }),
+                delegate_message(
+                    &env.contract.address,
+                    &Addr::unchecked(USER),
+                    &Addr::unchecked(VALIDATOR),
+                    &Coin::new(70, DENOM_UOSMO),
+                ),
+                delegate_message(
+                    &env.contract.address,
+                    &Addr::unchecked(USER),
+                    &Addr::unchecked(VALIDATOR_2),
+                    &Coin::new(30, DENOM_UOSMO),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_validators_above_max_commission_rate() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        let response = z_delegate_to_validators_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 50),
+                weighted_validator(HIGH_COMMISSION_VALIDATOR, 50),
+            ],
+            Some(Decimal::percent(10)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages[1..],
+            vec![delegate_message(
+                &env.contract.address,
+                &Addr::unchecked(USER),
+                &Addr::unchecked(VALIDATOR),
+                &Coin::new(100, DENOM_UOSMO),
+            )]
+        );
+    }
+
+    #[test]
+    fn with_no_eligible_validators_fails() {
+        let err = z_delegate_to_validators_handler(
+            calc_mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![weighted_validator(HIGH_COMMISSION_VALIDATOR, 100)],
+            Some(Decimal::percent(10)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no eligible validators to delegate to"
+        );
+    }
+
+    #[test]
+    fn skips_jailed_validators_and_redelegates_existing_delegations() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        deps.querier.jail_validator(VALIDATOR_2);
+        deps.querier.update_delegations(&[FullDelegation {
+            delegator: Addr::unchecked(USER),
+            validator: VALIDATOR_2.to_string(),
+            amount: Coin::new(500, DENOM_UOSMO),
+            can_redelegate: Coin::new(500, DENOM_UOSMO),
+            accumulated_rewards: vec![],
+        }]);
+
+        let response = z_delegate_to_validators_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 60),
+                weighted_validator(VALIDATOR_2, 40),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages[1..],
+            vec![
+                delegate_message(
+                    &env.contract.address,
+                    &Addr::unchecked(USER),
+                    &Addr::unchecked(VALIDATOR),
+                    &Coin::new(100, DENOM_UOSMO),
+                ),
+                redelegate_message(
+                    &env.contract.address,
+                    &Addr::unchecked(USER),
+                    &Addr::unchecked(VALIDATOR_2),
+                    &Addr::unchecked(VALIDATOR),
+                    &Coin::new(500, DENOM_UOSMO),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn logs_results_per_validator() {
+        let mut deps = calc_mock_dependencies();
+
+        z_delegate_to_validators_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[Coin::new(100, DENOM_UOSMO)]),
+            Addr::unchecked(USER),
+            vec![
+                weighted_validator(VALIDATOR, 50),
+                weighted_validator(VALIDATOR_2, 50),
+            ],
+            None,
+        )
+        .unwrap();
+
+        let first_response = log_delegation_result(
+            deps.as_mut(),
+            Reply {
+                id: AFTER_DELEGATION_REPLY_ID,
+                result: SubMsgResult::Err("error code 4".to_string()),
+            },
+        )
+        .unwrap();
+
+        let second_response = log_delegation_result(
+            deps.as_mut(),
+            Reply {
+                id: AFTER_DELEGATION_REPLY_ID,
+                result: SubMsgResult::Err("error code 4".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            first_response.attributes,
+            vec![
+                Attribute::new("delegation", Coin::new(50, DENOM_UOSMO).to_string()),
+                Attribute::new("validator", VALIDATOR),
+                Attribute::new("delegate_result", "failure"),
+            ]
+        );
+        assert_eq!(
+            second_response.attributes,
+            vec![
+                Attribute::new("delegation", Coin::new(50, DENOM_UOSMO).to_string()),
+                Attribute::new("validator", VALIDATOR_2),
+                Attribute::new("delegate_result", "failure"),
+            ]
+        );
+    }
+}
@@ -3,22 +3,34 @@ use crate::{
     error::ContractError,
     helpers::{
         authz::create_authz_exec_message,
-        validation::{assert_address_is_valid, assert_exactly_one_asset},
+        validation::{
+            assert_address_is_valid, assert_exactly_one_asset, assert_lockable_duration_is_valid,
+            assert_superfluid_lock_is_valid,
+        },
     },
     state::{
-        cache::{ProvideLiquidityCache, PROVIDE_LIQUIDITY_CACHE},
+        cache::{ProvideLiquidityCache, PROVIDE_LIQUIDITY_CACHE, VAULT_CACHE},
         config::get_config,
+        events::create_event,
+    },
+    types::{
+        event::{EventBuilder, EventData},
+        lockable_duration::LockableDuration,
     },
-    types::lockable_duration::LockableDuration,
 };
 use cosmwasm_std::{
-    Addr, BankMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, SubMsgResult,
-    Uint128,
+    Addr, BankMsg, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    StdResult, SubMsg, SubMsgResult, Uint128,
 };
-use osmosis_std::types::osmosis::{
-    gamm::v1beta1::{MsgJoinSwapExternAmountIn, QueryCalcJoinPoolSharesRequest},
-    lockup::MsgLockTokens,
+use osmosis_std::types::{
+    cosmos::authz::v1beta1::MsgExecResponse,
+    osmosis::{
+        gamm::v1beta1::{MsgJoinSwapExternAmountIn, QueryCalcJoinPoolSharesRequest},
+        lockup::{MsgLockTokens, MsgLockTokensResponse},
+        superfluid::{MsgLockAndSuperfluidDelegate, MsgLockAndSuperfluidDelegateResponse},
+    },
 };
+use prost::Message;
 
 pub fn z_provide_liquidity_handler(
     deps: DepsMut,
@@ -28,9 +40,26 @@ pub fn z_provide_liquidity_handler(
     pool_id: u64,
     duration: LockableDuration,
     slippage_tolerance: Option<Decimal>,
+    superfluid_validator_address: Option<Addr>,
 ) -> Result<Response, ContractError> {
     assert_exactly_one_asset(info.funds.clone())?;
     assert_address_is_valid(deps.as_ref(), &provider_address, "provider address")?;
+    assert_lockable_duration_is_valid(&duration)?;
+
+    if let Some(superfluid_validator_address) = superfluid_validator_address.clone() {
+        assert_superfluid_lock_is_valid(
+            deps.as_ref(),
+            pool_id,
+            superfluid_validator_address,
+            &duration,
+        )?;
+    }
+
+    let vault_id = if info.sender == env.contract.address {
+        VAULT_CACHE.may_load(deps.storage)?
+    } else {
+        None
+    };
 
     PROVIDE_LIQUIDITY_CACHE.save(
         deps.storage,
@@ -38,6 +67,8 @@ pub fn z_provide_liquidity_handler(
             provider_address,
             pool_id,
             duration,
+            superfluid_validator_address,
+            vault_id,
         },
     )?;
 
@@ -98,51 +129,134 @@ pub fn bond_lp_tokens(deps: Deps, env: Env) -> Result<Response, ContractError> {
                 amount: vec![lp_token_balance.clone()],
             }),
             SubMsg::reply_always(
-                create_authz_exec_message(
-                    env.contract.address,
-                    "/osmosis.lockup.MsgLockTokens".to_string(),
-                    MsgLockTokens {
-                        owner: cache.provider_address.to_string(),
-                        duration: Some(cache.duration.into()),
-                        coins: vec![lp_token_balance.into()],
-                    },
-                ),
+                match cache.superfluid_validator_address {
+                    Some(superfluid_validator_address) => create_authz_exec_message(
+                        env.contract.address,
+                        "/osmosis.superfluid.MsgLockAndSuperfluidDelegate".to_string(),
+                        MsgLockAndSuperfluidDelegate {
+                            sender: cache.provider_address.to_string(),
+                            coins: vec![lp_token_balance.into()],
+                            val_addr: superfluid_validator_address.to_string(),
+                        },
+                    ),
+                    None => create_authz_exec_message(
+                        env.contract.address,
+                        "/osmosis.lockup.MsgLockTokens".to_string(),
+                        MsgLockTokens {
+                            owner: cache.provider_address.to_string(),
+                            duration: Some(cache.duration.into()),
+                            coins: vec![lp_token_balance.into()],
+                        },
+                    ),
+                },
                 AFTER_BOND_LP_TOKENS_REPLY_ID,
             ),
         ]))
 }
 
-pub fn log_bond_lp_tokens_result(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+pub fn log_bond_lp_tokens_result(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let cache = PROVIDE_LIQUIDITY_CACHE.load(deps.storage)?;
     PROVIDE_LIQUIDITY_CACHE.remove(deps.storage);
 
-    let result = match reply.result {
-        SubMsgResult::Ok(_) => "success".to_string(),
-        SubMsgResult::Err(_) => "failure".to_string(),
-    };
+    let mut response = Response::new()
+        .add_attribute("bond_lp_tokens_pool_id", cache.pool_id.to_string())
+        .add_attribute("bond_lp_tokens_duration", cache.duration.to_string());
+
+    if let Some(superfluid_validator_address) = cache.superfluid_validator_address.clone() {
+        response = response.add_attribute(
+            "bond_lp_tokens_superfluid_validator",
+            superfluid_validator_address,
+        );
+    }
+
+    Ok(match reply.result {
+        SubMsgResult::Ok(sub_msg_response) => {
+            let lock_id = sub_msg_response
+                .data
+                .map(|data| get_lock_id(data, cache.superfluid_validator_address.is_some()))
+                .transpose()?;
+
+            if let (Some(lock_id), Some(vault_id)) = (lock_id, cache.vault_id) {
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(
+                        vault_id,
+                        env.block,
+                        EventData::DcaVaultLiquidityLocked {
+                            provider_address: cache.provider_address,
+                            pool_id: cache.pool_id,
+                            lock_id,
+                            duration: cache.duration,
+                            superfluid_validator_address: cache.superfluid_validator_address,
+                        },
+                    ),
+                )?;
+            }
+
+            response
+                .add_attribute("bond_lp_tokens_result", "success")
+                .add_attribute(
+                    "bond_lp_tokens_lock_id",
+                    lock_id.map_or("unknown".to_string(), |lock_id| lock_id.to_string()),
+                )
+        }
+        SubMsgResult::Err(_) => response.add_attribute("bond_lp_tokens_result", "failure"),
+    })
+}
 
-    Ok(Response::new().add_attribute("bond_lp_tokens_result", result))
+fn get_lock_id(data: Binary, is_superfluid: bool) -> StdResult<u64> {
+    let exec_response = MsgExecResponse::decode(data.as_slice())
+        .map_err(|_| StdError::generic_err("failed to decode authz exec response"))?;
+
+    let result = exec_response
+        .results
+        .first()
+        .ok_or_else(|| StdError::generic_err("authz exec response contained no results"))?;
+
+    if is_superfluid {
+        MsgLockAndSuperfluidDelegateResponse::decode(result.as_slice())
+            .map(|response| response.id)
+            .map_err(|_| StdError::generic_err("failed to decode superfluid lock response"))
+    } else {
+        MsgLockTokensResponse::decode(result.as_slice())
+            .map(|response| response.id)
+            .map_err(|_| StdError::generic_err("failed to decode lock tokens response"))
+    }
 }
 
 #[cfg(test)]
 mod z_provide_liquidity_tests {
     use super::*;
     use crate::{
-        constants::TEN,
-        handlers::z_provide_liquidity::{bond_lp_tokens, log_bond_lp_tokens_result},
+        constants::{MAX_LOCK_DURATION_SECONDS, TEN},
+        handlers::{
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+            z_provide_liquidity::{bond_lp_tokens, log_bond_lp_tokens_result},
+        },
         helpers::authz::create_authz_exec_message,
         state::cache::{ProvideLiquidityCache, PROVIDE_LIQUIDITY_CACHE},
         tests::{
             helpers::instantiate_contract,
-            mocks::{calc_mock_dependencies, DENOM_STAKE, DENOM_UOSMO, USER},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO, USER, VALIDATOR},
         },
         types::lockable_duration::LockableDuration,
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Attribute, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse,
+        to_binary, Addr, Attribute, BankMsg, Coin, Decimal, Reply, StdError, SubMsg,
+        SubMsgResponse,
     };
-    use osmosis_std::types::osmosis::{
-        gamm::v1beta1::MsgJoinSwapExternAmountIn, lockup::MsgLockTokens,
+    use osmosis_std::{
+        shim::Duration,
+        types::osmosis::{
+            gamm::v1beta1::MsgJoinSwapExternAmountIn,
+            lockup::MsgLockTokens,
+            superfluid::{AssetTypeResponse, SuperfluidAssetType},
+        },
     };
 
     #[test]
@@ -159,6 +273,7 @@ mod z_provide_liquidity_tests {
             1,
             LockableDuration::OneDay,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -185,6 +300,7 @@ mod z_provide_liquidity_tests {
             1,
             LockableDuration::OneDay,
             None,
+            None,
         )
         .unwrap_err();
 
@@ -217,6 +333,7 @@ mod z_provide_liquidity_tests {
             pool_id,
             duration.clone(),
             None,
+            None,
         )
         .unwrap();
 
@@ -228,6 +345,8 @@ mod z_provide_liquidity_tests {
                 provider_address,
                 pool_id,
                 duration,
+                superfluid_validator_address: None,
+                vault_id: None,
             }
         );
     }
@@ -253,6 +372,7 @@ mod z_provide_liquidity_tests {
             pool_id,
             LockableDuration::OneDay,
             None,
+            None,
         )
         .unwrap();
 
@@ -292,6 +412,7 @@ mod z_provide_liquidity_tests {
             pool_id,
             LockableDuration::OneDay,
             Some(slippage_tolerance),
+            None,
         )
         .unwrap();
 
@@ -326,6 +447,8 @@ mod z_provide_liquidity_tests {
                     provider_address: provider_address.clone(),
                     pool_id,
                     duration: LockableDuration::OneDay,
+                    superfluid_validator_address: None,
+                    vault_id: None,
                 },
             )
             .unwrap();
@@ -355,6 +478,8 @@ mod z_provide_liquidity_tests {
             provider_address: provider_address.clone(),
             pool_id,
             duration: LockableDuration::OneDay,
+            superfluid_validator_address: None,
+            vault_id: None,
         };
 
         PROVIDE_LIQUIDITY_CACHE
@@ -377,12 +502,225 @@ mod z_provide_liquidity_tests {
         )))
     }
 
+    #[test]
+    fn with_zero_custom_duration_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(
+            env.contract.address.as_ref(),
+            &[Coin::new(100, DENOM_STAKE)],
+        );
+
+        let err = z_provide_liquidity_handler(
+            deps.as_mut(),
+            env,
+            info,
+            Addr::unchecked(USER),
+            1,
+            LockableDuration::Custom { seconds: 0 },
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: lock duration must be greater than 0 seconds"
+        );
+    }
+
+    #[test]
+    fn with_custom_duration_exceeding_lockup_maximum_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(
+            env.contract.address.as_ref(),
+            &[Coin::new(100, DENOM_STAKE)],
+        );
+
+        let err = z_provide_liquidity_handler(
+            deps.as_mut(),
+            env,
+            info,
+            Addr::unchecked(USER),
+            1,
+            LockableDuration::Custom {
+                seconds: MAX_LOCK_DURATION_SECONDS + 1,
+            },
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: lock duration of {} seconds exceeds the maximum lock duration of {} seconds",
+                MAX_LOCK_DURATION_SECONDS + 1,
+                MAX_LOCK_DURATION_SECONDS
+            )
+        );
+    }
+
+    #[test]
+    fn with_superfluid_validator_and_short_duration_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(
+            env.contract.address.as_ref(),
+            &[Coin::new(100, DENOM_STAKE)],
+        );
+
+        let err = z_provide_liquidity_handler(
+            deps.as_mut(),
+            env,
+            info,
+            Addr::unchecked(USER),
+            1,
+            LockableDuration::OneWeek,
+            None,
+            Some(Addr::unchecked(VALIDATOR)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: superfluid staking requires a lock duration of 2 weeks"
+        );
+    }
+
+    #[test]
+    fn with_superfluid_validator_and_ineligible_pool_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(
+            env.contract.address.as_ref(),
+            &[Coin::new(100, DENOM_STAKE)],
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.superfluid.Query/AssetType" => to_binary(&AssetTypeResponse {
+                asset_type: SuperfluidAssetType::Native as i32,
+            }),
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let err = z_provide_liquidity_handler(
+            deps.as_mut(),
+            env,
+            info,
+            Addr::unchecked(USER),
+            1,
+            LockableDuration::TwoWeeks,
+            None,
+            Some(Addr::unchecked(VALIDATOR)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: gamm/pool/1 is not a superfluid eligible asset"
+        );
+    }
+
+    #[test]
+    fn with_custom_duration_bonds_for_custom_duration() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let pool_id = 1;
+        let provider_address = Addr::unchecked(USER);
+
+        let lp_tokens_minted = Coin::new(100000, format!("gamm/pool/{}", pool_id));
+
+        deps.querier
+            .update_balance(env.contract.address.clone(), vec![lp_tokens_minted.clone()]);
+
+        PROVIDE_LIQUIDITY_CACHE
+            .save(
+                deps.as_mut().storage,
+                &ProvideLiquidityCache {
+                    provider_address: provider_address.clone(),
+                    pool_id,
+                    duration: LockableDuration::Custom { seconds: 3600 },
+                    superfluid_validator_address: None,
+                    vault_id: None,
+                },
+            )
+            .unwrap();
+
+        let response = bond_lp_tokens(deps.as_ref(), env.clone()).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            create_authz_exec_message(
+                env.contract.address,
+                "/osmosis.lockup.MsgLockTokens".to_string(),
+                MsgLockTokens {
+                    owner: provider_address.to_string(),
+                    duration: Some(Duration {
+                        seconds: 3600,
+                        nanos: 0
+                    }),
+                    coins: vec![lp_tokens_minted.into()],
+                },
+            ),
+            AFTER_BOND_LP_TOKENS_REPLY_ID,
+        )))
+    }
+
+    #[test]
+    fn with_superfluid_validator_locks_and_superfluid_delegates_the_lp_tokens() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let pool_id = 1;
+        let provider_address = Addr::unchecked(USER);
+
+        let lp_tokens_minted = Coin::new(100000, format!("gamm/pool/{}", pool_id));
+
+        deps.querier
+            .update_balance(env.contract.address.clone(), vec![lp_tokens_minted.clone()]);
+
+        PROVIDE_LIQUIDITY_CACHE
+            .save(
+                deps.as_mut().storage,
+                &ProvideLiquidityCache {
+                    provider_address: provider_address.clone(),
+                    pool_id,
+                    duration: LockableDuration::TwoWeeks,
+                    superfluid_validator_address: Some(Addr::unchecked(VALIDATOR)),
+                    vault_id: None,
+                },
+            )
+            .unwrap();
+
+        let response = bond_lp_tokens(deps.as_ref(), env.clone()).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            create_authz_exec_message(
+                env.contract.address,
+                "/osmosis.superfluid.MsgLockAndSuperfluidDelegate".to_string(),
+                MsgLockAndSuperfluidDelegate {
+                    sender: provider_address.to_string(),
+                    coins: vec![lp_tokens_minted.into()],
+                    val_addr: VALIDATOR.to_string(),
+                },
+            ),
+            AFTER_BOND_LP_TOKENS_REPLY_ID,
+        )))
+    }
+
     #[test]
     fn logs_the_bond_lp_tokens_result_on_success() {
         let mut deps = mock_dependencies();
 
+        PROVIDE_LIQUIDITY_CACHE
+            .save(deps.as_mut().storage, &ProvideLiquidityCache::default())
+            .unwrap();
+
         let response = log_bond_lp_tokens_result(
             deps.as_mut(),
+            mock_env(),
             Reply {
                 id: AFTER_BOND_LP_TOKENS_REPLY_ID,
                 result: cosmwasm_std::SubMsgResult::Ok(SubMsgResponse {
@@ -402,8 +740,13 @@ mod z_provide_liquidity_tests {
     fn logs_the_bond_lp_tokens_result_on_failure() {
         let mut deps = mock_dependencies();
 
+        PROVIDE_LIQUIDITY_CACHE
+            .save(deps.as_mut().storage, &ProvideLiquidityCache::default())
+            .unwrap();
+
         let response = log_bond_lp_tokens_result(
             deps.as_mut(),
+            mock_env(),
             Reply {
                 id: AFTER_BOND_LP_TOKENS_REPLY_ID,
                 result: cosmwasm_std::SubMsgResult::Err("error code 4".to_string()),
@@ -415,4 +758,90 @@ mod z_provide_liquidity_tests {
             .attributes
             .contains(&Attribute::new("bond_lp_tokens_result", "failure")));
     }
+
+    #[test]
+    fn logs_the_lock_id_from_the_reply_data() {
+        let mut deps = mock_dependencies();
+
+        PROVIDE_LIQUIDITY_CACHE
+            .save(deps.as_mut().storage, &ProvideLiquidityCache::default())
+            .unwrap();
+
+        let response = log_bond_lp_tokens_result(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: AFTER_BOND_LP_TOKENS_REPLY_ID,
+                result: cosmwasm_std::SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(lock_tokens_reply_data(42)),
+                }),
+            },
+        )
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("bond_lp_tokens_lock_id", "42")));
+    }
+
+    #[test]
+    fn creates_liquidity_locked_event_for_the_vault() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let cache = ProvideLiquidityCache {
+            vault_id: Some(Uint128::new(3)),
+            ..ProvideLiquidityCache::default()
+        };
+
+        PROVIDE_LIQUIDITY_CACHE
+            .save(deps.as_mut().storage, &cache)
+            .unwrap();
+
+        log_bond_lp_tokens_result(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: AFTER_BOND_LP_TOKENS_REPLY_ID,
+                result: cosmwasm_std::SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(lock_tokens_reply_data(42)),
+                }),
+            },
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), Uint128::new(3), None, None, None)
+                .unwrap()
+                .events;
+
+        assert_eq!(
+            events,
+            vec![EventBuilder::new(
+                Uint128::new(3),
+                env.block,
+                EventData::DcaVaultLiquidityLocked {
+                    provider_address: cache.provider_address,
+                    pool_id: cache.pool_id,
+                    lock_id: 42,
+                    duration: cache.duration,
+                    superfluid_validator_address: None,
+                },
+            )
+            .build(1)]
+        );
+    }
+
+    fn lock_tokens_reply_data(lock_id: u64) -> Binary {
+        Binary::from(
+            MsgExecResponse {
+                results: vec![MsgLockTokensResponse { id: lock_id }.encode_to_vec()],
+            }
+            .encode_to_vec(),
+        )
+    }
 }
@@ -3,15 +3,26 @@ use osmosis_std::{shim::Any, types::cosmos::authz::v1beta1::MsgExec};
 use prost::Message;
 
 pub fn create_authz_exec_message<T: Message>(grantee: Addr, type_url: String, msg: T) -> CosmosMsg {
+    create_authz_exec_messages(grantee, type_url, vec![msg])
+}
+
+pub fn create_authz_exec_messages<T: Message>(
+    grantee: Addr,
+    type_url: String,
+    msgs: Vec<T>,
+) -> CosmosMsg {
     CosmosMsg::Stargate {
         type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
         value: Binary::from(
             MsgExec {
                 grantee: grantee.to_string(),
-                msgs: vec![Any {
-                    type_url,
-                    value: msg.encode_to_vec(),
-                }],
+                msgs: msgs
+                    .iter()
+                    .map(|msg| Any {
+                        type_url: type_url.clone(),
+                        value: msg.encode_to_vec(),
+                    })
+                    .collect(),
             }
             .encode_to_vec(),
         ),
@@ -1,88 +1,154 @@
 use super::math::checked_mul;
 use crate::{
     constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
+    msg::ExecuteMsg,
     state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
-    types::vault::Vault,
+    types::{
+        destination::{Destination, DestinationAction},
+        vault::Vault,
+    },
+};
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
-use cosmwasm_std::{BankMsg, Coin, StdResult, Storage, SubMsg, Uint128, WasmMsg};
 use std::collections::VecDeque;
 
 pub fn get_disbursement_messages(
     store: &mut dyn Storage,
+    contract_address: &Addr,
     vault: &Vault,
     amount_to_disburse: Uint128,
 ) -> StdResult<VecDeque<SubMsg>> {
     let mut post_execution_action_caches = VecDeque::<PostExecutionActionCacheEntry>::new();
 
-    let messages = vault
-        .destinations
-        .iter()
-        .flat_map(|destination| {
-            let allocation_amount = Coin::new(
-                checked_mul(amount_to_disburse, destination.allocation)
-                    .expect("amount to be distributed should be valid")
-                    .into(),
-                vault.target_denom.clone(),
-            );
-
-            if allocation_amount.amount.gt(&Uint128::zero()) {
-                let msg = destination.msg.clone().map_or(
-                    SubMsg::reply_always(
-                        BankMsg::Send {
-                            to_address: destination.address.to_string(),
-                            amount: vec![allocation_amount.clone()],
-                        },
-                        AFTER_FAILED_AUTOMATION_REPLY_ID,
-                    ),
-                    |msg| {
-                        SubMsg::reply_always(
-                            WasmMsg::Execute {
-                                contract_addr: destination.address.to_string(),
-                                msg,
-                                funds: vec![allocation_amount.clone()],
-                            },
-                            AFTER_FAILED_AUTOMATION_REPLY_ID,
-                        )
-                    },
-                );
-
-                post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
-                    msg: msg.clone(),
-                    funds: vec![allocation_amount],
-                });
-
-                return Some(msg);
-            }
-
-            None
-        })
-        .collect::<VecDeque<SubMsg>>();
+    let mut messages = VecDeque::<SubMsg>::new();
+
+    for destination in vault.destinations.iter() {
+        let allocation_amount = Coin::new(
+            checked_mul(amount_to_disburse, destination.allocation)
+                .expect("amount to be distributed should be valid")
+                .into(),
+            vault.target_denom.clone(),
+        );
+
+        if allocation_amount.amount.is_zero() {
+            continue;
+        }
+
+        let msg = SubMsg::reply_always(
+            get_destination_msg(contract_address, destination, allocation_amount.clone())?,
+            AFTER_FAILED_AUTOMATION_REPLY_ID,
+        );
+
+        post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
+            msg: msg.clone(),
+            funds: vec![allocation_amount],
+            destination: destination.clone(),
+        });
+
+        messages.push_back(msg);
+    }
 
     POST_EXECUTION_ACTION_CACHE.save(store, vault.id.into(), &post_execution_action_caches)?;
 
     Ok(messages)
 }
 
+pub fn get_destination_msg(
+    contract_address: &Addr,
+    destination: &Destination,
+    amount: Coin,
+) -> StdResult<CosmosMsg> {
+    Ok(match destination.action.clone() {
+        DestinationAction::Send => BankMsg::Send {
+            to_address: destination.address.to_string(),
+            amount: vec![amount],
+        }
+        .into(),
+        DestinationAction::ExecuteContract { msg } => WasmMsg::Execute {
+            contract_addr: destination.address.to_string(),
+            msg,
+            funds: vec![amount],
+        }
+        .into(),
+        DestinationAction::Delegate { validator_address } => WasmMsg::Execute {
+            contract_addr: contract_address.to_string(),
+            msg: to_binary(&ExecuteMsg::ZDelegate {
+                delegator_address: destination.address.clone(),
+                validator_address,
+            })?,
+            funds: vec![amount],
+        }
+        .into(),
+        DestinationAction::DelegateToValidators {
+            validators,
+            max_commission_rate,
+        } => WasmMsg::Execute {
+            contract_addr: contract_address.to_string(),
+            msg: to_binary(&ExecuteMsg::ZDelegateToValidators {
+                delegator_address: destination.address.clone(),
+                validators,
+                max_commission_rate,
+            })?,
+            funds: vec![amount],
+        }
+        .into(),
+        DestinationAction::ProvideLiquidity {
+            pool_id,
+            duration,
+            slippage_tolerance,
+            superfluid_validator_address,
+        } => WasmMsg::Execute {
+            contract_addr: contract_address.to_string(),
+            msg: to_binary(&ExecuteMsg::ZProvideLiquidity {
+                provider_address: destination.address.clone(),
+                pool_id,
+                duration,
+                slippage_tolerance,
+                superfluid_validator_address,
+            })?,
+            funds: vec![amount],
+        }
+        .into(),
+        DestinationAction::DepositToVault { vault_id } => WasmMsg::Execute {
+            contract_addr: contract_address.to_string(),
+            msg: to_binary(&ExecuteMsg::Deposit {
+                address: destination.address.clone(),
+                vault_id,
+            })?,
+            funds: vec![amount],
+        }
+        .into(),
+    })
+}
+
 #[cfg(test)]
 mod get_disbursement_messages_tests {
     use super::get_disbursement_messages;
     use crate::{
         constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE},
+        msg::ExecuteMsg,
         state::cache::POST_EXECUTION_ACTION_CACHE,
-        types::{destination::Destination, vault::Vault},
+        tests::mocks::VALIDATOR,
+        types::{
+            destination::{Destination, DestinationAction},
+            vault::Vault,
+        },
     };
     use cosmwasm_std::{
-        testing::mock_dependencies, to_binary, Addr, BankMsg, Coin, Decimal, SubMsg, WasmMsg,
+        testing::{mock_dependencies, mock_env},
+        to_binary, Addr, BankMsg, Coin, Decimal, SubMsg, WasmMsg,
     };
 
     #[test]
-    fn generates_bank_sends_for_destinations_with_no_msg() {
+    fn generates_bank_sends_for_send_destinations() {
         let mut deps = mock_dependencies();
+        let env = mock_env();
 
         let destination = Destination {
             address: Addr::unchecked("test"),
             allocation: Decimal::percent(100),
-            msg: None,
+            action: DestinationAction::Send,
         };
 
         let vault = Vault {
@@ -90,7 +156,9 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        let messages = get_disbursement_messages(deps.as_mut().storage, &vault, ONE).unwrap();
+        let messages =
+            get_disbursement_messages(deps.as_mut().storage, &env.contract.address, &vault, ONE)
+                .unwrap();
 
         assert!(messages.contains(&SubMsg::reply_always(
             BankMsg::Send {
@@ -101,27 +169,66 @@ mod get_disbursement_messages_tests {
         )))
     }
 
+    #[test]
+    fn generates_contract_callbacks_for_delegate_destinations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let destination = Destination {
+            address: Addr::unchecked("test"),
+            allocation: Decimal::percent(100),
+            action: DestinationAction::Delegate {
+                validator_address: Addr::unchecked(VALIDATOR),
+            },
+        };
+
+        let vault = Vault {
+            destinations: vec![destination.clone()],
+            ..Vault::default()
+        };
+
+        let messages =
+            get_disbursement_messages(deps.as_mut().storage, &env.contract.address, &vault, ONE)
+                .unwrap();
+
+        assert!(messages.contains(&SubMsg::reply_always(
+            WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::ZDelegate {
+                    delegator_address: destination.address,
+                    validator_address: Addr::unchecked(VALIDATOR),
+                })
+                .unwrap(),
+                funds: vec![Coin::new(ONE.into(), vault.target_denom)],
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )))
+    }
+
     #[test]
     fn saves_disbursement_messages_to_cache_queue() {
         let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let callback = to_binary(&WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: to_binary("test").unwrap(),
+            funds: vec![],
+        })
+        .unwrap();
 
         let destinations = vec![
             Destination {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
-                msg: None,
+                action: DestinationAction::Send,
             },
             Destination {
                 address: Addr::unchecked("contract"),
                 allocation: Decimal::percent(80),
-                msg: Some(
-                    to_binary(&WasmMsg::Execute {
-                        contract_addr: "contract".to_string(),
-                        msg: to_binary("test").unwrap(),
-                        funds: vec![],
-                    })
-                    .unwrap(),
-                ),
+                action: DestinationAction::ExecuteContract {
+                    msg: callback.clone(),
+                },
             },
         ];
 
@@ -130,15 +237,20 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, ONE).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env.contract.address, &vault, ONE)
+            .unwrap();
 
         let mut cache = POST_EXECUTION_ACTION_CACHE
             .load(deps.as_ref().storage, vault.id.into())
             .unwrap();
 
         assert_eq!(cache.len(), 2);
+
+        let first_entry = cache.pop_front().unwrap();
+
+        assert_eq!(first_entry.destination, destinations[0]);
         assert_eq!(
-            cache.pop_front().unwrap().msg,
+            first_entry.msg,
             SubMsg::reply_always(
                 BankMsg::Send {
                     to_address: destinations[0].address.to_string(),
@@ -150,12 +262,16 @@ mod get_disbursement_messages_tests {
                 AFTER_FAILED_AUTOMATION_REPLY_ID
             )
         );
+
+        let second_entry = cache.pop_front().unwrap();
+
+        assert_eq!(second_entry.destination, destinations[1]);
         assert_eq!(
-            cache.pop_front().unwrap().msg,
+            second_entry.msg,
             SubMsg::reply_always(
                 WasmMsg::Execute {
                     contract_addr: destinations[1].address.to_string(),
-                    msg: destinations[1].msg.clone().unwrap(),
+                    msg: callback,
                     funds: vec![Coin::new(
                         (ONE * destinations[1].allocation).into(),
                         vault.target_denom
@@ -1,10 +1,7 @@
-use super::math::checked_mul;
+use super::{math::checked_mul, vault::get_performance_values};
 use crate::{
     state::config::{get_config, get_custom_fee},
-    types::{
-        performance_assessment_strategy::PerformanceAssessmentStrategy,
-        swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
-    },
+    types::{swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault},
 };
 use cosmwasm_std::{BankMsg, Coin, Decimal, Deps, StdResult, Storage, SubMsg, Uint128};
 use std::cmp::min;
@@ -48,7 +45,7 @@ pub fn get_automation_fee_rate(storage: &dyn Storage, vault: &Vault) -> StdResul
         vault
             .destinations
             .iter()
-            .filter(|destination| destination.msg.is_some())
+            .filter(|destination| destination.action.is_automated())
             .map(|destination| destination.allocation)
             .sum(),
     )?)
@@ -73,7 +70,10 @@ pub fn get_swap_fee_rate(
             (Some(swap_denom_fee_percent), None) => swap_denom_fee_percent,
             (None, Some(receive_denom_fee_percent)) => receive_denom_fee_percent,
             (None, None) => match swap_adjustment_strategy {
-                Some(SwapAdjustmentStrategy::WeightedScale { .. }) => {
+                Some(SwapAdjustmentStrategy::WeightedScale { .. })
+                | Some(SwapAdjustmentStrategy::MovingAverage { .. })
+                | Some(SwapAdjustmentStrategy::ValueAveraging { .. })
+                | Some(SwapAdjustmentStrategy::VolatilityScaled { .. }) => {
                     config.weighted_scale_swap_fee_percent
                 }
                 Some(_) => Decimal::zero(),
@@ -83,36 +83,33 @@ pub fn get_swap_fee_rate(
     )
 }
 
+/// Value (in the swap denom) the vault has added over its performance benchmark.
+pub fn get_added_value(vault: &Vault, current_price: Decimal) -> StdResult<Uint128> {
+    let (vault_total_value, benchmark_total_value) = get_performance_values(vault, current_price)?;
+
+    Ok(vault_total_value
+        .checked_sub(benchmark_total_value)
+        .unwrap_or(Uint128::zero()))
+}
+
 pub fn get_performance_fee(vault: &Vault, current_price: Decimal) -> StdResult<Coin> {
-    Ok(vault.performance_assessment_strategy.clone().map_or(
-        Coin::new(0, vault.target_denom.clone()),
-        |strategy| match strategy.clone() {
-            PerformanceAssessmentStrategy::CompareToStandardDca {
-                swapped_amount,
-                received_amount,
-            } => {
-                let vault_total_value = vault.deposited_amount.amount - vault.swapped_amount.amount
-                    + vault.received_amount.amount * current_price;
-
-                let standard_dca_total_value = vault.deposited_amount.amount
-                    - swapped_amount.amount
-                    + received_amount.amount * current_price;
-
-                let added_value_in_terms_of_receive_denom = vault_total_value
-                    .checked_sub(standard_dca_total_value)
-                    .unwrap_or(Uint128::zero())
-                    * (Decimal::one() / current_price);
-
-                Coin {
-                    denom: vault.target_denom.clone(),
-                    amount: min(
-                        vault.escrowed_amount.amount,
-                        added_value_in_terms_of_receive_denom * strategy.performance_fee_rate(),
-                    ),
-                }
-            }
+    vault.performance_assessment_strategy.clone().map_or(
+        Ok(Coin::new(0, vault.target_denom.clone())),
+        |strategy| {
+            let added_value_in_terms_of_receive_denom = get_added_value(vault, current_price)?
+                .checked_sub(strategy.fee_baseline())
+                .unwrap_or(Uint128::zero())
+                * (Decimal::one() / current_price);
+
+            Ok(Coin {
+                denom: vault.target_denom.clone(),
+                amount: min(
+                    vault.escrowed_amount.amount,
+                    added_value_in_terms_of_receive_denom * strategy.performance_fee_rate(),
+                ),
+            })
         },
-    ))
+    )
 }
 
 #[cfg(test)]
@@ -124,8 +121,9 @@ mod tests {
         state::config::get_config,
         tests::{helpers::instantiate_contract, mocks::ADMIN},
         types::{
-            performance_assessment_strategy::PerformanceAssessmentStrategy,
-            swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
+            performance_assessment_strategy::{PerformanceAssessmentStrategy, PerformanceFee},
+            swap_adjustment_strategy::SwapAdjustmentStrategy,
+            vault::Vault,
         },
     };
     use cosmwasm_std::{
@@ -166,6 +164,7 @@ mod tests {
                         standard_dca_received_amount.into(),
                         "receive_denom".to_string(),
                     ),
+                    performance_fee: None,
                 },
             ),
             swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
@@ -357,6 +356,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lump_sum_fee_is_charged_on_value_added_over_lump_sum_purchase() {
+        let vault = Vault {
+            performance_assessment_strategy: Some(
+                PerformanceAssessmentStrategy::CompareToLumpSum {
+                    start_price: Some(Decimal::one()),
+                    performance_fee: PerformanceFee {
+                        rate: Decimal::percent(2),
+                        high_water_mark: None,
+                        settled_added_value: None,
+                    },
+                },
+            ),
+            ..get_vault(
+                Uint128::new(2000),
+                Uint128::new(1000),
+                Uint128::zero(),
+                Uint128::new(1000),
+                Uint128::zero(),
+            )
+        };
+
+        let fee = get_performance_fee(&vault, Decimal::percent(50)).unwrap();
+
+        assert_eq!(fee.amount, Uint128::new(20));
+    }
+
+    #[test]
+    fn holding_swap_denom_fee_is_charged_on_value_added_over_deposit() {
+        let vault = Vault {
+            performance_assessment_strategy: Some(
+                PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                    performance_fee: PerformanceFee {
+                        rate: Decimal::percent(10),
+                        high_water_mark: None,
+                        settled_added_value: None,
+                    },
+                },
+            ),
+            ..get_vault(
+                Uint128::new(2000),
+                Uint128::new(1000),
+                Uint128::zero(),
+                Uint128::new(1000),
+                Uint128::zero(),
+            )
+        };
+
+        let fee = get_performance_fee(&vault, Decimal::from_str("1.25").unwrap()).unwrap();
+
+        assert_eq!(fee.amount, Uint128::new(20));
+    }
+
+    #[test]
+    fn fee_is_only_charged_on_value_added_above_high_water_mark() {
+        let vault = Vault {
+            performance_assessment_strategy: Some(
+                PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                    performance_fee: PerformanceFee {
+                        rate: Decimal::percent(10),
+                        high_water_mark: Some(Uint128::new(150)),
+                        settled_added_value: None,
+                    },
+                },
+            ),
+            ..get_vault(
+                Uint128::new(2000),
+                Uint128::new(1000),
+                Uint128::zero(),
+                Uint128::new(1000),
+                Uint128::zero(),
+            )
+        };
+
+        let fee = get_performance_fee(&vault, Decimal::from_str("1.25").unwrap()).unwrap();
+
+        assert_eq!(fee.amount, Uint128::new(8));
+    }
+
+    #[test]
+    fn fee_is_only_charged_on_value_added_since_last_checkpoint() {
+        let vault = Vault {
+            performance_assessment_strategy: Some(
+                PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                    performance_fee: PerformanceFee {
+                        rate: Decimal::percent(10),
+                        high_water_mark: None,
+                        settled_added_value: Some(Uint128::new(150)),
+                    },
+                },
+            ),
+            ..get_vault(
+                Uint128::new(2000),
+                Uint128::new(1000),
+                Uint128::zero(),
+                Uint128::new(1000),
+                Uint128::zero(),
+            )
+        };
+
+        let fee = get_performance_fee(&vault, Decimal::from_str("1.25").unwrap()).unwrap();
+
+        assert_eq!(fee.amount, Uint128::new(8));
+    }
+
     #[test]
     fn swap_adjustment_specific_fee_level_is_used() {
         let mut deps = mock_dependencies();
@@ -367,6 +471,9 @@ mod tests {
             base_receive_amount: ONE,
             multiplier: Decimal::one(),
             increase_only: false,
+            curve: None,
+            min_swap_amount: None,
+            max_swap_amount: None,
         };
 
         let vault = Vault {
@@ -0,0 +1,152 @@
+use super::authz::{create_authz_exec_message, create_authz_exec_messages};
+use crate::constants::{
+    AFTER_CLAIM_STAKING_REWARDS_REPLY_ID, AFTER_PULL_FUNDS_REPLY_ID, MAX_CONSECUTIVE_FAILED_PULLS,
+};
+use crate::state::events::create_event;
+use crate::state::triggers::delete_trigger;
+use crate::state::vaults::update_vault;
+use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use crate::types::vault::{Vault, VaultStatus};
+use cosmwasm_std::{Addr, Coin, Env, QuerierWrapper, StdResult, Storage, SubMsg};
+use osmosis_std::types::cosmos::authz::v1beta1::AuthzQuerier;
+use osmosis_std::types::cosmos::bank::v1beta1::MsgSend;
+use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+/// `cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward`, which the pinned osmosis-std does not
+/// generate.
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct MsgWithdrawDelegatorReward {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+}
+
+impl MsgWithdrawDelegatorReward {
+    pub const TYPE_URL: &'static str = "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward";
+}
+
+pub fn get_amount_to_pull(vault: &Vault) -> Coin {
+    Coin::new(
+        vault
+            .swap_amount
+            .saturating_sub(vault.balance.amount)
+            .into(),
+        vault.get_swap_denom(),
+    )
+}
+
+pub const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+pub fn create_pull_funds_message(env: &Env, vault: &Vault, amount: Coin) -> SubMsg {
+    SubMsg::reply_always(
+        create_authz_exec_message(
+            env.contract.address.clone(),
+            String::from(MSG_SEND_TYPE_URL),
+            MsgSend {
+                from_address: vault.owner.to_string(),
+                to_address: env.contract.address.to_string(),
+                amount: vec![ProtoCoin {
+                    denom: amount.denom,
+                    amount: amount.amount.to_string(),
+                }],
+            },
+        ),
+        AFTER_PULL_FUNDS_REPLY_ID,
+    )
+}
+
+pub fn create_claim_staking_rewards_message(
+    env: &Env,
+    vault: &Vault,
+    validator_addresses: Vec<String>,
+) -> SubMsg {
+    SubMsg::reply_always(
+        create_authz_exec_messages(
+            env.contract.address.clone(),
+            String::from(MsgWithdrawDelegatorReward::TYPE_URL),
+            validator_addresses
+                .into_iter()
+                .map(|validator_address| MsgWithdrawDelegatorReward {
+                    delegator_address: vault.owner.to_string(),
+                    validator_address,
+                })
+                .collect(),
+        ),
+        AFTER_CLAIM_STAKING_REWARDS_REPLY_ID,
+    )
+}
+
+/// wasmd redacts the error returned to a failed authz exec reply, so the owner's grant to this
+/// contract is checked before pulling to know why the pull would fail.
+pub fn get_invalid_grant_reason(
+    querier: &QuerierWrapper,
+    env: &Env,
+    owner: &Addr,
+    msg_type_url: &str,
+) -> Option<ExecutionSkippedReason> {
+    let grants = AuthzQuerier::new(querier)
+        .grants(
+            owner.to_string(),
+            env.contract.address.to_string(),
+            msg_type_url.to_string(),
+            None,
+        )
+        .map(|response| response.grants)
+        .unwrap_or_default();
+
+    if grants.is_empty() {
+        return Some(ExecutionSkippedReason::FundingGrantNotFound);
+    }
+
+    let block_time = (
+        env.block.time.seconds() as i64,
+        env.block.time.subsec_nanos() as i32,
+    );
+
+    if grants.iter().all(|grant| {
+        grant
+            .expiration
+            .as_ref()
+            .is_some_and(|expiration| (expiration.seconds, expiration.nanos) < block_time)
+    }) {
+        return Some(ExecutionSkippedReason::FundingGrantExpired);
+    }
+
+    None
+}
+
+/// Records a skipped execution for a vault that could not pull funds from its owner. After
+/// MAX_CONSECUTIVE_FAILED_PULLS consecutive failures the vault is made inactive and its
+/// trigger removed, and can be reactivated with a deposit.
+pub fn record_failed_pull(
+    storage: &mut dyn Storage,
+    env: &Env,
+    mut vault: Vault,
+    reason: ExecutionSkippedReason,
+) -> StdResult<Vault> {
+    create_event(
+        storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::DcaVaultExecutionSkipped { reason },
+        ),
+    )?;
+
+    let consecutive_failures = vault.funding_strategy.consecutive_failures() + 1;
+
+    if consecutive_failures >= MAX_CONSECUTIVE_FAILED_PULLS {
+        vault.status = VaultStatus::Inactive;
+        vault.funding_strategy = vault.funding_strategy.with_consecutive_failures(0);
+        delete_trigger(storage, vault.id)?;
+    } else {
+        vault.funding_strategy = vault
+            .funding_strategy
+            .with_consecutive_failures(consecutive_failures);
+    }
+
+    update_vault(storage, vault.clone())?;
+
+    Ok(vault)
+}
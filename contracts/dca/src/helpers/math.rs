@@ -1,4 +1,4 @@
-use cosmwasm_std::{CheckedMultiplyRatioError, Decimal, Uint128};
+use cosmwasm_std::{CheckedMultiplyRatioError, Decimal, OverflowError, Uint128};
 
 pub fn checked_mul(a: Uint128, b: Decimal) -> Result<Uint128, CheckedMultiplyRatioError> {
     a.checked_multiply_ratio(
@@ -7,6 +7,70 @@ pub fn checked_mul(a: Uint128, b: Decimal) -> Result<Uint128, CheckedMultiplyRat
     )
 }
 
+pub fn median(mut values: Vec<Decimal>) -> Option<Decimal> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort();
+
+    let middle = values.len() / 2;
+
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) * Decimal::percent(50)
+    } else {
+        values[middle]
+    })
+}
+
+/// Raises `base` to a fractional `exponent` by multiplying its integer power by successive
+/// square roots for each binary digit of the fractional part, failing if the result overflows.
+pub fn checked_decimal_pow(base: Decimal, exponent: Decimal) -> Result<Decimal, OverflowError> {
+    let integer_exponent = exponent.atomics().u128() / 10u128.pow(Decimal::DECIMAL_PLACES);
+
+    let mut result = base.checked_pow(integer_exponent as u32)?;
+    let mut fraction = exponent - Decimal::from_ratio(integer_exponent, 1u128);
+    let mut root = base;
+
+    while !fraction.is_zero() && root != Decimal::one() {
+        let previous_root = root;
+
+        root = root.sqrt();
+        fraction *= Decimal::percent(200);
+
+        if fraction >= Decimal::one() {
+            result = result.checked_mul(root)?;
+            fraction -= Decimal::one();
+        }
+
+        if root == previous_root {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Root mean square of the relative changes between consecutive prices, or `None` when
+/// fewer than two prices (or a zero price) are provided.
+pub fn realized_volatility(prices: &[Decimal]) -> Option<Decimal> {
+    if prices.len() < 2 {
+        return None;
+    }
+
+    let sum_of_squares = prices.windows(2).try_fold(Decimal::zero(), |sum, window| {
+        if window[0].is_zero() {
+            return None;
+        }
+
+        let change = window[0].abs_diff(window[1]) / window[0];
+
+        Some(sum.saturating_add(change.saturating_mul(change)))
+    })?;
+
+    Some((sum_of_squares / Decimal::from_ratio((prices.len() - 1) as u128, 1u128)).sqrt())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +168,92 @@ mod tests {
             Uint128::new(1500000000000000000000000000000000000)
         );
     }
+
+    #[test]
+    fn median_of_no_values_is_none() {
+        assert_eq!(median(vec![]), None);
+    }
+
+    #[test]
+    fn median_of_odd_number_of_values_is_middle_value() {
+        assert_eq!(
+            median(vec![
+                Decimal::percent(120),
+                Decimal::percent(80),
+                Decimal::percent(100)
+            ]),
+            Some(Decimal::percent(100))
+        );
+    }
+
+    #[test]
+    fn median_of_even_number_of_values_is_mean_of_middle_values() {
+        assert_eq!(
+            median(vec![
+                Decimal::percent(150),
+                Decimal::percent(80),
+                Decimal::percent(100),
+                Decimal::percent(90)
+            ]),
+            Some(Decimal::percent(95))
+        );
+    }
+
+    #[test]
+    fn realized_volatility_of_single_price_is_none() {
+        assert_eq!(realized_volatility(&[Decimal::one()]), None);
+    }
+
+    #[test]
+    fn realized_volatility_of_constant_prices_is_zero() {
+        assert_eq!(
+            realized_volatility(&[Decimal::one(), Decimal::one(), Decimal::one()]),
+            Some(Decimal::zero())
+        );
+    }
+
+    #[test]
+    fn realized_volatility_is_root_mean_square_of_relative_changes() {
+        assert_eq!(
+            realized_volatility(&[
+                Decimal::percent(100),
+                Decimal::percent(110),
+                Decimal::percent(99)
+            ]),
+            Some(Decimal::percent(10))
+        );
+    }
+
+    #[test]
+    fn checked_decimal_pow_with_integer_exponent_is_integer_power() {
+        assert_eq!(
+            checked_decimal_pow(Decimal::percent(150), Decimal::percent(200)).unwrap(),
+            Decimal::percent(225)
+        );
+    }
+
+    #[test]
+    fn checked_decimal_pow_with_fractional_exponent_uses_square_roots() {
+        assert_eq!(
+            checked_decimal_pow(Decimal::percent(400), Decimal::percent(150)).unwrap(),
+            Decimal::percent(800)
+        );
+    }
+
+    #[test]
+    fn checked_decimal_pow_with_zero_exponent_is_one() {
+        assert_eq!(
+            checked_decimal_pow(Decimal::percent(400), Decimal::zero()).unwrap(),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn checked_decimal_pow_with_overflowing_result_fails() {
+        assert!(checked_decimal_pow(
+            Decimal::from_ratio(1000000u128, 1u128),
+            Decimal::percent(1000)
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,81 @@
+use crate::{
+    constants::LATEST_STORAGE_VERSION,
+    state::{
+        migrations::{
+            clear_migration_progress, get_migration_progress, get_storage_version,
+            save_migration_progress, save_storage_version,
+        },
+        swap_adjustments::migrate_legacy_swap_adjustments,
+        vaults::{backfill_vault_totals, migrate_vault_data, reindex_vaults},
+    },
+    types::migration::MigrationProgress,
+};
+use cosmwasm_std::{StdResult, Storage};
+
+type MigrationStep = fn(&mut dyn Storage, Option<u128>, u16) -> StdResult<Option<u128>>;
+
+fn migration_step(version: u16) -> Option<MigrationStep> {
+    match version {
+        2 => Some(migrate_vault_data),
+        3 => Some(backfill_vault_totals),
+        4 => Some(reindex_vaults),
+        5 => Some(migrate_legacy_swap_adjustments),
+        _ => None,
+    }
+}
+
+pub fn start_migration(store: &mut dyn Storage) -> StdResult<()> {
+    if get_migration_progress(store)?.is_some() {
+        return Ok(());
+    }
+
+    let storage_version = get_storage_version(store)?;
+
+    if storage_version < LATEST_STORAGE_VERSION {
+        save_migration_progress(
+            store,
+            &MigrationProgress {
+                version: storage_version + 1,
+                cursor: None,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn run_migration_batch(
+    store: &mut dyn Storage,
+    limit: u16,
+) -> StdResult<Option<MigrationProgress>> {
+    let progress = match get_migration_progress(store)? {
+        Some(progress) => progress,
+        None => return Ok(None),
+    };
+
+    let cursor = match migration_step(progress.version) {
+        Some(step) => step(store, progress.cursor, limit)?,
+        None => None,
+    };
+
+    if cursor.is_some() {
+        let progress = MigrationProgress { cursor, ..progress };
+        save_migration_progress(store, &progress)?;
+        return Ok(Some(progress));
+    }
+
+    save_storage_version(store, progress.version)?;
+
+    if progress.version < LATEST_STORAGE_VERSION {
+        let progress = MigrationProgress {
+            version: progress.version + 1,
+            cursor: None,
+        };
+        save_migration_progress(store, &progress)?;
+        return Ok(Some(progress));
+    }
+
+    clear_migration_progress(store);
+
+    Ok(None)
+}
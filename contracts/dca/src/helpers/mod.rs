@@ -2,10 +2,13 @@ pub mod authz;
 pub mod coin;
 pub mod disbursement;
 pub mod fees;
+pub mod funding;
 pub mod math;
+pub mod migrations;
 pub mod price;
 pub mod routes;
 pub mod state;
+pub mod swap_adjustments;
 pub mod swaps;
 pub mod time;
 pub mod validation;
@@ -1,29 +1,71 @@
 use super::routes::{calculate_route, get_pool, get_token_out_denom};
 use crate::{
     state::config::get_config,
-    types::{pair::Pair, position_type::PositionType},
+    types::{
+        pair::Pair,
+        position_type::PositionType,
+        price_history::{PriceCandle, PriceSample},
+        price_source::{OraclePriceResponse, OracleQueryMsg, PriceSource},
+    },
+};
+use cosmwasm_std::{
+    Addr, Coin, Decimal, Deps, Env, Fraction, QuerierWrapper, StdError, StdResult, Uint128,
 };
-use cosmwasm_std::{Coin, Decimal, Deps, Env, QuerierWrapper, StdError, StdResult, Uint128};
 use osmosis_std::{
     shim::Timestamp,
-    types::osmosis::{poolmanager::v1beta1::PoolmanagerQuerier, twap::v1beta1::TwapQuerier},
+    types::osmosis::{
+        gamm::v2::GammQuerier, poolmanager::v1beta1::PoolmanagerQuerier, twap::v1beta1::TwapQuerier,
+    },
 };
+use std::cmp::{max, min};
 
 pub fn query_belief_price(
     deps: &Deps,
     env: &Env,
     pair: &Pair,
-    mut swap_denom: String,
+    swap_denom: String,
 ) -> StdResult<Decimal> {
+    let (_, belief_price) = query_market_and_belief_prices(deps, env, pair, swap_denom)?;
+    Ok(belief_price)
+}
+
+/// Returns the price of the target denom in the swap denom along the pair route, both as
+/// reported by the price source and with the swap fee of each pool applied.
+pub fn query_market_and_belief_prices(
+    deps: &Deps,
+    env: &Env,
+    pair: &Pair,
+    mut swap_denom: String,
+) -> StdResult<(Decimal, Decimal)> {
     let route = match pair.position_type(swap_denom.clone()) {
         PositionType::Enter => pair.route.clone(),
         PositionType::Exit => pair.route.clone().into_iter().rev().collect(),
     };
 
-    let mut price = Decimal::one();
-
     let config = get_config(deps.storage)?;
 
+    let price_source = pair
+        .price_source
+        .clone()
+        .unwrap_or(PriceSource::ArithmeticTwap);
+
+    let twap_start_time = Some(Timestamp {
+        seconds: (env.block.time.seconds() - pair.twap_period(config.twap_period)) as i64,
+        nanos: 0,
+    });
+
+    let mut market_price = match &price_source {
+        PriceSource::Oracle { contract_address } => query_oracle_price(
+            &deps.querier,
+            contract_address,
+            pair.other_denom(swap_denom.clone()),
+            swap_denom.clone(),
+        )?,
+        _ => Decimal::one(),
+    };
+
+    let mut belief_price = market_price;
+
     for pool_id in route.into_iter() {
         let target_denom = get_token_out_denom(&deps.querier, swap_denom.clone(), pool_id)?;
 
@@ -36,27 +78,122 @@ pub fn query_belief_price(
             .parse::<Decimal>()
             .unwrap();
 
-        let pool_price = TwapQuerier::new(&deps.querier)
-            .arithmetic_twap_to_now(
-                pool_id,
-                target_denom.clone(),
-                swap_denom.clone(),
-                Some(Timestamp {
-                    seconds: (env.block.time.seconds() - config.twap_period) as i64,
-                    nanos: 0,
-                }),
-            )
-            .unwrap()
-            .arithmetic_twap
-            .parse::<Decimal>()?
-            * (Decimal::one() + swap_fee);
+        let pool_price = match &price_source {
+            PriceSource::ArithmeticTwap => TwapQuerier::new(&deps.querier)
+                .arithmetic_twap_to_now(
+                    pool_id,
+                    target_denom.clone(),
+                    swap_denom.clone(),
+                    twap_start_time.clone(),
+                )
+                .unwrap()
+                .arithmetic_twap
+                .parse::<Decimal>()?,
+            PriceSource::GeometricTwap => TwapQuerier::new(&deps.querier)
+                .geometric_twap_to_now(
+                    pool_id,
+                    target_denom.clone(),
+                    swap_denom.clone(),
+                    twap_start_time.clone(),
+                )?
+                .geometric_twap
+                .parse::<Decimal>()?,
+            PriceSource::Spot => GammQuerier::new(&deps.querier)
+                .spot_price(pool_id, target_denom.clone(), swap_denom.clone())?
+                .spot_price
+                .parse::<Decimal>()?,
+            PriceSource::Oracle { .. } => Decimal::one(),
+        };
+
+        belief_price = pool_price * (Decimal::one() + swap_fee) * belief_price;
+        market_price = pool_price * market_price;
+
+        swap_denom = target_denom;
+    }
+
+    Ok((market_price, belief_price))
+}
 
-        price = pool_price * price;
+/// Returns the arithmetic TWAP price of the swap denom over each of the latest `windows`
+/// consecutive periods of `window_seconds` ending at the current block, oldest first.
+pub fn query_twap_prices(
+    deps: &Deps,
+    env: &Env,
+    pair: &Pair,
+    mut swap_denom: String,
+    window_seconds: u64,
+    windows: u8,
+) -> StdResult<Vec<Decimal>> {
+    let route = match pair.position_type(swap_denom.clone()) {
+        PositionType::Enter => pair.route.clone(),
+        PositionType::Exit => pair.route.clone().into_iter().rev().collect(),
+    };
+
+    let mut hops = vec![];
 
+    for pool_id in route.into_iter() {
+        let target_denom = get_token_out_denom(&deps.querier, swap_denom.clone(), pool_id)?;
+        hops.push((pool_id, target_denom.clone(), swap_denom));
         swap_denom = target_denom;
     }
 
-    Ok(price)
+    (0..windows as u64)
+        .rev()
+        .map(|window| {
+            let end_time = env
+                .block
+                .time
+                .seconds()
+                .saturating_sub(window * window_seconds);
+
+            hops.iter().try_fold(
+                Decimal::one(),
+                |price, (pool_id, target_denom, swap_denom)| -> StdResult<Decimal> {
+                    Ok(price
+                        * TwapQuerier::new(&deps.querier)
+                            .arithmetic_twap(
+                                *pool_id,
+                                target_denom.clone(),
+                                swap_denom.clone(),
+                                Some(Timestamp {
+                                    seconds: end_time.saturating_sub(window_seconds) as i64,
+                                    nanos: 0,
+                                }),
+                                Some(Timestamp {
+                                    seconds: end_time as i64,
+                                    nanos: 0,
+                                }),
+                            )?
+                            .arithmetic_twap
+                            .parse::<Decimal>()?)
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn query_oracle_price(
+    querier: &QuerierWrapper,
+    contract_address: &Addr,
+    base_denom: String,
+    quote_denom: String,
+) -> StdResult<Decimal> {
+    let response: OraclePriceResponse = querier.query_wasm_smart(
+        contract_address,
+        &OracleQueryMsg::Price {
+            base_denom: base_denom.clone(),
+            quote_denom: quote_denom.clone(),
+        },
+    )?;
+
+    if response.price.is_zero() {
+        return Err(StdError::generic_err(format!(
+            "oracle {} returned a zero price for {} in {}",
+            contract_address, base_denom, quote_denom
+        )));
+    }
+
+    Ok(response.price)
 }
 
 pub fn query_price(
@@ -88,6 +225,47 @@ pub fn query_price(
     Ok(Decimal::from_ratio(swap_amount.amount, token_out_amount))
 }
 
+pub fn get_base_denom_price(
+    pair: &Pair,
+    swap_denom: String,
+    belief_price: Decimal,
+) -> Option<Decimal> {
+    match pair.position_type(swap_denom) {
+        PositionType::Enter => Some(belief_price),
+        PositionType::Exit => belief_price.inv(),
+    }
+}
+
+/// Groups samples, ordered oldest first, into candles of `resolution` seconds.
+pub fn get_price_candles(samples: &[PriceSample], resolution: u64) -> Vec<PriceCandle> {
+    let mut candles: Vec<PriceCandle> = vec![];
+
+    for sample in samples {
+        let open_time = sample
+            .timestamp
+            .minus_seconds(sample.timestamp.seconds() % resolution);
+
+        match candles.last_mut() {
+            Some(candle) if candle.open_time == open_time => {
+                candle.high = max(candle.high, sample.price);
+                candle.low = min(candle.low, sample.price);
+                candle.close = sample.price;
+                candle.samples += 1;
+            }
+            _ => candles.push(PriceCandle {
+                open_time,
+                open: sample.price,
+                high: sample.price,
+                low: sample.price,
+                close: sample.price,
+                samples: 1,
+            }),
+        }
+    }
+
+    candles
+}
+
 pub fn calculate_slippage(actual_price: Decimal, belief_price: Decimal) -> Decimal {
     let difference = actual_price
         .checked_sub(belief_price)
@@ -103,19 +281,22 @@ pub fn calculate_slippage(actual_price: Decimal, belief_price: Decimal) -> Decim
 #[cfg(test)]
 mod query_belief_price_tests {
     use super::*;
+    use crate::types::price_source::OraclePriceResponse;
     use crate::{
         constants::SWAP_FEE_RATE,
         tests::{
             helpers::instantiate_contract,
-            mocks::{calc_mock_dependencies, ADMIN},
+            mocks::{calc_mock_dependencies, mock_oracle, ADMIN, ORACLE},
         },
     };
     use cosmwasm_std::{
         testing::{mock_env, mock_info},
-        to_binary, StdError,
+        to_binary, ContractResult, StdError, SystemResult, WasmQuery,
     };
+    use osmosis_std::types::osmosis::gamm::v2::{QuerySpotPriceRequest, QuerySpotPriceResponse};
     use osmosis_std::types::osmosis::twap::v1beta1::{
-        ArithmeticTwapRequest, ArithmeticTwapResponse,
+        ArithmeticTwapRequest, ArithmeticTwapResponse, GeometricTwapResponse,
+        GeometricTwapToNowRequest,
     };
     use prost::Message;
     use std::str::FromStr;
@@ -198,4 +379,185 @@ mod query_belief_price_tests {
                 * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
         );
     }
+
+    #[test]
+    fn query_belief_price_with_geometric_twap_price_source_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_stargate(|path, data| {
+            if path == "/osmosis.twap.v1beta1.Query/GeometricTwapToNow" {
+                let price = match GeometricTwapToNowRequest::decode(data.as_slice())
+                    .unwrap()
+                    .pool_id
+                {
+                    3 => "0.7",
+                    _ => "1.0",
+                };
+
+                return to_binary(&GeometricTwapResponse {
+                    geometric_twap: price.to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
+
+        let pair = Pair {
+            price_source: Some(PriceSource::GeometricTwap),
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        assert_eq!(
+            price,
+            Decimal::percent(70) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_spot_price_source_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_stargate(|path, data| {
+            if path == "/osmosis.gamm.v2.Query/SpotPrice" {
+                let price = match QuerySpotPriceRequest::decode(data.as_slice())
+                    .unwrap()
+                    .pool_id
+                {
+                    1 => "0.5",
+                    4 => "1.5",
+                    _ => "1.0",
+                };
+
+                return to_binary(&QuerySpotPriceResponse {
+                    spot_price: price.to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
+
+        let pair = Pair {
+            route: vec![4, 1],
+            price_source: Some(PriceSource::Spot),
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        assert_eq!(
+            price,
+            Decimal::percent(50)
+                * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+                * Decimal::percent(150)
+                * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_oracle_price_source_should_succeed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_wasm(mock_oracle(Decimal::percent(150)));
+
+        let pair = Pair {
+            route: vec![4, 1],
+            price_source: Some(PriceSource::Oracle {
+                contract_address: Addr::unchecked(ORACLE),
+            }),
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        assert_eq!(
+            price,
+            Decimal::percent(150)
+                * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+                * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_oracle_price_source_should_send_price_query_to_oracle() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg }
+                if contract_addr == ORACLE
+                    && msg.as_slice()
+                        == br#"{"price":{"base_denom":"uosmo","quote_denom":"stake"}}"# =>
+            {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&OraclePriceResponse {
+                        price: Decimal::percent(150),
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => SystemResult::Ok(ContractResult::Err(format!(
+                "unexpected oracle query {:?}",
+                query
+            ))),
+        });
+
+        let pair = Pair {
+            price_source: Some(PriceSource::Oracle {
+                contract_address: Addr::unchecked(ORACLE),
+            }),
+            ..Pair::default()
+        };
+
+        let price =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap();
+
+        assert_eq!(
+            price,
+            Decimal::percent(150) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap())
+        );
+    }
+
+    #[test]
+    fn query_belief_price_with_oracle_returning_zero_price_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_wasm(mock_oracle(Decimal::zero()));
+
+        let pair = Pair {
+            price_source: Some(PriceSource::Oracle {
+                contract_address: Addr::unchecked(ORACLE),
+            }),
+            ..Pair::default()
+        };
+
+        let err =
+            query_belief_price(&deps.as_ref(), &env, &pair.clone(), pair.quote_denom).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: oracle oracle returned a zero price for uosmo in stake"
+        );
+    }
 }
@@ -113,6 +113,9 @@ mod get_token_out_denom_tests {
             route: vec![0],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         let swap_denom = "not_in_pair".to_string();
@@ -147,6 +150,9 @@ mod calculate_route_tests {
             route: vec![0],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         let swap_denom = "not_in_pair".to_string();
@@ -171,6 +177,9 @@ mod calculate_route_tests {
             route: vec![2],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         let err =
@@ -193,6 +202,9 @@ mod calculate_route_tests {
             route: vec![0, 2],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         let err =
@@ -215,6 +227,9 @@ mod calculate_route_tests {
             route: vec![0, 1],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         let err =
@@ -237,6 +252,9 @@ mod calculate_route_tests {
             route: vec![0],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UOSMO.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         assert_eq!(
@@ -264,6 +282,9 @@ mod calculate_route_tests {
             route: vec![0, 1],
             quote_denom: DENOM_UATOM.to_string(),
             base_denom: DENOM_UION.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         assert_eq!(
@@ -303,6 +324,9 @@ mod calculate_route_tests {
             route: vec![2, 1, 0],
             quote_denom: DENOM_USDC.to_string(),
             base_denom: DENOM_UATOM.to_string(),
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         };
 
         assert_eq!(
@@ -0,0 +1,286 @@
+use super::math::median;
+use crate::{
+    state::{
+        risk_weighted_average_models::get_risk_weighted_average_model_for_strategy,
+        roles::get_role_members,
+        swap_adjustments::{
+            get_swap_adjustment_aggregation, get_swap_adjustment_submission,
+            save_swap_adjustment_submission, update_swap_adjustment,
+        },
+    },
+    types::{
+        role::Role, swap_adjustment::PublisherSubmissionStatus,
+        swap_adjustment_strategy::SwapAdjustmentStrategy,
+    },
+};
+use cosmwasm_std::{Addr, Decimal, Event, StdResult, Storage, Timestamp};
+
+pub fn get_publisher_submission_statuses(
+    storage: &dyn Storage,
+    strategy: &SwapAdjustmentStrategy,
+    block_time: Timestamp,
+) -> StdResult<(Vec<PublisherSubmissionStatus>, Option<Decimal>)> {
+    let aggregation = get_swap_adjustment_aggregation(storage)?;
+    let model = get_risk_weighted_average_model_for_strategy(storage, strategy)?;
+    let ttl = strategy.ttl(model.as_ref());
+
+    let mut statuses = get_role_members(storage, &Role::SwapAdjustmentPublisher)?
+        .into_iter()
+        .map(|publisher| {
+            let submission = get_swap_adjustment_submission(storage, strategy, &publisher)?;
+            Ok(PublisherSubmissionStatus {
+                is_fresh: submission.as_ref().is_some_and(|submission| {
+                    submission.submitted_at.plus_seconds(ttl) > block_time
+                }),
+                is_outlier: false,
+                publisher,
+                submission,
+            })
+        })
+        .collect::<StdResult<Vec<PublisherSubmissionStatus>>>()?;
+
+    let fresh_values = statuses
+        .iter()
+        .filter(|status| status.is_fresh)
+        .flat_map(|status| {
+            status
+                .submission
+                .as_ref()
+                .map(|submission| submission.value)
+        })
+        .collect::<Vec<Decimal>>();
+
+    let aggregation = match aggregation {
+        Some(aggregation) if fresh_values.len() >= aggregation.quorum as usize => aggregation,
+        _ => return Ok((statuses, None)),
+    };
+
+    let aggregated_value = median(fresh_values);
+
+    if let Some(aggregated_value) = aggregated_value {
+        for status in statuses.iter_mut().filter(|status| status.is_fresh) {
+            if let Some(submission) = &status.submission {
+                let deviation = if submission.value > aggregated_value {
+                    submission.value - aggregated_value
+                } else {
+                    aggregated_value - submission.value
+                };
+                status.is_outlier = deviation > aggregated_value * aggregation.max_deviation;
+            }
+        }
+    }
+
+    Ok((statuses, aggregated_value))
+}
+
+pub fn publish_swap_adjustment(
+    storage: &mut dyn Storage,
+    block_time: Timestamp,
+    publisher: &Addr,
+    strategy: SwapAdjustmentStrategy,
+    value: Decimal,
+) -> StdResult<Vec<Event>> {
+    if get_swap_adjustment_aggregation(storage)?.is_none() {
+        update_swap_adjustment(storage, strategy, value, block_time)?;
+        return Ok(vec![]);
+    }
+
+    save_swap_adjustment_submission(storage, &strategy, publisher, value, block_time)?;
+
+    let (statuses, aggregated_value) =
+        get_publisher_submission_statuses(storage, &strategy, block_time)?;
+
+    let aggregated_value = match aggregated_value {
+        Some(aggregated_value) => aggregated_value,
+        None => return Ok(vec![]),
+    };
+
+    update_swap_adjustment(storage, strategy.clone(), aggregated_value, block_time)?;
+
+    Ok(statuses
+        .into_iter()
+        .filter(|status| status.is_outlier)
+        .flat_map(|status| {
+            status.submission.map(|submission| {
+                Event::new("swap_adjustment_outlier")
+                    .add_attribute("strategy", strategy.key())
+                    .add_attribute("publisher", status.publisher)
+                    .add_attribute("value", submission.value.to_string())
+                    .add_attribute("median", aggregated_value.to_string())
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod publish_swap_adjustment_tests {
+    use super::*;
+    use crate::{
+        state::{
+            roles::grant_role,
+            swap_adjustments::{get_swap_adjustment, save_swap_adjustment_aggregation},
+        },
+        types::{
+            position_type::PositionType, swap_adjustment::SwapAdjustmentAggregation,
+            swap_adjustment_strategy::BaseDenom,
+        },
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    fn strategy() -> SwapAdjustmentStrategy {
+        SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        }
+    }
+
+    fn setup_publishers(storage: &mut dyn Storage, quorum: u8) -> Vec<Addr> {
+        save_swap_adjustment_aggregation(
+            storage,
+            &Some(SwapAdjustmentAggregation {
+                quorum,
+                max_deviation: Decimal::percent(20),
+            }),
+        )
+        .unwrap();
+
+        ["publisher-1", "publisher-2", "publisher-3"]
+            .into_iter()
+            .map(|publisher| {
+                let publisher = Addr::unchecked(publisher);
+                grant_role(storage, &Role::SwapAdjustmentPublisher, &publisher).unwrap();
+                publisher
+            })
+            .collect()
+    }
+
+    #[test]
+    fn without_aggregation_updates_value_directly() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        publish_swap_adjustment(
+            deps.as_mut().storage,
+            env.block.time,
+            &Addr::unchecked("publisher-1"),
+            strategy(),
+            Decimal::percent(90),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy(), env.block.time),
+            Decimal::percent(90)
+        );
+    }
+
+    #[test]
+    fn without_quorum_does_not_update_value() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let publishers = setup_publishers(deps.as_mut().storage, 2);
+
+        publish_swap_adjustment(
+            deps.as_mut().storage,
+            env.block.time,
+            &publishers[0],
+            strategy(),
+            Decimal::percent(90),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy(), env.block.time),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn with_quorum_updates_value_to_median() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let publishers = setup_publishers(deps.as_mut().storage, 2);
+
+        for (publisher, value) in publishers.iter().zip([90, 100, 110]) {
+            publish_swap_adjustment(
+                deps.as_mut().storage,
+                env.block.time,
+                publisher,
+                strategy(),
+                Decimal::percent(value),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy(), env.block.time),
+            Decimal::percent(100)
+        );
+    }
+
+    #[test]
+    fn ignores_stale_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let publishers = setup_publishers(deps.as_mut().storage, 2);
+
+        publish_swap_adjustment(
+            deps.as_mut().storage,
+            env.block.time,
+            &publishers[0],
+            strategy(),
+            Decimal::percent(90),
+        )
+        .unwrap();
+
+        let later = env.block.time.plus_seconds(60 * 60 * 26);
+
+        publish_swap_adjustment(
+            deps.as_mut().storage,
+            later,
+            &publishers[1],
+            strategy(),
+            Decimal::percent(110),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy(), later),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn emits_outlier_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let publishers = setup_publishers(deps.as_mut().storage, 3);
+
+        let mut events = vec![];
+
+        for (publisher, value) in publishers.iter().zip([100, 105, 200]) {
+            events = publish_swap_adjustment(
+                deps.as_mut().storage,
+                env.block.time,
+                publisher,
+                strategy(),
+                Decimal::percent(value),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            events,
+            vec![Event::new("swap_adjustment_outlier")
+                .add_attribute("strategy", strategy().key())
+                .add_attribute("publisher", publishers[2].clone())
+                .add_attribute("value", Decimal::percent(200).to_string())
+                .add_attribute("median", Decimal::percent(105).to_string())]
+        );
+    }
+}
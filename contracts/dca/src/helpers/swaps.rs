@@ -58,7 +58,7 @@ mod create_osmosis_swap_message_tests {
         let env = mock_env();
 
         let swap_amount = Coin::new(ONE.into(), DENOM_UOSMO);
-        let minimum_receive_amount = Some(ONE);
+        let minimum_receive_amount = ONE;
         let belief_price = Decimal::one();
         let pair = Pair::default();
         let slippage_tolerance = Decimal::percent(100);
@@ -70,7 +70,7 @@ mod create_osmosis_swap_message_tests {
             swap_amount.clone(),
             slippage_tolerance,
             belief_price,
-            minimum_receive_amount,
+            Some(minimum_receive_amount),
             None,
             None,
         )
@@ -83,7 +83,7 @@ mod create_osmosis_swap_message_tests {
                 msg: MsgSwapExactAmountIn {
                     sender: env.contract.address.to_string(),
                     token_in: Some(swap_amount.clone().into()),
-                    token_out_min_amount: minimum_receive_amount.unwrap().to_string(),
+                    token_out_min_amount: minimum_receive_amount.to_string(),
                     routes: calculate_route(&deps.as_ref().querier, &pair, swap_amount.denom)
                         .unwrap(),
                 }
@@ -61,6 +61,42 @@ pub fn get_total_execution_duration(
     })
 }
 
+pub fn get_elapsed_intervals(
+    started_at: Timestamp,
+    current_timestamp: Timestamp,
+    interval: &TimeInterval,
+) -> u64 {
+    if current_timestamp <= started_at {
+        return 0;
+    }
+
+    let started_at_time = Utc
+        .timestamp_opt(started_at.seconds().try_into().unwrap(), 0)
+        .unwrap();
+
+    let current_time = Utc
+        .timestamp_opt(current_timestamp.seconds().try_into().unwrap(), 0)
+        .unwrap();
+
+    match interval {
+        TimeInterval::Monthly => {
+            let mut elapsed_intervals = 0;
+            let mut next_time = get_next_time(started_at_time, interval);
+
+            while next_time.le(&current_time) {
+                elapsed_intervals += 1;
+                next_time = get_next_time(next_time, interval);
+            }
+
+            elapsed_intervals
+        }
+        _ => {
+            ((current_time - started_at_time).num_seconds()
+                / get_duration(started_at_time, interval).num_seconds()) as u64
+        }
+    }
+}
+
 fn get_duration(previous: DateTime<Utc>, interval: &TimeInterval) -> Duration {
     match interval {
         TimeInterval::EveryBlock => Duration::seconds(1),
@@ -521,9 +557,8 @@ mod tests {
 
     #[test]
     fn execution_interval_elapsed_with_time_in_past_should_return_true() {
-        let current_time = Timestamp::from_seconds(Uint64::new(17000000000).try_into().unwrap());
-        let time_in_the_past =
-            Timestamp::from_seconds(Uint64::new(16000000000).try_into().unwrap());
+        let current_time = Timestamp::from_seconds(Uint64::new(17000000000).into());
+        let time_in_the_past = Timestamp::from_seconds(Uint64::new(16000000000).into());
 
         let result = target_time_elapsed(current_time, time_in_the_past);
 
@@ -532,9 +567,8 @@ mod tests {
 
     #[test]
     fn execution_interval_elapsed_with_time_in_future_should_return_false() {
-        let current_time = Timestamp::from_seconds(Uint64::new(17000000000).try_into().unwrap());
-        let time_in_the_future =
-            Timestamp::from_seconds(Uint64::new(18000000000).try_into().unwrap());
+        let current_time = Timestamp::from_seconds(Uint64::new(17000000000).into());
+        let time_in_the_future = Timestamp::from_seconds(Uint64::new(18000000000).into());
 
         let result = target_time_elapsed(current_time, time_in_the_future);
 
@@ -543,9 +577,8 @@ mod tests {
 
     #[test]
     fn execution_interval_elapsed_with_current_time_should_return_true() {
-        let current_time = Timestamp::from_seconds(Uint64::new(17000000000).try_into().unwrap());
-        let time_in_the_future =
-            Timestamp::from_seconds(Uint64::new(17000000000).try_into().unwrap());
+        let current_time = Timestamp::from_seconds(Uint64::new(17000000000).into());
+        let time_in_the_future = Timestamp::from_seconds(Uint64::new(17000000000).into());
 
         let result = target_time_elapsed(current_time, time_in_the_future);
 
@@ -632,7 +665,7 @@ mod tests {
 
 #[cfg(test)]
 mod get_total_execution_duration_tests {
-    use super::{get_total_execution_duration, shift_months};
+    use super::{get_elapsed_intervals, get_total_execution_duration, shift_months};
     use crate::types::time_interval::TimeInterval;
     use chrono::{Duration, TimeZone, Utc};
     use cosmwasm_std::Timestamp;
@@ -714,4 +747,46 @@ mod get_total_execution_duration_tests {
             shift_months(block_time_utc, 3) - block_time_utc,
         );
     }
+
+    #[test]
+    fn gets_elapsed_intervals() {
+        let started_at = Timestamp::from_seconds(1_640_995_200);
+
+        assert_eq!(
+            get_elapsed_intervals(started_at, started_at, &TimeInterval::Daily),
+            0
+        );
+        assert_eq!(
+            get_elapsed_intervals(
+                started_at,
+                started_at.plus_seconds(60 * 60 * 24 * 3 - 1),
+                &TimeInterval::Daily
+            ),
+            2
+        );
+        assert_eq!(
+            get_elapsed_intervals(
+                started_at,
+                started_at.plus_seconds(60 * 60 * 24 * 3),
+                &TimeInterval::Daily
+            ),
+            3
+        );
+        assert_eq!(
+            get_elapsed_intervals(
+                started_at,
+                started_at.plus_seconds(60 * 60 * 24 * 58),
+                &TimeInterval::Monthly
+            ),
+            1
+        );
+        assert_eq!(
+            get_elapsed_intervals(
+                started_at,
+                started_at.plus_seconds(60 * 60 * 24 * 31 * 5),
+                &TimeInterval::Monthly
+            ),
+            5
+        );
+    }
 }
@@ -1,20 +1,36 @@
-use super::routes::calculate_route;
+use super::price::{query_oracle_price, query_price};
+use super::routes::{calculate_route, get_pool};
+use crate::constants::{
+    MAX_LOCK_DURATION_SECONDS, MAX_MOVING_AVERAGE_RESOLUTION_SECONDS, MAX_VOLATILITY_WINDOWS,
+    MAX_WEIGHTED_SCALE_TIERS, ONE, PRICE_HISTORY_CAPACITY, TWAP_HISTORY_RETENTION_SECONDS,
+};
 use crate::error::ContractError;
-use crate::msg::ExecuteMsg;
 use crate::state::config::get_config;
 use crate::state::pairs::{find_pair, get_pairs};
-use crate::types::destination::Destination;
+use crate::state::risk_weighted_average_models::{
+    get_risk_weighted_average_model, get_risk_weighted_average_model_for_strategy,
+};
+use crate::state::roles::has_role;
+use crate::state::swap_adjustments::get_swap_adjustment_aggregation;
+use crate::state::vaults::get_vault;
+use crate::types::config::{Config, ConfigChange};
+use crate::types::destination::{Destination, DestinationAction, WeightedValidator};
 use crate::types::fee_collector::FeeCollector;
-use crate::types::pair::Pair;
+use crate::types::funding_strategy::FundingStrategyParams;
+use crate::types::lockable_duration::LockableDuration;
+use crate::types::pair::{Pair, PairConfig};
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
+use crate::types::price_source::PriceSource;
+use crate::types::risk_weighted_average_model::RiskWeightedAverageModel;
+use crate::types::role::Role;
+use crate::types::swap_adjustment::{SwapAdjustmentAggregation, SwapAdjustmentUpdate};
 use crate::types::swap_adjustment_strategy::{
-    SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
+    BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams, WeightedScaleCurve,
 };
 use crate::types::time_interval::TimeInterval;
 use crate::types::vault::{Vault, VaultStatus};
-use cosmwasm_std::{
-    from_binary, Addr, Coin, Decimal, Deps, Env, QuerierWrapper, Storage, Timestamp, Uint128,
-};
+use cosmwasm_std::{Addr, Coin, Decimal, Deps, Env, QuerierWrapper, Storage, Timestamp, Uint128};
+use osmosis_std::types::osmosis::superfluid::{SuperfluidAssetType, SuperfluidQuerier};
 
 pub fn assert_exactly_one_asset(funds: Vec<Coin>) -> Result<(), ContractError> {
     if funds.is_empty() || funds.len() > 1 {
@@ -46,6 +62,31 @@ pub fn assert_sender_is_admin(
     Ok(())
 }
 
+pub fn assert_sender_has_role(
+    storage: &dyn Storage,
+    sender: &Addr,
+    role: Role,
+) -> Result<(), ContractError> {
+    let config = get_config(storage)?;
+    if sender != config.admin && !has_role(storage, &role, sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_sender_can_manage_config_change(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    change: &ConfigChange,
+) -> Result<(), ContractError> {
+    match change {
+        ConfigChange::UpdateConfig { .. } => assert_sender_is_admin(storage, sender.clone()),
+        ConfigChange::CreateCustomSwapFee { .. } | ConfigChange::RemoveCustomSwapFee { .. } => {
+            assert_sender_has_role(storage, sender, Role::FeeManager)
+        }
+    }
+}
+
 pub fn assert_sender_is_executor(
     storage: &mut dyn Storage,
     env: &Env,
@@ -61,6 +102,20 @@ pub fn assert_sender_is_executor(
     Ok(())
 }
 
+pub fn assert_sender_can_publish_swap_adjustments(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if has_role(storage, &Role::SwapAdjustmentPublisher, sender) {
+        return Ok(());
+    }
+    if get_swap_adjustment_aggregation(storage)?.is_some() {
+        return Err(ContractError::Unauthorized {});
+    }
+    assert_sender_is_executor(storage, env, sender)
+}
+
 pub fn asset_sender_is_vault_owner(vault_owner: Addr, sender: Addr) -> Result<(), ContractError> {
     if sender != vault_owner {
         return Err(ContractError::Unauthorized {});
@@ -92,6 +147,13 @@ pub fn assert_sender_is_contract_or_admin(
     Ok(())
 }
 
+pub fn assert_sender_is_contract(sender: &Addr, env: &Env) -> Result<(), ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
 pub fn assert_vault_is_not_cancelled(vault: &Vault) -> Result<(), ContractError> {
     if vault.status == VaultStatus::Cancelled {
         return Err(ContractError::CustomError {
@@ -110,6 +172,28 @@ pub fn assert_swap_amount_is_greater_than_50000(swap_amount: Uint128) -> Result<
     Ok(())
 }
 
+pub fn assert_swap_amount_meets_pair_minimum(
+    storage: &dyn Storage,
+    swap_amount: Uint128,
+    denoms: [String; 2],
+) -> Result<(), ContractError> {
+    match find_pair(storage, denoms)
+        .ok()
+        .and_then(|pair| pair.minimum_swap_amount())
+    {
+        Some(minimum_swap_amount) if swap_amount < minimum_swap_amount => {
+            Err(ContractError::CustomError {
+                val: format!(
+                    "swap amount must be at least {} for this pair",
+                    minimum_swap_amount
+                ),
+            })
+        }
+        Some(_) => Ok(()),
+        None => assert_swap_amount_is_greater_than_50000(swap_amount),
+    }
+}
+
 pub fn assert_deposited_denom_matches_send_denom(
     deposit_denom: String,
     send_denom: String,
@@ -250,6 +334,62 @@ pub fn assert_pair_exists_for_denoms(
         })
 }
 
+pub fn assert_pair_does_not_exist(storage: &dyn Storage, pair: &Pair) -> Result<(), ContractError> {
+    if find_pair(storage, pair.denoms()).is_ok() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "pair for {} and {} already exists, use UpdatePairRoute to change its route",
+                pair.base_denom, pair.quote_denom
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_pair_is_enabled(pair: &Pair) -> Result<(), ContractError> {
+    if !pair.is_enabled() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "pair for {} and {} is disabled",
+                pair.base_denom, pair.quote_denom
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_slippage_tolerance_is_within_pair_maximum(
+    pair: &Pair,
+    slippage_tolerance: Decimal,
+) -> Result<(), ContractError> {
+    if let Some(max_slippage_tolerance) = pair.max_slippage_tolerance() {
+        if slippage_tolerance > max_slippage_tolerance {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "slippage tolerance must be less than or equal to {} for this pair",
+                    max_slippage_tolerance
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_pair_config_is_valid(config: &PairConfig) -> Result<(), ContractError> {
+    if let Some(twap_period) = config.twap_period {
+        assert_twap_period_is_valid(twap_period)?;
+    }
+    if let Some(max_slippage_tolerance) = config.max_slippage_tolerance {
+        assert_slippage_tolerance_is_less_than_or_equal_to_one(max_slippage_tolerance)?;
+    }
+    if config.minimum_swap_amount == Some(Uint128::zero()) {
+        return Err(ContractError::CustomError {
+            val: "minimum swap amount must be greater than 0".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_swap_adjusment_and_performance_assessment_strategies_are_compatible(
     swap_adjustment_strategy_params: &Option<SwapAdjustmentStrategyParams>,
     performance_assessment_strategy_params: &Option<PerformanceAssessmentStrategyParams>,
@@ -257,21 +397,22 @@ pub fn assert_swap_adjusment_and_performance_assessment_strategies_are_compatibl
     match swap_adjustment_strategy_params {
         Some(SwapAdjustmentStrategyParams::RiskWeightedAverage { .. }) => {
             match performance_assessment_strategy_params {
-                Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => Ok(()),
+                Some(_) => Ok(()),
                 None => Err(ContractError::CustomError {
                     val: "incompatible swap adjustment and performance assessment strategies"
                         .to_string(),
                 }),
             }
         }
-        Some(SwapAdjustmentStrategyParams::WeightedScale { .. }) => {
+        Some(SwapAdjustmentStrategyParams::WeightedScale { .. })
+        | Some(SwapAdjustmentStrategyParams::MovingAverage { .. })
+        | Some(SwapAdjustmentStrategyParams::ValueAveraging { .. })
+        | Some(SwapAdjustmentStrategyParams::VolatilityScaled { .. }) => {
             match performance_assessment_strategy_params {
-                Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => {
-                    Err(ContractError::CustomError {
-                        val: "incompatible swap adjustment and performance assessment strategies"
-                            .to_string(),
-                    })
-                }
+                Some(_) => Err(ContractError::CustomError {
+                    val: "incompatible swap adjustment and performance assessment strategies"
+                        .to_string(),
+                }),
                 None => Ok(()),
             }
         }
@@ -285,6 +426,37 @@ pub fn assert_swap_adjusment_and_performance_assessment_strategies_are_compatibl
     }
 }
 
+pub fn assert_funding_strategy_is_valid(
+    sender: &Addr,
+    owner: &Addr,
+    funds: &[Coin],
+    funding_strategy_params: &Option<FundingStrategyParams>,
+    swap_adjustment_strategy_params: &Option<SwapAdjustmentStrategyParams>,
+) -> Result<(), ContractError> {
+    match funding_strategy_params {
+        Some(FundingStrategyParams::AuthzPull { .. })
+        | Some(FundingStrategyParams::StakingRewards {}) => {
+            if sender != owner {
+                return Err(ContractError::Unauthorized {});
+            }
+            if !funds.is_empty() {
+                return Err(ContractError::CustomError {
+                    val: "vaults funded via authz pull cannot receive funds on creation"
+                        .to_string(),
+                });
+            }
+            if swap_adjustment_strategy_params.is_some() {
+                return Err(ContractError::CustomError {
+                    val: "vaults funded via authz pull cannot use a swap adjustment strategy"
+                        .to_string(),
+                });
+            }
+            Ok(())
+        }
+        _ => assert_exactly_one_asset(funds.to_vec()),
+    }
+}
+
 pub fn assert_destination_allocations_add_up_to_one(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
@@ -302,28 +474,104 @@ pub fn assert_destination_allocations_add_up_to_one(
     Ok(())
 }
 
-pub fn assert_contract_destination_callbacks_are_valid(
-    destinations: &[Destination],
+pub fn assert_destination_actions_are_valid(
+    deps: Deps,
     contract_address: &Addr,
+    destinations: &[Destination],
+    target_denom: String,
 ) -> Result<(), ContractError> {
     destinations
         .iter()
-        .filter(|d| d.address == *contract_address)
-        .try_for_each(|d| {
-            d.msg
-                .clone()
-                .map_or(Ok(()), |msg| match from_binary(&msg).unwrap() {
-                    ExecuteMsg::ZDelegate { .. }
-                    | ExecuteMsg::Deposit { .. }
-                    | ExecuteMsg::ZProvideLiquidity { .. } => Ok(()),
-                    _ => Err(ContractError::CustomError {
+        .try_for_each(|destination| match destination.action.clone() {
+            DestinationAction::Send => Ok(()),
+            DestinationAction::ExecuteContract { .. } => {
+                if destination.address == *contract_address {
+                    return Err(ContractError::CustomError {
                         val: "Cannot invoke provided destination callback against the DCA contract"
                             .to_string(),
-                    }),
+                    });
+                }
+                Ok(())
+            }
+            DestinationAction::Delegate { validator_address } => {
+                assert_denom_is_bond_denom(target_denom.clone())?;
+                assert_validator_is_valid(deps, validator_address.to_string())
+            }
+            DestinationAction::DelegateToValidators {
+                validators,
+                max_commission_rate,
+            } => {
+                assert_denom_is_bond_denom(target_denom.clone())?;
+                assert_weighted_validators_are_valid(deps, &validators)?;
+                max_commission_rate.map_or(Ok(()), |max_commission_rate| {
+                    assert_commission_rate_is_less_than_or_equal_to_one(max_commission_rate)
                 })
+            }
+            DestinationAction::ProvideLiquidity {
+                pool_id,
+                duration,
+                slippage_tolerance,
+                superfluid_validator_address,
+            } => {
+                assert_pool_contains_denom(&deps.querier, pool_id, target_denom.clone())?;
+                assert_lockable_duration_is_valid(&duration)?;
+                if let Some(superfluid_validator_address) = superfluid_validator_address {
+                    assert_superfluid_lock_is_valid(
+                        deps,
+                        pool_id,
+                        superfluid_validator_address,
+                        &duration,
+                    )?;
+                }
+                slippage_tolerance.map_or(Ok(()), |slippage_tolerance| {
+                    assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)
+                })
+            }
+            DestinationAction::DepositToVault { vault_id } => {
+                let vault =
+                    get_vault(deps.storage, vault_id).map_err(|_| ContractError::CustomError {
+                        val: format!("destination vault {} does not exist", vault_id),
+                    })?;
+
+                assert_vault_is_not_cancelled(&vault)?;
+
+                if vault.owner != destination.address {
+                    return Err(ContractError::CustomError {
+                        val: format!(
+                            "destination vault {} is not owned by {}",
+                            vault_id, destination.address
+                        ),
+                    });
+                }
+
+                assert_deposited_denom_matches_send_denom(
+                    target_denom.clone(),
+                    vault.get_swap_denom(),
+                )
+            }
         })
 }
 
+pub fn assert_pool_contains_denom(
+    querier: &QuerierWrapper,
+    pool_id: u64,
+    denom: String,
+) -> Result<(), ContractError> {
+    let pool = get_pool(querier, pool_id)?;
+
+    if !pool.pool_assets.iter().any(|asset| {
+        asset
+            .token
+            .clone()
+            .is_some_and(|token| token.denom == denom)
+    }) {
+        return Err(ContractError::CustomError {
+            val: format!("pool {} does not contain {}", pool_id, denom),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_fee_collector_allocations_add_up_to_one(
     fee_collectors: &[FeeCollector],
 ) -> Result<(), ContractError> {
@@ -363,6 +611,23 @@ pub fn assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
     Ok(())
 }
 
+pub fn assert_config_is_valid(deps: Deps, config: &Config) -> Result<(), ContractError> {
+    assert_fee_level_is_valid(&config.default_swap_fee_percent)?;
+    assert_fee_level_is_valid(&config.weighted_scale_swap_fee_percent)?;
+    assert_fee_level_is_valid(&config.automation_fee_percent)?;
+    assert_page_limit_is_valid(Some(config.default_page_limit))?;
+    assert_slippage_tolerance_is_less_than_or_equal_to_one(config.default_slippage_tolerance)?;
+    assert_twap_period_is_valid(config.twap_period)?;
+    assert_addresses_are_valid(deps, &config.executors, "executor")?;
+    assert_no_more_than_10_fee_collectors(&config.fee_collectors)?;
+    assert_fee_collector_addresses_are_valid(deps, &config.fee_collectors)?;
+    assert_fee_collector_allocations_add_up_to_one(&config.fee_collectors)?;
+    assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
+        config.risk_weighted_average_escrow_level,
+    )?;
+    Ok(())
+}
+
 pub fn assert_no_destination_allocations_are_zero(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
@@ -393,7 +658,11 @@ pub fn assert_validator_is_valid(
     deps: Deps,
     validator_address: String,
 ) -> Result<(), ContractError> {
-    let validator = deps.querier.query_validator(validator_address.clone()).ok();
+    let validator = deps
+        .querier
+        .query_validator(validator_address.clone())
+        .ok()
+        .flatten();
 
     if validator.is_none() {
         return Err(ContractError::CustomError {
@@ -403,6 +672,116 @@ pub fn assert_validator_is_valid(
     Ok(())
 }
 
+pub fn assert_weighted_validators_are_valid(
+    deps: Deps,
+    validators: &[WeightedValidator],
+) -> Result<(), ContractError> {
+    if validators.is_empty() || validators.len() > 10 {
+        return Err(ContractError::CustomError {
+            val: String::from("between 1 and 10 validators must be provided"),
+        });
+    }
+
+    for (index, validator) in validators.iter().enumerate() {
+        if validator.weight.is_zero() {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "validator {} weight must be > 0",
+                    validator.validator_address
+                ),
+            });
+        }
+
+        if validators[..index]
+            .iter()
+            .any(|other| other.validator_address == validator.validator_address)
+        {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "validator {} can only be provided once",
+                    validator.validator_address
+                ),
+            });
+        }
+
+        assert_validator_is_valid(deps, validator.validator_address.to_string())?;
+    }
+
+    if validators
+        .iter()
+        .try_fold(Decimal::zero(), |acc, validator| {
+            acc.checked_add(validator.weight)
+        })
+        != Ok(Decimal::percent(100))
+    {
+        return Err(ContractError::CustomError {
+            val: String::from("validator weights must add up to 1"),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn assert_commission_rate_is_less_than_or_equal_to_one(
+    commission_rate: Decimal,
+) -> Result<(), ContractError> {
+    if commission_rate > Decimal::percent(100) {
+        return Err(ContractError::CustomError {
+            val: String::from("max commission rate must be less than or equal to 1"),
+        });
+    }
+    Ok(())
+}
+
+/// The lockup module accepts any positive lock duration that fits in a Go
+/// `time.Duration` (i64 nanoseconds).
+pub fn assert_lockable_duration_is_valid(duration: &LockableDuration) -> Result<(), ContractError> {
+    if duration.seconds() == 0 {
+        return Err(ContractError::CustomError {
+            val: String::from("lock duration must be greater than 0 seconds"),
+        });
+    }
+
+    if duration.seconds() > MAX_LOCK_DURATION_SECONDS {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "lock duration of {} exceeds the maximum lock duration of {} seconds",
+                duration, MAX_LOCK_DURATION_SECONDS
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_superfluid_lock_is_valid(
+    deps: Deps,
+    pool_id: u64,
+    validator_address: Addr,
+    duration: &LockableDuration,
+) -> Result<(), ContractError> {
+    if duration.seconds() != LockableDuration::TwoWeeks.seconds() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "superfluid staking requires a lock duration of {}",
+                LockableDuration::TwoWeeks
+            ),
+        });
+    }
+
+    let lp_denom = format!("gamm/pool/{}", pool_id);
+
+    if !SuperfluidQuerier::new(&deps.querier)
+        .asset_type(lp_denom.clone())
+        .is_ok_and(|response| response.asset_type == SuperfluidAssetType::LpShare as i32)
+    {
+        return Err(ContractError::CustomError {
+            val: format!("{} is not a superfluid eligible asset", lp_denom),
+        });
+    }
+
+    assert_validator_is_valid(deps, validator_address.to_string())
+}
+
 pub fn assert_denom_is_bond_denom(denom: String) -> Result<(), ContractError> {
     if denom != *"uosmo" {
         return Err(ContractError::CustomError {
@@ -431,6 +810,56 @@ pub fn assert_route_matches_denoms(
     Ok(())
 }
 
+pub fn assert_route_can_be_estimated(
+    querier: &QuerierWrapper,
+    env: &Env,
+    pair: &Pair,
+) -> Result<(), ContractError> {
+    for denom in pair.denoms() {
+        query_price(querier, env, pair, &Coin::new(ONE.into(), denom.clone())).map_err(|_| {
+            ContractError::CustomError {
+                val: format!(
+                    "failed to estimate a swap of {} via route {:?}",
+                    denom, pair.route
+                ),
+            }
+        })?;
+    }
+    Ok(())
+}
+
+pub fn assert_price_source_is_valid(deps: Deps, pair: &Pair) -> Result<(), ContractError> {
+    if let Some(PriceSource::Oracle { contract_address }) = &pair.price_source {
+        assert_address_is_valid(deps, contract_address, "oracle")?;
+        query_oracle_price(
+            &deps.querier,
+            contract_address,
+            pair.base_denom.clone(),
+            pair.quote_denom.clone(),
+        )
+        .map_err(|_| ContractError::CustomError {
+            val: format!(
+                "oracle {} did not return a price for {} in {}",
+                contract_address, pair.base_denom, pair.quote_denom
+            ),
+        })?;
+    }
+    Ok(())
+}
+
+pub fn assert_circuit_breaker_is_valid(pair: &Pair) -> Result<(), ContractError> {
+    if let Some(circuit_breaker) = &pair.circuit_breaker {
+        if circuit_breaker.max_deviation.is_zero() || circuit_breaker.max_deviation > Decimal::one()
+        {
+            return Err(ContractError::CustomError {
+                val: "circuit breaker max deviation must be greater than 0 and no more than 1"
+                    .to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
 pub fn assert_route_not_empty(route: Vec<u64>) -> Result<(), ContractError> {
     if route.is_empty() {
         return Err(ContractError::CustomError {
@@ -453,32 +882,320 @@ pub fn assert_route_has_no_duplicate_entries(route: Vec<u64>) -> Result<(), Cont
 }
 
 pub fn assert_swap_adjustment_value_is_valid(
+    storage: &dyn Storage,
     strategy: &SwapAdjustmentStrategy,
     value: Decimal,
 ) -> Result<(), ContractError> {
-    if value < strategy.min_adjustment() || value > strategy.max_adjustment() {
+    let model = get_risk_weighted_average_model_for_strategy(storage, strategy)?;
+
+    if let SwapAdjustmentStrategy::RiskWeightedAverage {
+        model_id,
+        base_denom,
+        ..
+    } = strategy
+    {
+        match &model {
+            None => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "no risk weighted average model is registered for {:?}",
+                        base_denom
+                    ),
+                })
+            }
+            Some(model) if !model.has_model_id(*model_id) => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "model id {} is not supported by the {:?} risk weighted average model",
+                        model_id, base_denom
+                    ),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let min_adjustment = strategy.min_adjustment(model.as_ref());
+    let max_adjustment = strategy.max_adjustment(model.as_ref());
+
+    if value < min_adjustment || value > max_adjustment {
         return Err(ContractError::CustomError {
             val: format!(
                 "swap adjustment value for strategy {:?} must be between {} and {}",
-                strategy,
-                strategy.min_adjustment(),
-                strategy.max_adjustment()
+                strategy, min_adjustment, max_adjustment
             ),
         });
     }
     Ok(())
 }
 
+pub fn assert_swap_adjustment_updates_are_valid(
+    storage: &dyn Storage,
+    updates: &[SwapAdjustmentUpdate],
+) -> Result<(), ContractError> {
+    if updates.is_empty() {
+        return Err(ContractError::CustomError {
+            val: "must provide at least one swap adjustment update".to_string(),
+        });
+    }
+    for update in updates {
+        assert_swap_adjustment_value_is_valid(storage, &update.strategy, update.value)?;
+    }
+    Ok(())
+}
+
+pub fn assert_swap_adjustment_aggregation_is_valid(
+    aggregation: &SwapAdjustmentAggregation,
+) -> Result<(), ContractError> {
+    if aggregation.quorum == 0 {
+        return Err(ContractError::CustomError {
+            val: "swap adjustment quorum must be greater than 0".to_string(),
+        });
+    }
+    if aggregation.max_deviation.is_zero() {
+        return Err(ContractError::CustomError {
+            val: "swap adjustment max deviation must be greater than 0".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_swap_adjustment_strategy_params_are_valid(
+    storage: &dyn Storage,
     strategy: &SwapAdjustmentStrategyParams,
 ) -> Result<(), ContractError> {
-    if let SwapAdjustmentStrategyParams::WeightedScale { multiplier, .. } = strategy {
-        if multiplier > &Decimal::percent(1000) {
+    match strategy {
+        SwapAdjustmentStrategyParams::RiskWeightedAverage { base_denom } => {
+            if get_risk_weighted_average_model(storage, base_denom)?.is_none() {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "no risk weighted average model is registered for {:?}",
+                        base_denom
+                    ),
+                });
+            }
+        }
+        SwapAdjustmentStrategyParams::WeightedScale {
+            multiplier,
+            curve,
+            min_swap_amount,
+            max_swap_amount,
+            ..
+        } => assert_weighted_scale_params_are_valid(
+            *multiplier,
+            curve,
+            *min_swap_amount,
+            *max_swap_amount,
+        )?,
+        SwapAdjustmentStrategyParams::MovingAverage {
+            lookback,
+            resolution,
+            sensitivity,
+            min_adjustment,
+            max_adjustment,
+        } => assert_moving_average_params_are_valid(
+            *lookback,
+            *resolution,
+            *sensitivity,
+            *min_adjustment,
+            *max_adjustment,
+        )?,
+        SwapAdjustmentStrategyParams::ValueAveraging {
+            target_increment,
+            max_swap_multiple,
+            allow_sells,
+        } => assert_value_averaging_params_are_valid(
+            *target_increment,
+            *max_swap_multiple,
+            *allow_sells,
+        )?,
+        SwapAdjustmentStrategyParams::VolatilityScaled {
+            window_seconds,
+            windows,
+            target_volatility,
+            min_adjustment,
+            max_adjustment,
+        } => assert_volatility_scaled_params_are_valid(
+            *window_seconds,
+            *windows,
+            *target_volatility,
+            *min_adjustment,
+            *max_adjustment,
+        )?,
+    }
+    Ok(())
+}
+
+pub fn assert_moving_average_params_are_valid(
+    lookback: u32,
+    resolution: u64,
+    sensitivity: Decimal,
+    min_adjustment: Decimal,
+    max_adjustment: Decimal,
+) -> Result<(), ContractError> {
+    if !(2..=PRICE_HISTORY_CAPACITY).contains(&lookback) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "moving average lookback must be between 2 and {}",
+                PRICE_HISTORY_CAPACITY
+            ),
+        });
+    }
+    if !(1..=MAX_MOVING_AVERAGE_RESOLUTION_SECONDS).contains(&resolution) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "moving average resolution must be between 1 and {} seconds",
+                MAX_MOVING_AVERAGE_RESOLUTION_SECONDS
+            ),
+        });
+    }
+    if sensitivity.is_zero() || sensitivity > Decimal::percent(1000) {
+        return Err(ContractError::CustomError {
+            val: "moving average sensitivity must be greater than 0 and no more than 10"
+                .to_string(),
+        });
+    }
+    if min_adjustment > Decimal::one() || max_adjustment < Decimal::one() {
+        return Err(ContractError::CustomError {
+            val: "moving average adjustment bounds must include 1".to_string(),
+        });
+    }
+    if max_adjustment > Decimal::percent(1000) {
+        return Err(ContractError::CustomError {
+            val: "moving average max adjustment must be no more than 10".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_value_averaging_params_are_valid(
+    target_increment: Uint128,
+    max_swap_multiple: Decimal,
+    allow_sells: bool,
+) -> Result<(), ContractError> {
+    if target_increment.is_zero() {
+        return Err(ContractError::CustomError {
+            val: "value averaging target increment must be greater than 0".to_string(),
+        });
+    }
+    if max_swap_multiple < Decimal::one() || max_swap_multiple > Decimal::percent(1000) {
+        return Err(ContractError::CustomError {
+            val: "value averaging max swap multiple must be between 1 and 10".to_string(),
+        });
+    }
+    if allow_sells {
+        return Err(ContractError::CustomError {
+            val: "value averaging sells are not supported as received funds are sent to vault destinations"
+                .to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_volatility_scaled_params_are_valid(
+    window_seconds: u64,
+    windows: u8,
+    target_volatility: Decimal,
+    min_adjustment: Decimal,
+    max_adjustment: Decimal,
+) -> Result<(), ContractError> {
+    if !(2..=MAX_VOLATILITY_WINDOWS).contains(&windows) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "volatility scaled windows must be between 2 and {}",
+                MAX_VOLATILITY_WINDOWS
+            ),
+        });
+    }
+    if window_seconds < 60
+        || window_seconds.saturating_mul(windows as u64) > TWAP_HISTORY_RETENTION_SECONDS
+    {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "volatility scaled windows must each span at least 60 seconds and together no more than {} seconds",
+                TWAP_HISTORY_RETENTION_SECONDS
+            ),
+        });
+    }
+    if target_volatility.is_zero() {
+        return Err(ContractError::CustomError {
+            val: "volatility scaled target volatility must be greater than 0".to_string(),
+        });
+    }
+    if min_adjustment > Decimal::one() || max_adjustment < Decimal::one() {
+        return Err(ContractError::CustomError {
+            val: "volatility scaled adjustment bounds must include 1".to_string(),
+        });
+    }
+    if max_adjustment > Decimal::percent(1000) {
+        return Err(ContractError::CustomError {
+            val: "volatility scaled max adjustment must be no more than 10".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_risk_weighted_average_model_is_valid(
+    model: &RiskWeightedAverageModel,
+) -> Result<(), ContractError> {
+    if let BaseDenom::Other(symbol) = &model.base_denom {
+        if symbol.is_empty() || symbol.contains(':') {
             return Err(ContractError::CustomError {
-                val: "Cannot set weighted scale multiplier to more than 10".to_string(),
+                val:
+                    "risk weighted average model base denom symbol must not be empty or contain ':'"
+                        .to_string(),
             });
         }
     }
+    if model
+        .duration_buckets
+        .windows(2)
+        .any(|buckets| buckets[0].max_days >= buckets[1].max_days)
+    {
+        return Err(ContractError::CustomError {
+            val: "risk weighted average model duration buckets must be in ascending order of max_days"
+                .to_string(),
+        });
+    }
+    if model.min_adjustment > Decimal::one() || model.max_adjustment < Decimal::one() {
+        return Err(ContractError::CustomError {
+            val: "risk weighted average model adjustment bounds must include 1".to_string(),
+        });
+    }
+    if model.ttl == 0 {
+        return Err(ContractError::CustomError {
+            val: "risk weighted average model ttl must be greater than 0".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_price_history_range_is_valid(
+    from: Option<Timestamp>,
+    to: Option<Timestamp>,
+    resolution: u64,
+) -> Result<(), ContractError> {
+    if resolution == 0 {
+        return Err(ContractError::CustomError {
+            val: "price history resolution must be greater than 0".to_string(),
+        });
+    }
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(ContractError::CustomError {
+                val: "price history from must not be after to".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_migration_limit_is_valid(limit: u16) -> Result<(), ContractError> {
+    if limit == 0 {
+        return Err(ContractError::CustomError {
+            val: "migration limit must be greater than 0".to_string(),
+        });
+    }
     Ok(())
 }
 
@@ -491,6 +1208,17 @@ pub fn assert_fee_level_is_valid(swap_fee_percent: &Decimal) -> Result<(), Contr
     Ok(())
 }
 
+pub fn assert_performance_fee_percent_is_valid(
+    performance_fee_percent: Decimal,
+) -> Result<(), ContractError> {
+    if performance_fee_percent > Decimal::percent(50) {
+        return Err(ContractError::CustomError {
+            val: "performance fee cannot be larger than 50%".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_denom_exists(storage: &dyn Storage, denom: String) -> Result<(), ContractError> {
     let pairs = get_pairs(storage, None, None);
     if !pairs.iter().any(|p| p.denoms().contains(&denom)) {
@@ -511,3 +1239,75 @@ pub fn assert_weighted_scale_multiplier_is_no_more_than_10(
     }
     Ok(())
 }
+
+pub fn assert_weighted_scale_params_are_valid(
+    multiplier: Decimal,
+    curve: &Option<WeightedScaleCurve>,
+    min_swap_amount: Option<Uint128>,
+    max_swap_amount: Option<Uint128>,
+) -> Result<(), ContractError> {
+    assert_weighted_scale_multiplier_is_no_more_than_10(multiplier)?;
+
+    match curve {
+        Some(WeightedScaleCurve::Stepped { tiers }) => {
+            if tiers.is_empty() || tiers.len() > MAX_WEIGHTED_SCALE_TIERS {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "weighted scale stepped curve must have between 1 and {} tiers",
+                        MAX_WEIGHTED_SCALE_TIERS
+                    ),
+                });
+            }
+            if tiers
+                .windows(2)
+                .any(|tiers| tiers[0].price >= tiers[1].price)
+            {
+                return Err(ContractError::CustomError {
+                    val: "weighted scale tier prices must be strictly increasing".to_string(),
+                });
+            }
+            if tiers
+                .iter()
+                .any(|tier| tier.adjustment > Decimal::percent(1000))
+            {
+                return Err(ContractError::CustomError {
+                    val: "weighted scale tier adjustments must be no more than 10".to_string(),
+                });
+            }
+        }
+        Some(WeightedScaleCurve::Clamp {
+            min_adjustment,
+            max_adjustment,
+        }) => {
+            if min_adjustment > max_adjustment {
+                return Err(ContractError::CustomError {
+                    val: "weighted scale min adjustment must be no more than max adjustment"
+                        .to_string(),
+                });
+            }
+            if max_adjustment > &Decimal::percent(1000) {
+                return Err(ContractError::CustomError {
+                    val: "weighted scale max adjustment must be no more than 10".to_string(),
+                });
+            }
+        }
+        _ => {}
+    }
+
+    if max_swap_amount == Some(Uint128::zero()) {
+        return Err(ContractError::CustomError {
+            val: "weighted scale max swap amount must be greater than 0".to_string(),
+        });
+    }
+
+    if let (Some(min_swap_amount), Some(max_swap_amount)) = (min_swap_amount, max_swap_amount) {
+        if min_swap_amount > max_swap_amount {
+            return Err(ContractError::CustomError {
+                val: "weighted scale min swap amount must be no more than max swap amount"
+                    .to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,19 +1,25 @@
 use super::{
     coin::add_to,
     fees::{get_automation_fee_rate, get_swap_fee_rate},
-    price::{calculate_slippage, query_belief_price, query_price},
-    time::get_total_execution_duration,
+    math::{checked_decimal_pow, checked_mul, realized_volatility},
+    price::{
+        calculate_slippage, get_base_denom_price, get_price_candles, query_belief_price,
+        query_market_and_belief_prices, query_price, query_twap_prices,
+    },
+    time::{get_elapsed_intervals, get_total_execution_duration},
 };
 use crate::{
     state::{
-        events::create_event, pairs::find_pair, swap_adjustments::get_swap_adjustment,
-        vaults::update_vault,
+        events::create_event, pairs::find_pair, price_history::get_price_samples_between,
+        swap_adjustments::get_swap_adjustment, vaults::update_vault,
     },
     types::{
         event::{EventBuilder, EventData, ExecutionSkippedReason},
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         position_type::PositionType,
-        swap_adjustment_strategy::SwapAdjustmentStrategy,
+        price_history::PriceCandle,
+        risk_weighted_average_model::RiskWeightedAverageModel,
+        swap_adjustment_strategy::{SwapAdjustmentStrategy, WeightedScaleCurve},
         time_interval::TimeInterval,
         vault::Vault,
     },
@@ -35,13 +41,15 @@ pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin>
             base_receive_amount,
             multiplier,
             increase_only,
+            curve,
+            ..
         }) => {
             let pair = find_pair(deps.storage, vault.denoms())?;
             let belief_price = query_belief_price(deps, env, &pair, vault.get_swap_denom())?;
             let base_price = Decimal::from_ratio(vault.swap_amount, base_receive_amount);
             let scaled_price_delta = base_price.abs_diff(belief_price) / base_price * multiplier;
 
-            if belief_price > base_price {
+            let linear_adjustment = if belief_price > base_price {
                 if increase_only {
                     Decimal::one()
                 } else {
@@ -53,13 +61,124 @@ pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin>
                 Decimal::one()
                     .checked_add(scaled_price_delta)
                     .unwrap_or_else(|_| Decimal::one())
+            };
+
+            let adjustment = match curve {
+                None => linear_adjustment,
+                Some(WeightedScaleCurve::Exponential) => base_price
+                    .checked_div(belief_price)
+                    .ok()
+                    .and_then(|price_ratio| checked_decimal_pow(price_ratio, multiplier).ok())
+                    .unwrap_or(Decimal::MAX),
+                Some(WeightedScaleCurve::Stepped { tiers }) => tiers
+                    .iter()
+                    .rev()
+                    .find(|tier| tier.price <= belief_price)
+                    .map_or(Decimal::one(), |tier| tier.adjustment),
+                Some(WeightedScaleCurve::Clamp {
+                    min_adjustment,
+                    max_adjustment,
+                }) => linear_adjustment.clamp(min_adjustment, max_adjustment),
+            };
+
+            if increase_only {
+                adjustment.max(Decimal::one())
+            } else {
+                adjustment
+            }
+        }
+        Some(SwapAdjustmentStrategy::MovingAverage {
+            lookback,
+            resolution,
+            sensitivity,
+            min_adjustment,
+            max_adjustment,
+        }) => {
+            let pair = find_pair(deps.storage, vault.denoms())?;
+            let (market_price, _) =
+                query_market_and_belief_prices(deps, env, &pair, vault.get_swap_denom())?;
+            let current_open_time = env
+                .block
+                .time
+                .minus_seconds(env.block.time.seconds() % resolution);
+            let samples = get_price_samples_between(
+                deps.storage,
+                &pair,
+                current_open_time.minus_seconds(lookback as u64 * resolution),
+                current_open_time,
+            )?;
+            let candles = get_price_candles(&samples, resolution);
+
+            match get_base_denom_price(&pair, vault.get_swap_denom(), market_price) {
+                Some(current_price) if candles.len() == lookback as usize => {
+                    get_moving_average_swap_adjustment(
+                        current_price,
+                        &candles,
+                        pair.position_type(vault.get_swap_denom()),
+                        sensitivity,
+                    )
+                    .clamp(min_adjustment, max_adjustment)
+                }
+                _ => Decimal::one(),
+            }
+        }
+        Some(SwapAdjustmentStrategy::ValueAveraging {
+            target_increment,
+            max_swap_multiple,
+            ..
+        }) => {
+            let pair = find_pair(deps.storage, vault.denoms())?;
+            let belief_price = query_belief_price(deps, env, &pair, vault.get_swap_denom())?;
+            let (current_value, target_value) =
+                get_value_averaging_values(vault, env.block.time, belief_price, target_increment);
+
+            if current_value >= target_value {
+                Decimal::zero()
+            } else {
+                min(
+                    Decimal::from_ratio(target_value - current_value, vault.swap_amount),
+                    max_swap_multiple,
+                )
             }
         }
+        Some(SwapAdjustmentStrategy::VolatilityScaled {
+            window_seconds,
+            windows,
+            target_volatility,
+            min_adjustment,
+            max_adjustment,
+        }) => {
+            let pair = find_pair(deps.storage, vault.denoms())?;
+            let prices = query_twap_prices(
+                deps,
+                env,
+                &pair,
+                vault.get_swap_denom(),
+                window_seconds,
+                windows,
+            )?;
+
+            realized_volatility(&prices).map_or(Decimal::one(), |volatility| {
+                get_volatility_scaled_swap_adjustment(volatility, target_volatility)
+                    .clamp(min_adjustment, max_adjustment)
+            })
+        }
         Some(strategy) => get_swap_adjustment(deps.storage, strategy, env.block.time),
         None => Decimal::one(),
     };
 
-    let adjusted_amount = vault.swap_amount * swap_adjustment;
+    let adjusted_amount = checked_mul(vault.swap_amount, swap_adjustment).unwrap_or(Uint128::MAX);
+
+    let adjusted_amount = match vault.swap_adjustment_strategy {
+        Some(SwapAdjustmentStrategy::WeightedScale {
+            min_swap_amount,
+            max_swap_amount,
+            ..
+        }) if !swap_adjustment.is_zero() => adjusted_amount
+            .max(min_swap_amount.unwrap_or_default())
+            .min(max_swap_amount.unwrap_or(Uint128::MAX)),
+        _ => adjusted_amount,
+    };
 
     Ok(Coin::new(
         min(adjusted_amount, vault.balance.amount).into(),
@@ -67,7 +186,72 @@ pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin>
     ))
 }
 
+/// Compares the current price to the average close of the given candles, which must not include
+/// the candle the current price falls in.
+pub fn get_moving_average_swap_adjustment(
+    current_price: Decimal,
+    candles: &[PriceCandle],
+    position_type: PositionType,
+    sensitivity: Decimal,
+) -> Decimal {
+    let moving_average = candles
+        .iter()
+        .fold(Decimal::zero(), |sum, candle| sum + candle.close)
+        / Decimal::from_ratio(candles.len().max(1) as u128, 1u128);
+
+    if moving_average.is_zero() {
+        return Decimal::one();
+    }
+
+    let scaled_price_delta = current_price.abs_diff(moving_average) / moving_average * sensitivity;
+
+    let is_favourable = match position_type {
+        PositionType::Enter => current_price < moving_average,
+        PositionType::Exit => current_price > moving_average,
+    };
+
+    if is_favourable {
+        Decimal::one()
+            .checked_add(scaled_price_delta)
+            .unwrap_or(Decimal::MAX)
+    } else {
+        Decimal::one()
+            .checked_sub(scaled_price_delta)
+            .unwrap_or_else(|_| Decimal::zero())
+    }
+}
+
+pub fn get_volatility_scaled_swap_adjustment(
+    volatility: Decimal,
+    target_volatility: Decimal,
+) -> Decimal {
+    target_volatility
+        .checked_div(volatility)
+        .unwrap_or(Decimal::MAX)
+}
+
+/// Returns the current value of everything the vault has received and the value it should
+/// have reached by this execution, both denominated in the swap denom.
+pub fn get_value_averaging_values(
+    vault: &Vault,
+    block_time: Timestamp,
+    belief_price: Decimal,
+    target_increment: Uint128,
+) -> (Uint128, Uint128) {
+    let executions = get_elapsed_intervals(
+        vault.started_at.unwrap_or(block_time),
+        block_time,
+        &vault.time_interval,
+    ) + 1;
+
+    (
+        vault.received_amount.amount * belief_price,
+        target_increment * Uint128::from(executions),
+    )
+}
+
 pub fn get_risk_weighted_average_model_id(
+    model: &RiskWeightedAverageModel,
     block_time: &Timestamp,
     balance: &Coin,
     swap_amount: &Uint128,
@@ -83,42 +267,54 @@ pub fn get_risk_weighted_average_model_id(
         time_interval,
     );
 
-    match execution_duration.num_days() {
-        0..=32 => 30,
-        33..=38 => 35,
-        39..=44 => 40,
-        45..=51 => 45,
-        52..=57 => 50,
-        58..=65 => 55,
-        66..=77 => 60,
-        78..=96 => 70,
-        97..=123 => 80,
-        _ => 90,
-    }
+    model.model_id(execution_duration.num_days())
 }
 
-pub fn get_performance_factor(vault: &Vault, current_price: Decimal) -> StdResult<Decimal> {
-    match &vault.performance_assessment_strategy {
+/// Returns the total value of the vault and of its performance benchmark, both denominated
+/// in the swap denom.
+pub fn get_performance_values(
+    vault: &Vault,
+    current_price: Decimal,
+) -> StdResult<(Uint128, Uint128)> {
+    let vault_total_value = vault.deposited_amount.amount - vault.swapped_amount.amount
+        + vault.received_amount.amount * current_price;
+
+    let benchmark_total_value = match &vault.performance_assessment_strategy {
         Some(PerformanceAssessmentStrategy::CompareToStandardDca {
             swapped_amount,
             received_amount,
+            ..
         }) => {
-            let vault_total_value = vault.deposited_amount.amount - vault.swapped_amount.amount
-                + vault.received_amount.amount * current_price;
-
-            let standard_dca_vault_total_value = vault.deposited_amount.amount
-                - swapped_amount.amount
-                + received_amount.amount * current_price;
-
-            Ok(Decimal::from_ratio(
-                vault_total_value,
-                standard_dca_vault_total_value,
+            vault.deposited_amount.amount - swapped_amount.amount
+                + received_amount.amount * current_price
+        }
+        Some(PerformanceAssessmentStrategy::CompareToLumpSum { start_price, .. }) => start_price
+            .map_or(vault.deposited_amount.amount, |start_price| {
+                vault.deposited_amount.amount
+                    * current_price
+                        .checked_div(start_price)
+                        .unwrap_or(Decimal::one())
+            }),
+        Some(PerformanceAssessmentStrategy::CompareToHoldingSwapDenom { .. }) => {
+            vault.deposited_amount.amount
+        }
+        None => {
+            return Err(StdError::generic_err(
+                "performance assessment strategy not set",
             ))
         }
-        None => Err(StdError::generic_err(
-            "performance assessment strategy not set",
-        )),
-    }
+    };
+
+    Ok((vault_total_value, benchmark_total_value))
+}
+
+pub fn get_performance_factor(vault: &Vault, current_price: Decimal) -> StdResult<Decimal> {
+    let (vault_total_value, benchmark_total_value) = get_performance_values(vault, current_price)?;
+
+    Ok(Decimal::from_ratio(
+        vault_total_value,
+        benchmark_total_value,
+    ))
 }
 
 pub fn simulate_standard_dca_execution(
@@ -130,10 +326,31 @@ pub fn simulate_standard_dca_execution(
     belief_price: Decimal,
 ) -> StdResult<(Vault, Response)> {
     match vault.performance_assessment_strategy.clone() {
-        None => Ok((vault, response)),
+        Some(PerformanceAssessmentStrategy::CompareToLumpSum {
+            start_price: None,
+            performance_fee,
+        }) => {
+            let vault = Vault {
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToLumpSum {
+                        start_price: Some(belief_price),
+                        performance_fee,
+                    },
+                ),
+                ..vault
+            };
+
+            update_vault(storage, vault.clone())?;
+
+            Ok((
+                vault,
+                response.add_attribute("lump_sum_start_price", belief_price.to_string()),
+            ))
+        }
         Some(PerformanceAssessmentStrategy::CompareToStandardDca {
             swapped_amount,
             received_amount,
+            performance_fee,
         }) => {
             let standard_dca_balance = if swapped_amount.amount >= vault.deposited_amount.amount {
                 Uint128::zero()
@@ -212,6 +429,7 @@ pub fn simulate_standard_dca_execution(
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: add_to(swapped_amount, swap_amount),
                         received_amount: add_to(received_amount, received_amount_after_fee),
+                        performance_fee,
                     },
                 ),
                 ..vault
@@ -246,6 +464,7 @@ pub fn simulate_standard_dca_execution(
 
             Ok((vault, response))
         }
+        _ => Ok((vault, response)),
     }
 }
 
@@ -253,20 +472,28 @@ pub fn simulate_standard_dca_execution(
 mod get_swap_amount_tests {
     use super::*;
     use crate::{
-        constants::{ONE, SWAP_FEE_RATE, TWO_MICRONS},
-        state::swap_adjustments::update_swap_adjustment,
+        constants::{ONE, SWAP_FEE_RATE, TEN, TWO_MICRONS},
+        state::{
+            pairs::save_pair, price_history::record_price, swap_adjustments::update_swap_adjustment,
+        },
         tests::{
             helpers::{instantiate_contract, setup_vault},
-            mocks::{calc_mock_dependencies, ADMIN, DENOM_UOSMO},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_STAKE, DENOM_UOSMO},
+        },
+        types::{
+            pair::Pair,
+            swap_adjustment_strategy::{SwapAdjustmentStrategy, WeightedScaleTier},
         },
-        types::swap_adjustment_strategy::SwapAdjustmentStrategy,
     };
     use cosmwasm_std::{
         coin,
         testing::{mock_dependencies, mock_env, mock_info},
         to_binary, StdError,
     };
-    use osmosis_std::types::osmosis::twap::v1beta1::ArithmeticTwapResponse;
+    use osmosis_std::types::osmosis::twap::v1beta1::{
+        ArithmeticTwapRequest, ArithmeticTwapResponse,
+    };
+    use prost::Message;
     use std::str::FromStr;
 
     #[test]
@@ -342,261 +569,1109 @@ mod get_swap_amount_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                balance: coin((ONE / TWO_MICRONS).into(), DENOM_UOSMO),
-                swap_amount: ONE,
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                balance: coin((ONE / TWO_MICRONS).into(), DENOM_UOSMO),
+                swap_amount: ONE,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        let swap_adjustment = Decimal::percent(17);
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            vault.swap_adjustment_strategy.clone().unwrap(),
+            swap_adjustment,
+            env.block.time,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_amount(&deps.as_ref(), &env, &vault)
+                .unwrap()
+                .amount,
+            vault.swap_amount * swap_adjustment
+        );
+    }
+
+    #[test]
+    fn rwa_should_return_vault_balance_with_low_funds_and_increased_swap_amount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: coin((ONE / TWO_MICRONS).into(), DENOM_UOSMO),
+                swap_amount: ONE,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        let swap_adjustment = Decimal::percent(120);
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            vault.swap_adjustment_strategy.clone().unwrap(),
+            swap_adjustment,
+            env.block.time,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_amount(&deps.as_ref(), &env, &vault)
+                .unwrap()
+                .amount,
+            vault.balance.amount
+        );
+    }
+
+    #[test]
+    fn raw_should_return_vault_balance_with_increased_swap_amount_above_balance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: coin((ONE * Decimal::percent(150)).into(), DENOM_UOSMO),
+                swap_amount: ONE,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        let swap_adjustment = Decimal::percent(200);
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            vault.swap_adjustment_strategy.clone().unwrap(),
+            swap_adjustment,
+            env.block.time,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_amount(&deps.as_ref(), &env, &vault)
+                .unwrap()
+                .amount,
+            vault.balance.amount
+        );
+    }
+
+    #[test]
+    fn ws_should_return_decreased_swap_amount_when_price_increased() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let multiplier = Decimal::percent(300);
+        let base_receive_amount = ONE;
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount,
+                    multiplier,
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let base_price = Decimal::from_ratio(vault.swap_amount, base_receive_amount);
+        let current_price =
+            Decimal::percent(120) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap());
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "1.2".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount
+                * (Decimal::one() - (current_price.abs_diff(base_price) / base_price) * multiplier)
+        );
+    }
+
+    #[test]
+    fn ws_should_not_return_decreased_swap_amount_when_price_increased_but_increase_only_is_true() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let multiplier = Decimal::percent(300);
+        let base_receive_amount = ONE;
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount,
+                    multiplier,
+                    increase_only: true,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "1.2".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount);
+    }
+
+    #[test]
+    fn ws_should_return_increased_swap_amount_when_price_decreased() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let base_receive_amount = ONE;
+        let multiplier = Decimal::percent(150);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount,
+                    multiplier,
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let base_price = Decimal::from_ratio(vault.swap_amount, base_receive_amount);
+        let current_price =
+            Decimal::percent(70) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap());
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "0.7".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount
+                * (Decimal::one() + (current_price.abs_diff(base_price) / base_price) * multiplier)
+        );
+    }
+
+    #[test]
+    fn ws_should_return_swap_amount_zero_when_price_increased_enough() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let base_receive_amount = ONE;
+        let multiplier = Decimal::percent(300);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount,
+                    multiplier,
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "2.0".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, Uint128::zero());
+    }
+
+    #[test]
+    fn ma_should_return_swap_amount_without_enough_price_history() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback: 3,
+                    resolution: 3600,
+                    sensitivity: Decimal::percent(50),
+                    min_adjustment: Decimal::percent(50),
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        for i in (1..=2).rev() {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                Decimal::percent(50),
+                env.block.time.minus_seconds(i * 3600),
+            )
+            .unwrap();
+        }
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount);
+    }
+
+    #[test]
+    fn ma_should_return_increased_swap_amount_when_selling_above_moving_average() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let sensitivity = Decimal::percent(50);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback: 3,
+                    resolution: 3600,
+                    sensitivity,
+                    min_adjustment: Decimal::percent(50),
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        save_pair(deps.as_mut().storage, &Pair::default()).unwrap();
+
+        let current_price = Decimal::one();
+        let moving_average = current_price * Decimal::percent(50);
+
+        for i in (1..=3).rev() {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                moving_average,
+                env.block.time.minus_seconds(i * 3600),
+            )
+            .unwrap();
+        }
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert!(swap_amount.amount > vault.swap_amount);
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount
+                * (Decimal::one()
+                    + (current_price.abs_diff(moving_average) / moving_average) * sensitivity)
+        );
+    }
+
+    #[test]
+    fn ma_should_ignore_prices_in_current_candle() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let sensitivity = Decimal::percent(50);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback: 3,
+                    resolution: 3600,
+                    sensitivity,
+                    min_adjustment: Decimal::percent(50),
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        save_pair(deps.as_mut().storage, &Pair::default()).unwrap();
+
+        let current_price = Decimal::one();
+        let moving_average = current_price * Decimal::percent(50);
+
+        for i in (1..=3).rev() {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                moving_average,
+                env.block.time.minus_seconds(i * 3600),
+            )
+            .unwrap();
+        }
+
+        record_price(
+            deps.as_mut().storage,
+            &Pair::default(),
+            current_price,
+            env.block.time,
+        )
+        .unwrap();
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount
+                * (Decimal::one()
+                    + (current_price.abs_diff(moving_average) / moving_average) * sensitivity)
+        );
+    }
+
+    #[test]
+    fn ma_should_average_candle_closes() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let sensitivity = Decimal::percent(50);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback: 2,
+                    resolution: 3600,
+                    sensitivity,
+                    min_adjustment: Decimal::percent(50),
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        save_pair(deps.as_mut().storage, &Pair::default()).unwrap();
+
+        let current_open_time = env
+            .block
+            .time
+            .minus_seconds(env.block.time.seconds() % 3600);
+
+        for (price, timestamp) in [
+            (Decimal::percent(300), current_open_time.minus_seconds(7200)),
+            (Decimal::percent(50), current_open_time.minus_seconds(3600)),
+            (Decimal::percent(300), current_open_time.minus_seconds(1800)),
+            (Decimal::percent(50), current_open_time.minus_seconds(1)),
+        ] {
+            record_price(deps.as_mut().storage, &Pair::default(), price, timestamp).unwrap();
+        }
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        let moving_average = Decimal::percent(175);
+
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount
+                * (Decimal::one()
+                    - (Decimal::one().abs_diff(moving_average) / moving_average) * sensitivity)
+        );
+    }
+
+    #[test]
+    fn ma_should_clamp_swap_adjustment_to_bounds() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let min_adjustment = Decimal::percent(80);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    lookback: 3,
+                    resolution: 3600,
+                    sensitivity: Decimal::percent(1000),
+                    min_adjustment,
+                    max_adjustment: Decimal::percent(200),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        save_pair(deps.as_mut().storage, &Pair::default()).unwrap();
+
+        for i in (1..=3).rev() {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                Decimal::percent(500),
+                env.block.time.minus_seconds(i * 3600),
+            )
+            .unwrap();
+        }
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount * min_adjustment);
+    }
+
+    #[test]
+    fn ws_stepped_should_use_highest_tier_at_or_below_current_price() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::one(),
+                    increase_only: false,
+                    curve: Some(WeightedScaleCurve::Stepped {
+                        tiers: vec![
+                            WeightedScaleTier {
+                                price: Decimal::zero(),
+                                adjustment: Decimal::percent(200),
+                            },
+                            WeightedScaleTier {
+                                price: Decimal::one(),
+                                adjustment: Decimal::one(),
+                            },
+                            WeightedScaleTier {
+                                price: Decimal::percent(120),
+                                adjustment: Decimal::percent(50),
+                            },
+                        ],
+                    }),
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "1.2".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount * Decimal::percent(50));
+    }
+
+    #[test]
+    fn ws_clamp_should_not_decrease_swap_amount_below_min_adjustment() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(300),
+                    increase_only: false,
+                    curve: Some(WeightedScaleCurve::Clamp {
+                        min_adjustment: Decimal::percent(50),
+                        max_adjustment: Decimal::percent(200),
+                    }),
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "1.2".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount * Decimal::percent(50));
+    }
+
+    #[test]
+    fn ws_exponential_should_raise_price_ratio_to_multiplier() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(200),
+                    increase_only: false,
+                    curve: Some(WeightedScaleCurve::Exponential),
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "0.5".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let current_price =
+            Decimal::percent(50) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap());
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount * (Decimal::one() / current_price).checked_pow(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn ws_exponential_with_overflowing_adjustment_saturates_to_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: Uint128::one(),
+                    multiplier: Decimal::percent(1000),
+                    increase_only: false,
+                    curve: Some(WeightedScaleCurve::Exponential),
+                    min_swap_amount: None,
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.balance.amount);
+    }
+
+    #[test]
+    fn ws_exponential_with_overflowing_adjustment_is_bounded_by_max_swap_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: Uint128::one(),
+                    multiplier: Decimal::percent(1000),
+                    increase_only: false,
+                    curve: Some(WeightedScaleCurve::Exponential),
+                    min_swap_amount: None,
+                    max_swap_amount: Some(ONE),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, ONE);
+    }
+
+    #[test]
+    fn ws_should_not_return_more_than_max_swap_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(300),
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: None,
+                    max_swap_amount: Some(ONE * Decimal::percent(150)),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "0.5".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, ONE * Decimal::percent(150));
+    }
+
+    #[test]
+    fn ws_should_not_return_less_than_min_swap_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(90),
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: Some(ONE * Decimal::percent(25)),
+                    max_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "2".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, ONE * Decimal::percent(25));
+    }
+
+    #[test]
+    fn ws_should_not_apply_min_swap_amount_when_adjustment_is_zero() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(300),
+                    increase_only: false,
+                    curve: None,
+                    min_swap_amount: Some(ONE * Decimal::percent(25)),
+                    max_swap_amount: None,
+                }),
                 ..Vault::default()
             },
         );
 
-        let swap_adjustment = Decimal::percent(17);
+        deps.querier.update_stargate(|path, _| match path {
+            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
+                to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: "2".to_string(),
+                })
+            }
+            _ => Err(StdError::generic_err("message not customised")),
+        });
 
-        update_swap_adjustment(
-            deps.as_mut().storage,
-            vault.swap_adjustment_strategy.clone().unwrap(),
-            swap_adjustment,
-            env.block.time,
-        )
-        .unwrap();
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
-        assert_eq!(
-            get_swap_amount(&deps.as_ref(), &env, &vault)
-                .unwrap()
-                .amount,
-            vault.swap_amount * swap_adjustment
-        );
+        assert_eq!(swap_amount.amount, Uint128::zero());
     }
 
     #[test]
-    fn rwa_should_return_vault_balance_with_low_funds_and_increased_swap_amount() {
-        let mut deps = mock_dependencies();
+    fn va_should_return_swap_amount_needed_to_reach_target_value() {
+        let mut deps = calc_mock_dependencies();
         let env = mock_env();
 
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                balance: coin((ONE / TWO_MICRONS).into(), DENOM_UOSMO),
-                swap_amount: ONE,
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                started_at: Some(env.block.time.minus_seconds(2 * 24 * 60 * 60)),
+                received_amount: Coin::new(ONE.into(), DENOM_STAKE),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_increment: ONE,
+                    max_swap_multiple: Decimal::percent(500),
+                    allow_sells: false,
+                }),
                 ..Vault::default()
             },
         );
 
-        let swap_adjustment = Decimal::percent(120);
+        let belief_price = Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap();
 
-        update_swap_adjustment(
-            deps.as_mut().storage,
-            vault.swap_adjustment_strategy.clone().unwrap(),
-            swap_adjustment,
-            env.block.time,
-        )
-        .unwrap();
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
         assert_eq!(
-            get_swap_amount(&deps.as_ref(), &env, &vault)
-                .unwrap()
-                .amount,
-            vault.balance.amount
+            swap_amount.amount,
+            vault.swap_amount
+                * Decimal::from_ratio(
+                    ONE * Uint128::new(3) - ONE * belief_price,
+                    vault.swap_amount
+                )
         );
     }
 
     #[test]
-    fn raw_should_return_vault_balance_with_increased_swap_amount_above_balance() {
-        let mut deps = mock_dependencies();
+    fn va_should_cap_swap_amount_at_max_swap_multiple() {
+        let mut deps = calc_mock_dependencies();
         let env = mock_env();
 
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let max_swap_multiple = Decimal::percent(200);
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                balance: coin((ONE * Decimal::percent(150)).into(), DENOM_UOSMO),
-                swap_amount: ONE,
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                started_at: Some(env.block.time.minus_seconds(9 * 24 * 60 * 60)),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_increment: ONE,
+                    max_swap_multiple,
+                    allow_sells: false,
+                }),
                 ..Vault::default()
             },
         );
 
-        let swap_adjustment = Decimal::percent(200);
-
-        update_swap_adjustment(
-            deps.as_mut().storage,
-            vault.swap_adjustment_strategy.clone().unwrap(),
-            swap_adjustment,
-            env.block.time,
-        )
-        .unwrap();
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
-        assert_eq!(
-            get_swap_amount(&deps.as_ref(), &env, &vault)
-                .unwrap()
-                .amount,
-            vault.balance.amount
-        );
+        assert_eq!(swap_amount.amount, vault.swap_amount * max_swap_multiple);
     }
 
     #[test]
-    fn ws_should_return_decreased_swap_amount_when_price_increased() {
+    fn va_should_return_zero_when_value_is_above_target_value() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
 
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let multiplier = Decimal::percent(300);
-        let base_receive_amount = ONE;
-
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount,
-                    multiplier,
-                    increase_only: false,
+                started_at: Some(env.block.time),
+                received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_increment: ONE,
+                    max_swap_multiple: Decimal::percent(200),
+                    allow_sells: false,
                 }),
                 ..Vault::default()
             },
         );
 
-        let base_price = Decimal::from_ratio(vault.swap_amount, base_receive_amount);
-        let current_price =
-            Decimal::percent(120) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap());
-
-        deps.querier.update_stargate(|path, _| match path {
-            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
-                to_binary(&ArithmeticTwapResponse {
-                    arithmetic_twap: "1.2".to_string(),
-                })
-            }
-            _ => Err(StdError::generic_err("message not customised")),
-        });
-
         let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
-        assert_eq!(
-            swap_amount.amount,
-            vault.swap_amount
-                * (Decimal::one() - (current_price.abs_diff(base_price) / base_price) * multiplier)
-        );
+        assert!(swap_amount.amount.is_zero());
     }
 
     #[test]
-    fn ws_should_not_return_decreased_swap_amount_when_price_increased_but_increase_only_is_true() {
+    fn vs_should_return_max_adjustment_when_prices_are_flat() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
 
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let multiplier = Decimal::percent(300);
-        let base_receive_amount = ONE;
+        let max_adjustment = Decimal::percent(200);
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount,
-                    multiplier,
-                    increase_only: true,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::VolatilityScaled {
+                    window_seconds: 60 * 60,
+                    windows: 6,
+                    target_volatility: Decimal::percent(10),
+                    min_adjustment: Decimal::percent(25),
+                    max_adjustment,
                 }),
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_stargate(|path, _| match path {
-            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
-                to_binary(&ArithmeticTwapResponse {
-                    arithmetic_twap: "1.2".to_string(),
-                })
-            }
-            _ => Err(StdError::generic_err("message not customised")),
-        });
-
         let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
-        assert_eq!(swap_amount.amount, vault.swap_amount);
+        assert_eq!(swap_amount.amount, vault.swap_amount * max_adjustment);
     }
 
     #[test]
-    fn ws_should_return_increased_swap_amount_when_price_decreased() {
+    fn vs_should_decrease_swap_amount_when_volatility_is_above_target() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
 
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let base_receive_amount = ONE;
-        let multiplier = Decimal::percent(150);
+        let block_time = env.block.time.seconds() as i64;
+
+        deps.querier.update_stargate(move |path, data| {
+            if path == "/osmosis.twap.v1beta1.Query/ArithmeticTwap" {
+                let request = ArithmeticTwapRequest::decode(data.as_slice()).unwrap();
+
+                return to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: if request.end_time.unwrap().seconds == block_time {
+                        "1.2"
+                    } else {
+                        "1.0"
+                    }
+                    .to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount,
-                    multiplier,
-                    increase_only: false,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::VolatilityScaled {
+                    window_seconds: 60 * 60,
+                    windows: 2,
+                    target_volatility: Decimal::percent(10),
+                    min_adjustment: Decimal::percent(25),
+                    max_adjustment: Decimal::percent(200),
                 }),
                 ..Vault::default()
             },
         );
 
-        let base_price = Decimal::from_ratio(vault.swap_amount, base_receive_amount);
-        let current_price =
-            Decimal::percent(70) * (Decimal::one() + Decimal::from_str(SWAP_FEE_RATE).unwrap());
-
-        deps.querier.update_stargate(|path, _| match path {
-            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
-                to_binary(&ArithmeticTwapResponse {
-                    arithmetic_twap: "0.7".to_string(),
-                })
-            }
-            _ => Err(StdError::generic_err("message not customised")),
-        });
-
         let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
-        assert_eq!(
-            swap_amount.amount,
-            vault.swap_amount
-                * (Decimal::one() + (current_price.abs_diff(base_price) / base_price) * multiplier)
-        );
+        assert_eq!(swap_amount.amount, vault.swap_amount * Decimal::percent(50));
     }
 
     #[test]
-    fn ws_should_return_swap_amount_zero_when_price_increased_enough() {
+    fn vs_should_not_decrease_swap_amount_below_min_adjustment() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
 
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let base_receive_amount = ONE;
-        let multiplier = Decimal::percent(300);
+        let block_time = env.block.time.seconds() as i64;
+
+        deps.querier.update_stargate(move |path, data| {
+            if path == "/osmosis.twap.v1beta1.Query/ArithmeticTwap" {
+                let request = ArithmeticTwapRequest::decode(data.as_slice()).unwrap();
+
+                return to_binary(&ArithmeticTwapResponse {
+                    arithmetic_twap: if request.end_time.unwrap().seconds == block_time {
+                        "1.2"
+                    } else {
+                        "1.0"
+                    }
+                    .to_string(),
+                });
+            }
+            Err(StdError::generic_err("invoke fallback"))
+        });
+
+        let min_adjustment = Decimal::percent(25);
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount,
-                    multiplier,
-                    increase_only: false,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::VolatilityScaled {
+                    window_seconds: 60 * 60,
+                    windows: 2,
+                    target_volatility: Decimal::percent(1),
+                    min_adjustment,
+                    max_adjustment: Decimal::percent(200),
                 }),
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_stargate(|path, _| match path {
-            "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow" => {
-                to_binary(&ArithmeticTwapResponse {
-                    arithmetic_twap: "2.0".to_string(),
-                })
-            }
-            _ => Err(StdError::generic_err("message not customised")),
-        });
-
         let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
 
-        assert_eq!(swap_amount.amount, Uint128::zero());
+        assert_eq!(swap_amount.amount, vault.swap_amount * min_adjustment);
+    }
+}
+
+#[cfg(test)]
+mod get_moving_average_swap_adjustment_tests {
+    use super::*;
+
+    fn candles(prices: &[u64]) -> Vec<PriceCandle> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(i, price)| PriceCandle {
+                open_time: Timestamp::from_seconds(i as u64 * 3600),
+                open: Decimal::percent(*price),
+                high: Decimal::percent(*price),
+                low: Decimal::percent(*price),
+                close: Decimal::percent(*price),
+                samples: 1,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn returns_one_when_price_equals_moving_average() {
+        assert_eq!(
+            get_moving_average_swap_adjustment(
+                Decimal::one(),
+                &candles(&[90, 100, 110]),
+                PositionType::Enter,
+                Decimal::one()
+            ),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn increases_when_entering_below_moving_average() {
+        assert_eq!(
+            get_moving_average_swap_adjustment(
+                Decimal::percent(80),
+                &candles(&[100, 100]),
+                PositionType::Enter,
+                Decimal::percent(200)
+            ),
+            Decimal::percent(140)
+        );
+    }
+
+    #[test]
+    fn decreases_when_entering_above_moving_average() {
+        assert_eq!(
+            get_moving_average_swap_adjustment(
+                Decimal::percent(120),
+                &candles(&[100, 100]),
+                PositionType::Enter,
+                Decimal::one()
+            ),
+            Decimal::percent(80)
+        );
+    }
+
+    #[test]
+    fn increases_when_exiting_above_moving_average() {
+        assert_eq!(
+            get_moving_average_swap_adjustment(
+                Decimal::percent(120),
+                &candles(&[100, 100]),
+                PositionType::Exit,
+                Decimal::one()
+            ),
+            Decimal::percent(120)
+        );
+    }
+
+    #[test]
+    fn does_not_go_below_zero() {
+        assert_eq!(
+            get_moving_average_swap_adjustment(
+                Decimal::percent(300),
+                &candles(&[100]),
+                PositionType::Enter,
+                Decimal::one()
+            ),
+            Decimal::zero()
+        );
     }
 }
 
@@ -605,7 +1680,9 @@ mod get_risk_weighted_average_strategy_model_id_tests {
     use crate::{
         constants::{ONE, TEN},
         helpers::vault::get_risk_weighted_average_model_id,
-        types::time_interval::TimeInterval,
+        types::{
+            risk_weighted_average_model::RiskWeightedAverageModel, time_interval::TimeInterval,
+        },
     };
     use cosmwasm_std::{testing::mock_env, Coin, Uint128};
 
@@ -618,6 +1695,7 @@ mod get_risk_weighted_average_strategy_model_id_tests {
 
         assert_eq!(
             get_risk_weighted_average_model_id(
+                &RiskWeightedAverageModel::bitcoin(),
                 &env.block.time,
                 &balance,
                 &swap_amount,
@@ -636,6 +1714,7 @@ mod get_risk_weighted_average_strategy_model_id_tests {
 
         assert_eq!(
             get_risk_weighted_average_model_id(
+                &RiskWeightedAverageModel::bitcoin(),
                 &env.block.time,
                 &balance,
                 &swap_amount,
@@ -654,6 +1733,7 @@ mod get_risk_weighted_average_strategy_model_id_tests {
 
         assert_eq!(
             get_risk_weighted_average_model_id(
+                &RiskWeightedAverageModel::bitcoin(),
                 &env.block.time,
                 &balance,
                 &swap_amount,
@@ -709,6 +1789,7 @@ mod get_performance_factor_tests {
                         standard_dca_received_amount.into(),
                         "receive_denom".to_string(),
                     ),
+                    performance_fee: None,
                 },
             ),
             ..Vault::default()
@@ -848,11 +1929,14 @@ mod simulate_standard_dca_execution_tests {
     use crate::tests::helpers::setup_vault;
     use crate::tests::mocks::DENOM_STAKE;
     use crate::types::event::{Event, EventData, ExecutionSkippedReason};
-    use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
+    use crate::types::performance_assessment_strategy::{
+        PerformanceAssessmentStrategy, PerformanceFee,
+    };
     use crate::{
         constants::{ONE, TEN},
         handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
         helpers::fees::{get_automation_fee_rate, get_swap_fee_rate},
+        state::vaults::get_vault,
         tests::{
             helpers::instantiate_contract,
             mocks::{calc_mock_dependencies, ADMIN, DENOM_UOSMO},
@@ -863,7 +1947,7 @@ mod simulate_standard_dca_execution_tests {
         testing::{mock_dependencies, mock_env, mock_info},
         Decimal,
     };
-    use cosmwasm_std::{Coin, Response};
+    use cosmwasm_std::{Attribute, Coin, Response};
 
     #[test]
     fn for_standard_dca_vault_succeeds() {
@@ -904,6 +1988,7 @@ mod simulate_standard_dca_execution_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                     received_amount: Coin::new(TEN.into(), DENOM_STAKE),
+                    performance_fee: None,
                 },
             ),
             ..Vault::default()
@@ -1138,6 +2223,7 @@ mod simulate_standard_dca_execution_tests {
             match performance_assessment_strategy.clone() {
                 PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. } =>
                     swapped_amount,
+                _ => panic!("unexpected performance assessment strategy"),
             },
             Coin::new(vault.swap_amount.into(), vault.get_swap_denom()),
         );
@@ -1146,8 +2232,69 @@ mod simulate_standard_dca_execution_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     received_amount, ..
                 } => received_amount,
+                _ => panic!("unexpected performance assessment strategy"),
             },
             Coin::new(received_amount_after_fee.into(), vault.target_denom)
         );
     }
+
+    #[test]
+    fn records_lump_sum_start_price_on_first_execution() {
+        let deps = calc_mock_dependencies();
+        let mut storage_deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(storage_deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let belief_price = Decimal::percent(150);
+
+        let performance_fee = PerformanceFee {
+            rate: Decimal::percent(20),
+            high_water_mark: None,
+            settled_added_value: None,
+        };
+
+        let vault = setup_vault(
+            storage_deps.as_mut(),
+            env.clone(),
+            Vault {
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToLumpSum {
+                        start_price: None,
+                        performance_fee: performance_fee.clone(),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        let (updated_vault, response) = simulate_standard_dca_execution(
+            Response::new(),
+            &deps.as_ref().querier,
+            storage_deps.as_mut().storage,
+            &env,
+            vault.clone(),
+            belief_price,
+        )
+        .unwrap();
+
+        let expected_strategy = Some(PerformanceAssessmentStrategy::CompareToLumpSum {
+            start_price: Some(belief_price),
+            performance_fee,
+        });
+
+        assert_eq!(
+            updated_vault.performance_assessment_strategy,
+            expected_strategy
+        );
+        assert_eq!(
+            get_vault(storage_deps.as_ref().storage, vault.id)
+                .unwrap()
+                .performance_assessment_strategy,
+            expected_strategy
+        );
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("lump_sum_start_price", "1.5")));
+    }
 }
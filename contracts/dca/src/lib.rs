@@ -7,4 +7,3 @@ pub mod msg;
 pub mod state;
 pub mod tests;
 pub mod types;
-mod util;
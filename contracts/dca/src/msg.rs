@@ -1,18 +1,31 @@
-use crate::types::config::Config;
-use crate::types::destination::Destination;
+use crate::types::circuit_breaker::{CircuitBreaker, CircuitBreakerState};
+use crate::types::config::{Config, PendingAdmin, QueuedConfigChange};
+use crate::types::destination::{Destination, WeightedValidator};
+use crate::types::escrow_release::EscrowRelease;
 use crate::types::event::Event;
 use crate::types::fee_collector::FeeCollector;
+use crate::types::funding_strategy::FundingStrategyParams;
 use crate::types::lockable_duration::LockableDuration;
-use crate::types::pair::Pair;
+use crate::types::migration::MigrationProgress;
+use crate::types::pair::{Pair, PairConfig};
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
 use crate::types::position_type::PositionType;
+use crate::types::price_history::PriceCandle;
+use crate::types::price_source::PriceSource;
+use crate::types::risk_weighted_average_model::RiskWeightedAverageModel;
+use crate::types::role::Role;
+use crate::types::solvency::DenomSolvency;
+use crate::types::swap_adjustment::{
+    PublisherSubmissionStatus, SwapAdjustmentAggregation, SwapAdjustmentHistoryEntry,
+    SwapAdjustmentStatus, SwapAdjustmentUpdate,
+};
 use crate::types::swap_adjustment_strategy::{
-    SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
+    BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
 use crate::types::time_interval::TimeInterval;
 use crate::types::vault::{Vault, VaultStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128, Uint64};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -30,7 +43,9 @@ pub struct InstantiateMsg {
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    pub limit: Option<u16>,
+}
 
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -38,6 +53,8 @@ pub enum ExecuteMsg {
         base_denom: String,
         quote_denom: String,
         route: Vec<u64>,
+        price_source: Option<PriceSource>,
+        circuit_breaker: Option<CircuitBreaker>,
     },
     CreateVault {
         owner: Option<Addr>,
@@ -52,6 +69,7 @@ pub enum ExecuteMsg {
         target_start_time_utc_seconds: Option<Uint64>,
         performance_assessment_strategy: Option<PerformanceAssessmentStrategyParams>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
+        funding_strategy: Option<FundingStrategyParams>,
     },
     Deposit {
         address: Addr,
@@ -83,6 +101,8 @@ pub enum ExecuteMsg {
         risk_weighted_average_escrow_level: Option<Decimal>,
         twap_period: Option<u64>,
         default_slippage_tolerance: Option<Decimal>,
+        config_change_delay_seconds: Option<u64>,
+        performance_fee_percent: Option<Decimal>,
     },
     CreateCustomSwapFee {
         denom: String,
@@ -95,6 +115,9 @@ pub enum ExecuteMsg {
         strategy: SwapAdjustmentStrategy,
         value: Decimal,
     },
+    UpdateSwapAdjustments {
+        adjustments: Vec<SwapAdjustmentUpdate>,
+    },
     DisburseEscrow {
         vault_id: Uint128,
     },
@@ -102,11 +125,17 @@ pub enum ExecuteMsg {
         delegator_address: Addr,
         validator_address: Addr,
     },
+    ZDelegateToValidators {
+        delegator_address: Addr,
+        validators: Vec<WeightedValidator>,
+        max_commission_rate: Option<Decimal>,
+    },
     ZProvideLiquidity {
         provider_address: Addr,
         pool_id: u64,
         duration: LockableDuration,
         slippage_tolerance: Option<Decimal>,
+        superfluid_validator_address: Option<Addr>,
     },
     CreatePairs {
         pairs: Vec<Pair>,
@@ -114,6 +143,62 @@ pub enum ExecuteMsg {
     FixPositionType {
         vault_id: Uint128,
     },
+    ResetCircuitBreaker {
+        base_denom: String,
+        quote_denom: String,
+    },
+    UpdatePairConfig {
+        base_denom: String,
+        quote_denom: String,
+        config: Option<PairConfig>,
+    },
+    UpdatePairRoute {
+        base_denom: String,
+        quote_denom: String,
+        route: Vec<u64>,
+    },
+    DeletePair {
+        base_denom: String,
+        quote_denom: String,
+    },
+    GrantRole {
+        role: Role,
+        address: Addr,
+    },
+    RevokeRole {
+        role: Role,
+        address: Addr,
+    },
+    SetPaused {
+        paused: bool,
+    },
+    ProposeNewAdmin {
+        address: Addr,
+    },
+    AcceptAdmin {},
+    CancelAdminProposal {},
+    ExecuteQueuedConfigChange {
+        id: u64,
+    },
+    CancelQueuedConfigChange {
+        id: u64,
+    },
+    ContinueMigration {
+        limit: Option<u16>,
+    },
+    RecoverSurplus {
+        denom: String,
+        recipient: Addr,
+    },
+    SetRiskWeightedAverageModel {
+        model: RiskWeightedAverageModel,
+    },
+    RemoveRiskWeightedAverageModel {
+        base_denom: BaseDenom,
+    },
+    SetSwapAdjustmentAggregation {
+        aggregation: Option<SwapAdjustmentAggregation>,
+    },
 }
 
 #[cw_serde]
@@ -121,11 +206,39 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     GetConfig {},
+    #[returns(PendingAdminResponse)]
+    GetPendingAdmin {},
+    #[returns(PendingConfigChangesResponse)]
+    GetPendingConfigChanges {},
+    #[returns(MigrationStatusResponse)]
+    GetMigrationStatus {},
+    #[returns(SolvencyReportResponse)]
+    GetSolvencyReport {},
+    #[returns(RiskWeightedAverageModelsResponse)]
+    GetRiskWeightedAverageModels {},
+    #[returns(SwapAdjustmentResponse)]
+    GetSwapAdjustment { strategy: SwapAdjustmentStrategy },
+    #[returns(SwapAdjustmentsResponse)]
+    GetSwapAdjustments {
+        start_after: Option<String>,
+        limit: Option<u16>,
+    },
+    #[returns(SwapAdjustmentHistoryResponse)]
+    GetSwapAdjustmentHistory {
+        strategy: SwapAdjustmentStrategy,
+        start_after: Option<Timestamp>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    },
+    #[returns(SwapAdjustmentPublishersResponse)]
+    GetSwapAdjustmentPublishers { strategy: SwapAdjustmentStrategy },
     #[returns(PairsResponse)]
     GetPairs {
         start_after: Option<Pair>,
         limit: Option<u16>,
     },
+    #[returns(PairsResponse)]
+    GetPairsByDenom { denom: String },
     #[returns(TriggerIdsResponse)]
     GetTimeTriggerIds { limit: Option<u16> },
     #[returns(VaultResponse)]
@@ -161,11 +274,85 @@ pub enum QueryMsg {
     GetVaultPerformance { vault_id: Uint128 },
     #[returns(DisburseEscrowTasksResponse)]
     GetDisburseEscrowTasks { limit: Option<u16> },
+    #[returns(EscrowReleasesResponse)]
+    GetEscrowReleases {
+        vault_id: Option<Uint128>,
+        limit: Option<u16>,
+    },
+    #[returns(RolesResponse)]
+    GetRoles { address: Addr },
+    #[returns(RoleMembersResponse)]
+    GetRoleMembers { role: Role },
+    #[returns(CircuitBreakerResponse)]
+    GetCircuitBreaker {
+        base_denom: String,
+        quote_denom: String,
+    },
+    #[returns(PriceHistoryResponse)]
+    GetPriceHistory {
+        base_denom: String,
+        quote_denom: String,
+        from: Option<Timestamp>,
+        to: Option<Timestamp>,
+        resolution: u64,
+    },
 }
 
 #[cw_serde]
 pub struct ConfigResponse {
     pub config: Config,
+    pub performance_fee_percent: Decimal,
+}
+
+#[cw_serde]
+pub struct PendingAdminResponse {
+    pub pending_admin: Option<PendingAdmin>,
+}
+
+#[cw_serde]
+pub struct MigrationStatusResponse {
+    pub storage_version: u16,
+    pub latest_storage_version: u16,
+    pub progress: Option<MigrationProgress>,
+}
+
+#[cw_serde]
+pub struct RiskWeightedAverageModelsResponse {
+    pub models: Vec<RiskWeightedAverageModel>,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentResponse {
+    pub adjustment: SwapAdjustmentStatus,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentsResponse {
+    pub adjustments: Vec<SwapAdjustmentStatus>,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentHistoryResponse {
+    pub history: Vec<SwapAdjustmentHistoryEntry>,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentPublishersResponse {
+    pub aggregation: Option<SwapAdjustmentAggregation>,
+    pub median: Option<Decimal>,
+    pub publishers: Vec<PublisherSubmissionStatus>,
+}
+
+#[cw_serde]
+pub struct SolvencyReportResponse {
+    pub is_solvent: bool,
+    pub denoms: Vec<DenomSolvency>,
+}
+
+#[cw_serde]
+pub struct PendingConfigChangesResponse {
+    pub delay_seconds: u64,
+    pub changes: Vec<QueuedConfigChange>,
 }
 
 #[cw_serde]
@@ -192,6 +379,8 @@ pub struct VaultResponse {
 pub struct VaultPerformanceResponse {
     pub fee: Coin,
     pub factor: Decimal,
+    pub fee_rate: Decimal,
+    pub high_water_mark: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -213,3 +402,31 @@ pub struct CustomFeesResponse {
 pub struct DisburseEscrowTasksResponse {
     pub vault_ids: Vec<Uint128>,
 }
+
+#[cw_serde]
+pub struct EscrowReleasesResponse {
+    pub releases: Vec<EscrowRelease>,
+}
+
+#[cw_serde]
+pub struct CircuitBreakerResponse {
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub state: Option<CircuitBreakerState>,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub roles: Vec<Role>,
+}
+
+#[cw_serde]
+pub struct RoleMembersResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct PriceHistoryResponse {
+    pub base_denom: String,
+    pub quote_denom: String,
+    pub candles: Vec<PriceCandle>,
+}
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use crate::types::lockable_duration::LockableDuration;
+use crate::types::{destination::Destination, lockable_duration::LockableDuration};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, SubMsg, Uint128};
 use cw_storage_plus::{Item, Map};
@@ -15,10 +15,20 @@ pub struct SwapCache {
 
 pub const SWAP_CACHE: Item<SwapCache> = Item::new("swap_cache_v8");
 
+pub const PULL_FUNDS_CACHE: Item<Coin> = Item::new("pull_funds_cache_v8");
+
+#[cw_serde]
+pub struct StakingRewardsCache {
+    pub owner_balance: Coin,
+}
+
+pub const STAKING_REWARDS_CACHE: Item<StakingRewardsCache> = Item::new("staking_rewards_cache_v8");
+
 #[cw_serde]
 pub struct PostExecutionActionCacheEntry {
     pub msg: SubMsg,
     pub funds: Vec<Coin>,
+    pub destination: Destination,
 }
 
 pub const POST_EXECUTION_ACTION_CACHE: Map<u128, VecDeque<PostExecutionActionCacheEntry>> =
@@ -29,7 +39,18 @@ pub struct ProvideLiquidityCache {
     pub provider_address: Addr,
     pub pool_id: u64,
     pub duration: LockableDuration,
+    pub superfluid_validator_address: Option<Addr>,
+    pub vault_id: Option<Uint128>,
 }
 
 pub const PROVIDE_LIQUIDITY_CACHE: Item<ProvideLiquidityCache> =
     Item::new("provide_liquidity_cache_v8");
+
+#[cw_serde]
+pub struct DelegationCacheEntry {
+    pub source_validator_address: Option<Addr>,
+    pub validator_address: Addr,
+    pub amount: Coin,
+}
+
+pub const DELEGATION_CACHE: Item<VecDeque<DelegationCacheEntry>> = Item::new("delegation_cache_v8");
@@ -0,0 +1,29 @@
+use super::pairs::key_from;
+use crate::types::circuit_breaker::CircuitBreakerState;
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::Map;
+
+const CIRCUIT_BREAKERS: Map<String, CircuitBreakerState> = Map::new("circuit_breakers_v8");
+
+pub fn save_circuit_breaker_state(
+    storage: &mut dyn Storage,
+    denoms: [String; 2],
+    state: &CircuitBreakerState,
+) -> StdResult<()> {
+    CIRCUIT_BREAKERS.save(storage, key_from(denoms), state)
+}
+
+pub fn get_circuit_breaker_state(
+    storage: &dyn Storage,
+    denoms: [String; 2],
+) -> StdResult<Option<CircuitBreakerState>> {
+    CIRCUIT_BREAKERS.may_load(storage, key_from(denoms))
+}
+
+pub fn is_pair_paused(storage: &dyn Storage, denoms: [String; 2]) -> StdResult<bool> {
+    Ok(get_circuit_breaker_state(storage, denoms)?.is_some_and(|state| state.paused))
+}
+
+pub fn remove_circuit_breaker_state(storage: &mut dyn Storage, denoms: [String; 2]) {
+    CIRCUIT_BREAKERS.remove(storage, key_from(denoms))
+}
@@ -1,5 +1,8 @@
-use crate::types::config::Config;
-use cosmwasm_std::{Decimal, Order, StdError, StdResult, Storage};
+use crate::{
+    helpers::state::fetch_and_increment_counter,
+    types::config::{Config, ConfigChange, PendingAdmin, QueuedConfigChange},
+};
+use cosmwasm_std::{Addr, Decimal, Order, StdError, StdResult, Storage, Timestamp};
 use cw_storage_plus::{Item, Map};
 
 const CONFIG: Item<Config> = Item::new("config_v8");
@@ -29,6 +32,90 @@ pub fn clear_config(store: &mut dyn Storage) {
     CONFIG.remove(store);
 }
 
+const CONTRACT_ADDRESS: Item<Addr> = Item::new("contract_address_v8");
+
+pub fn save_contract_address(store: &mut dyn Storage, address: &Addr) -> StdResult<()> {
+    CONTRACT_ADDRESS.save(store, address)
+}
+
+pub fn get_contract_address(store: &dyn Storage) -> StdResult<Option<Addr>> {
+    CONTRACT_ADDRESS.may_load(store)
+}
+
+const PENDING_ADMIN: Item<PendingAdmin> = Item::new("pending_admin_v8");
+
+pub fn save_pending_admin(store: &mut dyn Storage, pending_admin: &PendingAdmin) -> StdResult<()> {
+    PENDING_ADMIN.save(store, pending_admin)
+}
+
+pub fn get_pending_admin(store: &dyn Storage) -> StdResult<Option<PendingAdmin>> {
+    PENDING_ADMIN.may_load(store)
+}
+
+pub fn clear_pending_admin(store: &mut dyn Storage) {
+    PENDING_ADMIN.remove(store);
+}
+
+const CONFIG_CHANGE_DELAY: Item<u64> = Item::new("config_change_delay_v8");
+
+pub fn get_config_change_delay(store: &dyn Storage) -> StdResult<u64> {
+    Ok(CONFIG_CHANGE_DELAY.may_load(store)?.unwrap_or(0))
+}
+
+pub fn save_config_change_delay(store: &mut dyn Storage, delay_seconds: u64) -> StdResult<()> {
+    CONFIG_CHANGE_DELAY.save(store, &delay_seconds)
+}
+
+const PERFORMANCE_FEE_RATE: Item<Decimal> = Item::new("performance_fee_rate_v8");
+
+pub fn get_performance_fee_rate(store: &dyn Storage) -> StdResult<Decimal> {
+    Ok(PERFORMANCE_FEE_RATE
+        .may_load(store)?
+        .unwrap_or(Decimal::percent(20)))
+}
+
+pub fn save_performance_fee_rate(store: &mut dyn Storage, rate: Decimal) -> StdResult<()> {
+    PERFORMANCE_FEE_RATE.save(store, &rate)
+}
+
+const QUEUED_CONFIG_CHANGE_COUNTER: Item<u64> = Item::new("queued_config_change_counter_v8");
+
+const QUEUED_CONFIG_CHANGES: Map<u64, QueuedConfigChange> = Map::new("queued_config_changes_v8");
+
+pub fn queue_config_change(
+    store: &mut dyn Storage,
+    change: ConfigChange,
+    queued_at: Timestamp,
+    executable_at: Timestamp,
+) -> StdResult<QueuedConfigChange> {
+    let queued_change = QueuedConfigChange {
+        id: fetch_and_increment_counter(store, QUEUED_CONFIG_CHANGE_COUNTER)?,
+        change,
+        queued_at,
+        executable_at,
+    };
+    QUEUED_CONFIG_CHANGES.save(store, queued_change.id, &queued_change)?;
+    Ok(queued_change)
+}
+
+pub fn get_queued_config_change(
+    store: &dyn Storage,
+    id: u64,
+) -> StdResult<Option<QueuedConfigChange>> {
+    QUEUED_CONFIG_CHANGES.may_load(store, id)
+}
+
+pub fn get_queued_config_changes(store: &dyn Storage) -> StdResult<Vec<QueuedConfigChange>> {
+    QUEUED_CONFIG_CHANGES
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, change)| change))
+        .collect()
+}
+
+pub fn remove_queued_config_change(store: &mut dyn Storage, id: u64) {
+    QUEUED_CONFIG_CHANGES.remove(store, id);
+}
+
 const CUSTOM_FEES: Map<String, Decimal> = Map::new("fees_v6");
 
 pub fn create_custom_fee(
@@ -27,6 +27,8 @@ fn disburse_escrow_task_store<'a>(
     IndexedMap::new("disburse_escrow_task_v8", indexes)
 }
 
+/// Due dates are stored at second precision, so tasks become due at the start of the second
+/// they are scheduled for.
 pub fn save_disburse_escrow_task(
     store: &mut dyn Storage,
     vault_id: Uint128,
@@ -70,6 +72,23 @@ pub fn get_disburse_escrow_tasks(
         .collect::<Vec<Uint128>>())
 }
 
+pub fn get_scheduled_disburse_escrow_tasks(
+    store: &dyn Storage,
+    limit: Option<u16>,
+) -> StdResult<Vec<(Uint128, Timestamp)>> {
+    Ok(disburse_escrow_task_store()
+        .idx
+        .due_date
+        .range(store, None, None, Order::Ascending)
+        .take(limit.unwrap_or_else(|| get_config(store).unwrap().default_page_limit) as usize)
+        .flat_map(|result| {
+            result.map(|(_, (due_date, vault_id))| {
+                (vault_id.into(), Timestamp::from_seconds(due_date))
+            })
+        })
+        .collect::<Vec<(Uint128, Timestamp)>>())
+}
+
 pub fn delete_disburse_escrow_task(store: &mut dyn Storage, vault_id: Uint128) -> StdResult<()> {
     disburse_escrow_task_store().remove(store, vault_id.into())
 }
@@ -179,4 +198,42 @@ mod tests {
         assert_eq!(vault_ids_before_delete, vec![vault_id_1, vault_id_2]);
         assert_eq!(vault_ids_after_delete, vec![vault_id_2]);
     }
+
+    #[test]
+    fn fetches_scheduled_tasks_in_due_date_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault_id_1 = Uint128::one();
+        let vault_id_2 = Uint128::new(2);
+
+        save_disburse_escrow_task(
+            &mut deps.storage,
+            vault_id_1,
+            env.block.time.plus_seconds(20),
+        )
+        .unwrap();
+        save_disburse_escrow_task(
+            &mut deps.storage,
+            vault_id_2,
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+
+        let tasks = get_scheduled_disburse_escrow_tasks(&deps.storage, Some(100)).unwrap();
+
+        assert_eq!(
+            tasks,
+            vec![
+                (
+                    vault_id_2,
+                    Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds())
+                ),
+                (
+                    vault_id_1,
+                    Timestamp::from_seconds(env.block.time.plus_seconds(20).seconds())
+                )
+            ]
+        );
+    }
 }
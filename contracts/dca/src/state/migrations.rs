@@ -0,0 +1,30 @@
+use crate::types::migration::MigrationProgress;
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::Item;
+
+const STORAGE_VERSION: Item<u16> = Item::new("storage_version_v8");
+
+pub fn get_storage_version(store: &dyn Storage) -> StdResult<u16> {
+    Ok(STORAGE_VERSION.may_load(store)?.unwrap_or(1))
+}
+
+pub fn save_storage_version(store: &mut dyn Storage, version: u16) -> StdResult<()> {
+    STORAGE_VERSION.save(store, &version)
+}
+
+const MIGRATION_PROGRESS: Item<MigrationProgress> = Item::new("migration_progress_v8");
+
+pub fn get_migration_progress(store: &dyn Storage) -> StdResult<Option<MigrationProgress>> {
+    MIGRATION_PROGRESS.may_load(store)
+}
+
+pub fn save_migration_progress(
+    store: &mut dyn Storage,
+    progress: &MigrationProgress,
+) -> StdResult<()> {
+    MIGRATION_PROGRESS.save(store, progress)
+}
+
+pub fn clear_migration_progress(store: &mut dyn Storage) {
+    MIGRATION_PROGRESS.remove(store);
+}
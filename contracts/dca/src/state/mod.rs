@@ -1,8 +1,14 @@
 pub mod cache;
+pub mod circuit_breakers;
 pub mod config;
 pub mod disburse_escrow_tasks;
 pub mod events;
+pub mod migrations;
 pub mod pairs;
+pub mod price_history;
+pub mod risk_weighted_average_models;
+pub mod roles;
 pub mod swap_adjustments;
 pub mod triggers;
+pub mod vault_totals;
 pub mod vaults;
@@ -10,11 +10,15 @@ pub fn save_pair(storage: &mut dyn Storage, pair: &Pair) -> StdResult<()> {
     PAIRS.save(storage, key_from(pair.denoms()), pair)
 }
 
-fn key_from(mut denoms: [String; 2]) -> String {
+pub(crate) fn key_from(mut denoms: [String; 2]) -> String {
     denoms.sort();
     format!("{}-{}", denoms[0], denoms[1])
 }
 
+pub fn delete_pair(storage: &mut dyn Storage, denoms: [String; 2]) {
+    PAIRS.remove(storage, key_from(denoms))
+}
+
 pub fn find_pair(storage: &dyn Storage, denoms: [String; 2]) -> StdResult<Pair> {
     PAIRS.load(storage, key_from(denoms))
 }
@@ -36,6 +40,14 @@ pub fn get_pairs(
         .collect::<Vec<Pair>>()
 }
 
+pub fn get_pairs_by_denom(storage: &dyn Storage, denom: String) -> Vec<Pair> {
+    PAIRS
+        .range(storage, None, None, Order::Ascending)
+        .flat_map(|result| result.map(|(_, pair)| pair))
+        .filter(|pair| pair.denoms().contains(&denom))
+        .collect::<Vec<Pair>>()
+}
+
 #[cfg(test)]
 mod pairs_state_tests {
     use super::*;
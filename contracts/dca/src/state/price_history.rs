@@ -0,0 +1,325 @@
+use super::pairs::key_from;
+use crate::{
+    constants::PRICE_HISTORY_CAPACITY,
+    types::{pair::Pair, price_history::PriceSample},
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+#[derive(Default)]
+struct PriceHistoryCursor {
+    next: u32,
+    len: u32,
+}
+
+const PRICE_HISTORY_CURSORS: Map<String, PriceHistoryCursor> = Map::new("price_history_cursors_v8");
+
+const PRICE_SAMPLES: Map<(String, u32), PriceSample> = Map::new("price_samples_v8");
+
+pub fn record_price(
+    store: &mut dyn Storage,
+    pair: &Pair,
+    price: Decimal,
+    block_time: Timestamp,
+) -> StdResult<()> {
+    let key = key_from(pair.denoms());
+
+    let cursor = PRICE_HISTORY_CURSORS
+        .may_load(store, key.clone())?
+        .unwrap_or_default();
+
+    if cursor.len > 0 {
+        let latest_slot = (cursor.next + PRICE_HISTORY_CAPACITY - 1) % PRICE_HISTORY_CAPACITY;
+        let latest = PRICE_SAMPLES.load(store, (key.clone(), latest_slot))?;
+
+        if latest.timestamp >= block_time {
+            return Ok(());
+        }
+    }
+
+    PRICE_SAMPLES.save(
+        store,
+        (key.clone(), cursor.next),
+        &PriceSample {
+            price,
+            timestamp: block_time,
+        },
+    )?;
+
+    PRICE_HISTORY_CURSORS.save(
+        store,
+        key,
+        &PriceHistoryCursor {
+            next: (cursor.next + 1) % PRICE_HISTORY_CAPACITY,
+            len: (cursor.len + 1).min(PRICE_HISTORY_CAPACITY),
+        },
+    )
+}
+
+pub fn get_latest_price_samples(
+    store: &dyn Storage,
+    pair: &Pair,
+    count: u32,
+) -> StdResult<Vec<PriceSample>> {
+    let key = key_from(pair.denoms());
+
+    let cursor = PRICE_HISTORY_CURSORS
+        .may_load(store, key.clone())?
+        .unwrap_or_default();
+
+    (1..=count.min(cursor.len))
+        .map(|offset| {
+            PRICE_SAMPLES.load(
+                store,
+                (
+                    key.clone(),
+                    (cursor.next + PRICE_HISTORY_CAPACITY - offset) % PRICE_HISTORY_CAPACITY,
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Returns the samples recorded at or after `from` and before `to`, oldest first, only loading
+/// samples newer than `from`.
+pub fn get_price_samples_between(
+    store: &dyn Storage,
+    pair: &Pair,
+    from: Timestamp,
+    to: Timestamp,
+) -> StdResult<Vec<PriceSample>> {
+    let key = key_from(pair.denoms());
+
+    let cursor = PRICE_HISTORY_CURSORS
+        .may_load(store, key.clone())?
+        .unwrap_or_default();
+
+    let mut samples = vec![];
+
+    for offset in 1..=cursor.len {
+        let sample = PRICE_SAMPLES.load(
+            store,
+            (
+                key.clone(),
+                (cursor.next + PRICE_HISTORY_CAPACITY - offset) % PRICE_HISTORY_CAPACITY,
+            ),
+        )?;
+
+        if sample.timestamp < from {
+            break;
+        }
+
+        if sample.timestamp < to {
+            samples.push(sample);
+        }
+    }
+
+    samples.reverse();
+
+    Ok(samples)
+}
+
+pub fn get_price_samples(store: &dyn Storage, pair: &Pair) -> StdResult<Vec<PriceSample>> {
+    let key = key_from(pair.denoms());
+
+    let cursor = PRICE_HISTORY_CURSORS
+        .may_load(store, key.clone())?
+        .unwrap_or_default();
+
+    let oldest_slot = (cursor.next + PRICE_HISTORY_CAPACITY - cursor.len) % PRICE_HISTORY_CAPACITY;
+
+    (0..cursor.len)
+        .map(|offset| {
+            PRICE_SAMPLES.load(
+                store,
+                (key.clone(), (oldest_slot + offset) % PRICE_HISTORY_CAPACITY),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod price_history_tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn gets_no_samples_for_pair_without_history() {
+        let deps = mock_dependencies();
+
+        assert!(
+            get_latest_price_samples(deps.as_ref().storage, &Pair::default(), 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn gets_latest_samples_newest_first() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for i in 1..=3 {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                Decimal::percent(i * 100),
+                env.block.time.plus_seconds(i),
+            )
+            .unwrap();
+        }
+
+        let samples = get_latest_price_samples(deps.as_ref().storage, &Pair::default(), 2).unwrap();
+
+        assert_eq!(
+            samples,
+            vec![
+                PriceSample {
+                    price: Decimal::percent(300),
+                    timestamp: env.block.time.plus_seconds(3),
+                },
+                PriceSample {
+                    price: Decimal::percent(200),
+                    timestamp: env.block.time.plus_seconds(2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn gets_samples_between_oldest_first() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for i in 1..=4 {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                Decimal::percent(i * 100),
+                env.block.time.plus_seconds(i),
+            )
+            .unwrap();
+        }
+
+        let samples = get_price_samples_between(
+            deps.as_ref().storage,
+            &Pair::default(),
+            env.block.time.plus_seconds(2),
+            env.block.time.plus_seconds(4),
+        )
+        .unwrap();
+
+        assert_eq!(
+            samples,
+            vec![
+                PriceSample {
+                    price: Decimal::percent(200),
+                    timestamp: env.block.time.plus_seconds(2),
+                },
+                PriceSample {
+                    price: Decimal::percent(300),
+                    timestamp: env.block.time.plus_seconds(3),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn records_one_sample_per_block() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        record_price(
+            deps.as_mut().storage,
+            &Pair::default(),
+            Decimal::percent(100),
+            env.block.time,
+        )
+        .unwrap();
+
+        record_price(
+            deps.as_mut().storage,
+            &Pair::default(),
+            Decimal::percent(200),
+            env.block.time,
+        )
+        .unwrap();
+
+        let samples =
+            get_latest_price_samples(deps.as_ref().storage, &Pair::default(), 10).unwrap();
+
+        assert_eq!(
+            samples,
+            vec![PriceSample {
+                price: Decimal::percent(100),
+                timestamp: env.block.time,
+            }]
+        );
+    }
+
+    #[test]
+    fn gets_all_samples_oldest_first() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for i in 0..PRICE_HISTORY_CAPACITY + 2 {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                Decimal::from_ratio(i, 1u128),
+                env.block.time.plus_seconds(i.into()),
+            )
+            .unwrap();
+        }
+
+        let samples = get_price_samples(deps.as_ref().storage, &Pair::default()).unwrap();
+
+        assert_eq!(samples.len(), PRICE_HISTORY_CAPACITY as usize);
+        assert_eq!(
+            samples.first().unwrap().price,
+            Decimal::from_ratio(2u128, 1u128)
+        );
+        assert_eq!(
+            samples.last().unwrap().price,
+            Decimal::from_ratio(PRICE_HISTORY_CAPACITY + 1, 1u128)
+        );
+        assert!(samples
+            .windows(2)
+            .all(|samples| samples[0].timestamp < samples[1].timestamp));
+    }
+
+    #[test]
+    fn overwrites_oldest_samples_once_full() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for i in 0..PRICE_HISTORY_CAPACITY + 5 {
+            record_price(
+                deps.as_mut().storage,
+                &Pair::default(),
+                Decimal::from_ratio(i, 1u128),
+                env.block.time.plus_seconds(i.into()),
+            )
+            .unwrap();
+        }
+
+        let samples = get_latest_price_samples(
+            deps.as_ref().storage,
+            &Pair::default(),
+            PRICE_HISTORY_CAPACITY + 5,
+        )
+        .unwrap();
+
+        assert_eq!(samples.len(), PRICE_HISTORY_CAPACITY as usize);
+        assert_eq!(
+            samples.first().unwrap().price,
+            Decimal::from_ratio(PRICE_HISTORY_CAPACITY + 4, 1u128)
+        );
+        assert_eq!(
+            samples.last().unwrap().price,
+            Decimal::from_ratio(5u128, 1u128)
+        );
+    }
+}
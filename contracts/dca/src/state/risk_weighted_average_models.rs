@@ -0,0 +1,104 @@
+use crate::types::{
+    risk_weighted_average_model::RiskWeightedAverageModel,
+    swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
+};
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const RISK_WEIGHTED_AVERAGE_MODELS: Map<String, RiskWeightedAverageModel> =
+    Map::new("risk_weighted_average_models_v8");
+
+pub fn save_risk_weighted_average_model(
+    store: &mut dyn Storage,
+    model: &RiskWeightedAverageModel,
+) -> StdResult<()> {
+    RISK_WEIGHTED_AVERAGE_MODELS.save(store, model.base_denom.key(), model)
+}
+
+pub fn remove_risk_weighted_average_model(store: &mut dyn Storage, base_denom: &BaseDenom) {
+    RISK_WEIGHTED_AVERAGE_MODELS.remove(store, base_denom.key());
+}
+
+pub fn get_risk_weighted_average_model(
+    store: &dyn Storage,
+    base_denom: &BaseDenom,
+) -> StdResult<Option<RiskWeightedAverageModel>> {
+    let model = RISK_WEIGHTED_AVERAGE_MODELS.may_load(store, base_denom.key())?;
+
+    Ok(match (model, base_denom) {
+        (None, BaseDenom::Bitcoin) => Some(RiskWeightedAverageModel::bitcoin()),
+        (model, _) => model,
+    })
+}
+
+pub fn get_risk_weighted_average_model_for_strategy(
+    store: &dyn Storage,
+    strategy: &SwapAdjustmentStrategy,
+) -> StdResult<Option<RiskWeightedAverageModel>> {
+    match strategy {
+        SwapAdjustmentStrategy::RiskWeightedAverage { base_denom, .. } => {
+            get_risk_weighted_average_model(store, base_denom)
+        }
+        _ => Ok(None),
+    }
+}
+
+pub fn get_risk_weighted_average_models(
+    store: &dyn Storage,
+) -> StdResult<Vec<RiskWeightedAverageModel>> {
+    let mut models = RISK_WEIGHTED_AVERAGE_MODELS
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, model)| model))
+        .collect::<StdResult<Vec<RiskWeightedAverageModel>>>()?;
+
+    if !models
+        .iter()
+        .any(|model| model.base_denom == BaseDenom::Bitcoin)
+    {
+        models.insert(0, RiskWeightedAverageModel::bitcoin());
+    }
+
+    Ok(models)
+}
+
+#[cfg(test)]
+mod risk_weighted_average_models_tests {
+    use super::*;
+    use cosmwasm_std::{testing::mock_dependencies, Decimal};
+
+    #[test]
+    fn falls_back_to_default_bitcoin_model() {
+        let deps = mock_dependencies();
+
+        assert_eq!(
+            get_risk_weighted_average_model(deps.as_ref().storage, &BaseDenom::Bitcoin).unwrap(),
+            Some(RiskWeightedAverageModel::bitcoin())
+        );
+        assert_eq!(
+            get_risk_weighted_average_model(deps.as_ref().storage, &BaseDenom::Ethereum).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn registered_model_overrides_default() {
+        let mut deps = mock_dependencies();
+
+        let model = RiskWeightedAverageModel {
+            ttl: 60 * 60,
+            min_adjustment: Decimal::percent(50),
+            ..RiskWeightedAverageModel::bitcoin()
+        };
+
+        save_risk_weighted_average_model(deps.as_mut().storage, &model).unwrap();
+
+        assert_eq!(
+            get_risk_weighted_average_model(deps.as_ref().storage, &BaseDenom::Bitcoin).unwrap(),
+            Some(model.clone())
+        );
+        assert_eq!(
+            get_risk_weighted_average_models(deps.as_ref().storage).unwrap(),
+            vec![model]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::types::role::Role;
+use cosmwasm_std::{Addr, Empty, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const ROLES: Map<(&str, &Addr), Empty> = Map::new("roles_v8");
+
+pub fn grant_role(storage: &mut dyn Storage, role: &Role, address: &Addr) -> StdResult<()> {
+    ROLES.save(storage, (role.key(), address), &Empty {})
+}
+
+pub fn revoke_role(storage: &mut dyn Storage, role: &Role, address: &Addr) {
+    ROLES.remove(storage, (role.key(), address))
+}
+
+pub fn has_role(storage: &dyn Storage, role: &Role, address: &Addr) -> bool {
+    ROLES.has(storage, (role.key(), address))
+}
+
+pub fn get_roles(storage: &dyn Storage, address: &Addr) -> Vec<Role> {
+    Role::all()
+        .into_iter()
+        .filter(|role| has_role(storage, role, address))
+        .collect()
+}
+
+pub fn get_role_members(storage: &dyn Storage, role: &Role) -> StdResult<Vec<Addr>> {
+    ROLES
+        .prefix(role.key())
+        .keys(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+#[cfg(test)]
+mod roles_state_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn grants_and_revokes_role() {
+        let mut deps = mock_dependencies();
+        let address = Addr::unchecked("publisher");
+
+        grant_role(
+            deps.as_mut().storage,
+            &Role::SwapAdjustmentPublisher,
+            &address,
+        )
+        .unwrap();
+
+        assert!(has_role(
+            deps.as_ref().storage,
+            &Role::SwapAdjustmentPublisher,
+            &address
+        ));
+        assert!(!has_role(
+            deps.as_ref().storage,
+            &Role::FeeManager,
+            &address
+        ));
+        assert_eq!(
+            get_roles(deps.as_ref().storage, &address),
+            vec![Role::SwapAdjustmentPublisher]
+        );
+
+        revoke_role(
+            deps.as_mut().storage,
+            &Role::SwapAdjustmentPublisher,
+            &address,
+        );
+
+        assert!(get_roles(deps.as_ref().storage, &address).is_empty());
+    }
+
+    #[test]
+    fn gets_role_members() {
+        let mut deps = mock_dependencies();
+
+        grant_role(
+            deps.as_mut().storage,
+            &Role::Pauser,
+            &Addr::unchecked("pauser-1"),
+        )
+        .unwrap();
+        grant_role(
+            deps.as_mut().storage,
+            &Role::Pauser,
+            &Addr::unchecked("pauser-2"),
+        )
+        .unwrap();
+        grant_role(
+            deps.as_mut().storage,
+            &Role::FeeManager,
+            &Addr::unchecked("fee-manager"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_role_members(deps.as_ref().storage, &Role::Pauser).unwrap(),
+            vec![Addr::unchecked("pauser-1"), Addr::unchecked("pauser-2")]
+        );
+    }
+}
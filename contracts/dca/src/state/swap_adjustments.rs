@@ -1,15 +1,136 @@
-use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+use super::risk_weighted_average_models::get_risk_weighted_average_model_for_strategy;
+use crate::types::{
+    position_type::PositionType,
+    risk_weighted_average_model::RiskWeightedAverageModel,
+    swap_adjustment::{
+        SwapAdjustment, SwapAdjustmentAggregation, SwapAdjustmentHistoryEntry,
+        SwapAdjustmentStatus, SwapAdjustmentSubmission,
+    },
+    swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp};
-use cw_storage_plus::Map;
+use cosmwasm_std::{to_binary, Addr, Decimal, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
+const SWAP_ADJUSTMENTS: Map<String, SwapAdjustment> = Map::new("swap_adjustments_v8");
+
+const SWAP_ADJUSTMENT_HISTORY: Map<(String, u64), Decimal> = Map::new("swap_adjustment_history_v8");
+
+const SWAP_ADJUSTMENT_AGGREGATION: Item<SwapAdjustmentAggregation> =
+    Item::new("swap_adjustment_aggregation_v8");
+
+const SWAP_ADJUSTMENT_SUBMISSIONS: Map<(String, &Addr), SwapAdjustmentSubmission> =
+    Map::new("swap_adjustment_submissions_v8");
+
+/// Swap adjustments as stored before they were keyed by `SwapAdjustmentStrategy::key`, under a
+/// hash of the JSON encoded strategy.
 #[cw_serde]
-struct SwapAdjustment {
+struct LegacySwapAdjustment {
     value: Decimal,
     timestamp: u64,
 }
 
-const SWAP_ADJUSTMENTS: Map<u64, SwapAdjustment> = Map::new("buy_adjustments_v8");
+const LEGACY_SWAP_ADJUSTMENTS: Map<u64, LegacySwapAdjustment> = Map::new("buy_adjustments_v8");
+
+fn legacy_key_from(strategy: &SwapAdjustmentStrategy) -> StdResult<u64> {
+    let mut hasher = DefaultHasher::new();
+    to_binary(strategy)?.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+/// Copies the swap adjustments published before the upgrade into the current layout. Hashed
+/// keys cannot be enumerated back into strategies, so every strategy the legacy layout could
+/// hold (the built in bitcoin model ids for both position types) is looked up instead. Values
+/// already published under the current layout are kept.
+pub fn migrate_legacy_swap_adjustments(
+    store: &mut dyn Storage,
+    _start_after: Option<u128>,
+    _limit: u16,
+) -> StdResult<Option<u128>> {
+    let model = RiskWeightedAverageModel::bitcoin();
+
+    let model_ids = model
+        .duration_buckets
+        .iter()
+        .map(|bucket| bucket.model_id)
+        .chain([model.default_model_id]);
+
+    for model_id in model_ids {
+        for position_type in [PositionType::Enter, PositionType::Exit] {
+            let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id,
+                base_denom: BaseDenom::Bitcoin,
+                position_type,
+            };
+
+            let legacy_key = legacy_key_from(&strategy)?;
+
+            if let Some(legacy_adjustment) = LEGACY_SWAP_ADJUSTMENTS.may_load(store, legacy_key)? {
+                if !SWAP_ADJUSTMENTS.has(store, strategy.key()) {
+                    SWAP_ADJUSTMENTS.save(
+                        store,
+                        strategy.key(),
+                        &SwapAdjustment {
+                            strategy,
+                            value: legacy_adjustment.value,
+                            updated_at: Timestamp::from_seconds(legacy_adjustment.timestamp),
+                        },
+                    )?;
+                }
+
+                LEGACY_SWAP_ADJUSTMENTS.remove(store, legacy_key);
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn get_swap_adjustment_aggregation(
+    storage: &dyn Storage,
+) -> StdResult<Option<SwapAdjustmentAggregation>> {
+    SWAP_ADJUSTMENT_AGGREGATION.may_load(storage)
+}
+
+pub fn save_swap_adjustment_aggregation(
+    storage: &mut dyn Storage,
+    aggregation: &Option<SwapAdjustmentAggregation>,
+) -> StdResult<()> {
+    match aggregation {
+        Some(aggregation) => SWAP_ADJUSTMENT_AGGREGATION.save(storage, aggregation),
+        None => {
+            SWAP_ADJUSTMENT_AGGREGATION.remove(storage);
+            Ok(())
+        }
+    }
+}
+
+pub fn save_swap_adjustment_submission(
+    storage: &mut dyn Storage,
+    strategy: &SwapAdjustmentStrategy,
+    publisher: &Addr,
+    value: Decimal,
+    block_time: Timestamp,
+) -> StdResult<()> {
+    SWAP_ADJUSTMENT_SUBMISSIONS.save(
+        storage,
+        (strategy.key(), publisher),
+        &SwapAdjustmentSubmission {
+            value,
+            submitted_at: block_time,
+        },
+    )
+}
+
+pub fn get_swap_adjustment_submission(
+    storage: &dyn Storage,
+    strategy: &SwapAdjustmentStrategy,
+    publisher: &Addr,
+) -> StdResult<Option<SwapAdjustmentSubmission>> {
+    SWAP_ADJUSTMENT_SUBMISSIONS.may_load(storage, (strategy.key(), publisher))
+}
 
 pub fn update_swap_adjustment(
     storage: &mut dyn Storage,
@@ -17,12 +138,14 @@ pub fn update_swap_adjustment(
     value: Decimal,
     block_time: Timestamp,
 ) -> StdResult<()> {
+    SWAP_ADJUSTMENT_HISTORY.save(storage, (strategy.key(), block_time.nanos()), &value)?;
     SWAP_ADJUSTMENTS.save(
         storage,
-        strategy.hash(),
+        strategy.key(),
         &SwapAdjustment {
+            strategy,
             value,
-            timestamp: block_time.seconds(),
+            updated_at: block_time,
         },
     )
 }
@@ -32,24 +155,102 @@ pub fn get_swap_adjustment(
     strategy: SwapAdjustmentStrategy,
     block_time: Timestamp,
 ) -> Decimal {
-    let adjustment = SWAP_ADJUSTMENTS
-        .load(storage, strategy.hash())
-        .unwrap_or_else(|_| SwapAdjustment {
-            value: Decimal::one(),
-            timestamp: block_time.seconds(),
-        });
-
-    if adjustment.timestamp + strategy.ttl() > block_time.seconds() {
-        adjustment.value
-    } else {
-        Decimal::one()
-    }
+    get_swap_adjustment_status(storage, strategy, block_time)
+        .map_or(Decimal::one(), |status| status.value)
+}
+
+pub fn get_swap_adjustment_status(
+    storage: &dyn Storage,
+    strategy: SwapAdjustmentStrategy,
+    block_time: Timestamp,
+) -> StdResult<SwapAdjustmentStatus> {
+    let adjustment = SWAP_ADJUSTMENTS.may_load(storage, strategy.key())?;
+    let model = get_risk_weighted_average_model_for_strategy(storage, &strategy)?;
+
+    let expires_at = adjustment.as_ref().map(|adjustment| {
+        adjustment
+            .updated_at
+            .plus_seconds(strategy.ttl(model.as_ref()))
+    });
+
+    let is_stale = expires_at.is_none_or(|expires_at| expires_at <= block_time);
+
+    Ok(SwapAdjustmentStatus {
+        key: strategy.key(),
+        value: match (&adjustment, is_stale) {
+            (Some(adjustment), false) => adjustment.value,
+            _ => Decimal::one(),
+        },
+        published_value: adjustment.as_ref().map(|adjustment| adjustment.value),
+        updated_at: adjustment.map(|adjustment| adjustment.updated_at),
+        expires_at,
+        is_stale,
+        strategy,
+    })
+}
+
+pub fn get_swap_adjustment_statuses(
+    storage: &dyn Storage,
+    block_time: Timestamp,
+    start_after: Option<String>,
+    limit: u16,
+) -> StdResult<Vec<SwapAdjustmentStatus>> {
+    SWAP_ADJUSTMENTS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|result| {
+            result.and_then(|(_, adjustment)| {
+                get_swap_adjustment_status(storage, adjustment.strategy, block_time)
+            })
+        })
+        .collect()
+}
+
+pub fn get_swap_adjustment_history(
+    storage: &dyn Storage,
+    strategy: &SwapAdjustmentStrategy,
+    start_after: Option<Timestamp>,
+    limit: u16,
+    reverse: bool,
+) -> StdResult<Vec<SwapAdjustmentHistoryEntry>> {
+    let (min, max, order) = match reverse {
+        false => (
+            start_after.map(|timestamp| Bound::exclusive(timestamp.nanos())),
+            None,
+            Order::Ascending,
+        ),
+        true => (
+            None,
+            start_after.map(|timestamp| Bound::exclusive(timestamp.nanos())),
+            Order::Descending,
+        ),
+    };
+
+    SWAP_ADJUSTMENT_HISTORY
+        .prefix(strategy.key())
+        .range(storage, min, max, order)
+        .take(limit as usize)
+        .map(|result| {
+            result.map(|(timestamp, value)| SwapAdjustmentHistoryEntry {
+                value,
+                updated_at: Timestamp::from_nanos(timestamp),
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{position_type::PositionType, swap_adjustment_strategy::BaseDenom};
+    use crate::types::{
+        position_type::PositionType, risk_weighted_average_model::RiskWeightedAverageModel,
+        swap_adjustment_strategy::BaseDenom,
+    };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env},
         Decimal,
@@ -109,9 +310,228 @@ mod tests {
         let adjustment = get_swap_adjustment(
             deps.as_ref().storage,
             strategy.clone(),
-            env.block.time.plus_seconds(strategy.ttl() + 1),
+            env.block
+                .time
+                .plus_seconds(strategy.ttl(Some(&RiskWeightedAverageModel::bitcoin())) + 1),
         );
 
         assert_eq!(adjustment, Decimal::one());
     }
+
+    #[test]
+    fn gets_stale_status_if_never_updated() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        let status =
+            get_swap_adjustment_status(deps.as_ref().storage, strategy.clone(), env.block.time)
+                .unwrap();
+
+        assert_eq!(
+            status,
+            SwapAdjustmentStatus {
+                key: strategy.key(),
+                strategy,
+                value: Decimal::one(),
+                published_value: None,
+                updated_at: None,
+                expires_at: None,
+                is_stale: true,
+            }
+        );
+    }
+
+    #[test]
+    fn gets_stale_status_with_published_value_if_not_updated_within_ttl() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            strategy.clone(),
+            Decimal::percent(90),
+            env.block.time,
+        )
+        .unwrap();
+
+        let ttl = strategy.ttl(Some(&RiskWeightedAverageModel::bitcoin()));
+
+        let status = get_swap_adjustment_status(
+            deps.as_ref().storage,
+            strategy.clone(),
+            env.block.time.plus_seconds(ttl),
+        )
+        .unwrap();
+
+        assert_eq!(
+            status,
+            SwapAdjustmentStatus {
+                key: strategy.key(),
+                strategy,
+                value: Decimal::one(),
+                published_value: Some(Decimal::percent(90)),
+                updated_at: Some(env.block.time),
+                expires_at: Some(env.block.time.plus_seconds(ttl)),
+                is_stale: true,
+            }
+        );
+    }
+
+    #[test]
+    fn retains_history_of_updates() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        let other_strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Exit,
+        };
+
+        for (i, value) in [90, 110, 120].into_iter().enumerate() {
+            update_swap_adjustment(
+                deps.as_mut().storage,
+                strategy.clone(),
+                Decimal::percent(value),
+                env.block.time.plus_seconds(i as u64 * 100),
+            )
+            .unwrap();
+        }
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            other_strategy,
+            Decimal::percent(80),
+            env.block.time,
+        )
+        .unwrap();
+
+        let history =
+            get_swap_adjustment_history(deps.as_ref().storage, &strategy, None, 30, false).unwrap();
+
+        assert_eq!(
+            history,
+            vec![
+                SwapAdjustmentHistoryEntry {
+                    value: Decimal::percent(90),
+                    updated_at: env.block.time,
+                },
+                SwapAdjustmentHistoryEntry {
+                    value: Decimal::percent(110),
+                    updated_at: env.block.time.plus_seconds(100),
+                },
+                SwapAdjustmentHistoryEntry {
+                    value: Decimal::percent(120),
+                    updated_at: env.block.time.plus_seconds(200),
+                },
+            ]
+        );
+
+        let history = get_swap_adjustment_history(
+            deps.as_ref().storage,
+            &strategy,
+            Some(env.block.time.plus_seconds(200)),
+            1,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            history,
+            vec![SwapAdjustmentHistoryEntry {
+                value: Decimal::percent(110),
+                updated_at: env.block.time.plus_seconds(100),
+            }]
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_swap_adjustments_to_strategy_keys() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 45,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Exit,
+        };
+
+        let legacy_key = legacy_key_from(&strategy).unwrap();
+
+        LEGACY_SWAP_ADJUSTMENTS
+            .save(
+                deps.as_mut().storage,
+                legacy_key,
+                &LegacySwapAdjustment {
+                    value: Decimal::percent(150),
+                    timestamp: env.block.time.minus_seconds(60).seconds(),
+                },
+            )
+            .unwrap();
+
+        let cursor = migrate_legacy_swap_adjustments(deps.as_mut().storage, None, 10).unwrap();
+
+        assert_eq!(cursor, None);
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, env.block.time),
+            Decimal::percent(150)
+        );
+        assert!(!LEGACY_SWAP_ADJUSTMENTS.has(deps.as_ref().storage, legacy_key));
+    }
+
+    #[test]
+    fn migrating_legacy_swap_adjustments_keeps_newer_values() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        };
+
+        LEGACY_SWAP_ADJUSTMENTS
+            .save(
+                deps.as_mut().storage,
+                legacy_key_from(&strategy).unwrap(),
+                &LegacySwapAdjustment {
+                    value: Decimal::percent(150),
+                    timestamp: env.block.time.minus_seconds(60).seconds(),
+                },
+            )
+            .unwrap();
+
+        update_swap_adjustment(
+            deps.as_mut().storage,
+            strategy.clone(),
+            Decimal::percent(80),
+            env.block.time,
+        )
+        .unwrap();
+
+        migrate_legacy_swap_adjustments(deps.as_mut().storage, None, 10).unwrap();
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, env.block.time),
+            Decimal::percent(80)
+        );
+    }
 }
@@ -0,0 +1,162 @@
+use crate::types::solvency::VaultTotals;
+use cosmwasm_std::{Coin, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const VAULT_TOTALS: Map<String, VaultTotals> = Map::new("vault_totals_v8");
+
+pub fn get_vault_totals(store: &dyn Storage, denom: String) -> StdResult<VaultTotals> {
+    Ok(VAULT_TOTALS.may_load(store, denom)?.unwrap_or_default())
+}
+
+pub fn get_all_vault_totals(store: &dyn Storage) -> StdResult<Vec<(String, VaultTotals)>> {
+    VAULT_TOTALS
+        .range(store, None, None, Order::Ascending)
+        .collect()
+}
+
+pub fn add_to_vault_totals(
+    store: &mut dyn Storage,
+    balance: &Coin,
+    escrowed: &Coin,
+) -> StdResult<()> {
+    if !balance.amount.is_zero() {
+        let totals = get_vault_totals(store, balance.denom.clone())?;
+        VAULT_TOTALS.save(
+            store,
+            balance.denom.clone(),
+            &VaultTotals {
+                balance: totals.balance.checked_add(balance.amount)?,
+                ..totals
+            },
+        )?;
+    }
+
+    if !escrowed.amount.is_zero() {
+        let totals = get_vault_totals(store, escrowed.denom.clone())?;
+        VAULT_TOTALS.save(
+            store,
+            escrowed.denom.clone(),
+            &VaultTotals {
+                escrowed: totals.escrowed.checked_add(escrowed.amount)?,
+                ..totals
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn subtract_from_vault_totals(
+    store: &mut dyn Storage,
+    balance: &Coin,
+    escrowed: &Coin,
+) -> StdResult<()> {
+    if !balance.amount.is_zero() {
+        let totals = get_vault_totals(store, balance.denom.clone())?;
+        VAULT_TOTALS.save(
+            store,
+            balance.denom.clone(),
+            &VaultTotals {
+                balance: totals.balance.saturating_sub(balance.amount),
+                ..totals
+            },
+        )?;
+    }
+
+    if !escrowed.amount.is_zero() {
+        let totals = get_vault_totals(store, escrowed.denom.clone())?;
+        VAULT_TOTALS.save(
+            store,
+            escrowed.denom.clone(),
+            &VaultTotals {
+                escrowed: totals.escrowed.saturating_sub(escrowed.amount),
+                ..totals
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn clear_vault_totals(store: &mut dyn Storage) -> StdResult<()> {
+    let denoms = VAULT_TOTALS
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    for denom in denoms {
+        VAULT_TOTALS.remove(store, denom);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod vault_totals_tests {
+    use super::*;
+    use crate::tests::mocks::{DENOM_STAKE, DENOM_UOSMO};
+    use cosmwasm_std::{testing::mock_dependencies, Coin, Uint128};
+
+    #[test]
+    fn adds_and_subtracts_balances_and_escrow_per_denom() {
+        let mut deps = mock_dependencies();
+
+        add_to_vault_totals(
+            deps.as_mut().storage,
+            &Coin::new(1000, DENOM_UOSMO),
+            &Coin::new(20, DENOM_STAKE),
+        )
+        .unwrap();
+
+        add_to_vault_totals(
+            deps.as_mut().storage,
+            &Coin::new(500, DENOM_STAKE),
+            &Coin::new(10, DENOM_UOSMO),
+        )
+        .unwrap();
+
+        subtract_from_vault_totals(
+            deps.as_mut().storage,
+            &Coin::new(400, DENOM_UOSMO),
+            &Coin::new(0, DENOM_STAKE),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_all_vault_totals(deps.as_ref().storage).unwrap(),
+            vec![
+                (
+                    DENOM_STAKE.to_string(),
+                    VaultTotals {
+                        balance: Uint128::new(500),
+                        escrowed: Uint128::new(20),
+                    }
+                ),
+                (
+                    DENOM_UOSMO.to_string(),
+                    VaultTotals {
+                        balance: Uint128::new(600),
+                        escrowed: Uint128::new(10),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn clears_all_totals() {
+        let mut deps = mock_dependencies();
+
+        add_to_vault_totals(
+            deps.as_mut().storage,
+            &Coin::new(1000, DENOM_UOSMO),
+            &Coin::new(20, DENOM_STAKE),
+        )
+        .unwrap();
+
+        clear_vault_totals(deps.as_mut().storage).unwrap();
+
+        assert!(get_all_vault_totals(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+    }
+}
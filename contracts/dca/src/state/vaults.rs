@@ -1,28 +1,47 @@
-use super::{config::get_config, triggers::get_trigger};
+use super::{
+    config::{get_config, get_contract_address},
+    migrations::get_migration_progress,
+    pairs::key_from,
+    triggers::get_trigger,
+    vault_totals::{add_to_vault_totals, clear_vault_totals, subtract_from_vault_totals},
+};
 use crate::{
     helpers::state::fetch_and_increment_counter,
     types::{
-        destination::Destination,
+        destination::{Destination, DestinationAction},
+        funding_strategy::FundingStrategy,
+        lockable_duration::LockableDuration,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
-        swap_adjustment_strategy::SwapAdjustmentStrategy,
+        swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
         time_interval::TimeInterval,
         vault::{Vault, VaultBuilder, VaultStatus},
     },
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
+use cosmwasm_std::{
+    from_binary, Addr, Binary, Coin, Decimal, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, MultiIndex, UniqueIndex};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 const VAULT_COUNTER: Item<u64> = Item::new("vault_counter_v8");
 
 struct VaultIndexes<'a> {
     pub owner: UniqueIndex<'a, (Addr, u128), VaultData, u128>,
     pub owner_status: UniqueIndex<'a, (Addr, u8, u128), VaultData, u128>,
+    pub pair_status: MultiIndex<'a, (String, u8), VaultData, u128>,
+    pub base_denom_status: MultiIndex<'a, (String, u8), VaultData, u128>,
 }
 
 impl<'a> IndexList<VaultData> for VaultIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<VaultData>> + '_> {
-        let v: Vec<&dyn Index<VaultData>> = vec![&self.owner, &self.owner_status];
+        let v: Vec<&dyn Index<VaultData>> = vec![
+            &self.owner,
+            &self.owner_status,
+            &self.pair_status,
+            &self.base_denom_status,
+        ];
         Box::new(v.into_iter())
     }
 }
@@ -34,13 +53,40 @@ fn vault_store<'a>() -> IndexedMap<'a, u128, VaultData, VaultIndexes<'a>> {
             |v| (v.owner.clone(), v.status.clone() as u8, v.id.into()),
             "vaults_v8__owner_status",
         ),
+        pair_status: MultiIndex::new(
+            |_, v| {
+                (
+                    key_from([v.balance.denom.clone(), v.target_denom.clone()]),
+                    v.status.clone() as u8,
+                )
+            },
+            "vaults_v8",
+            "vaults_v8__pair_status",
+        ),
+        base_denom_status: MultiIndex::new(
+            |_, v| {
+                (
+                    match &v.swap_adjustment_strategy {
+                        Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                            base_denom, ..
+                        }) => base_denom.key(),
+                        _ => String::new(),
+                    },
+                    v.status.clone() as u8,
+                )
+            },
+            "vaults_v8",
+            "vaults_v8__base_denom_status",
+        ),
     };
     IndexedMap::new("vaults_v8", indexes)
 }
 
 pub fn save_vault(store: &mut dyn Storage, vault_builder: VaultBuilder) -> StdResult<Vault> {
     let vault = vault_builder.build(fetch_and_increment_counter(store, VAULT_COUNTER)?.into());
-    vault_store().save(store, vault.id.into(), &vault.clone().into())?;
+    let vault_data: VaultData = vault.clone().into();
+    vault_store().save(store, vault.id.into(), &vault_data)?;
+    update_vault_totals(store, None, &vault_data)?;
     Ok(vault)
 }
 
@@ -95,18 +141,283 @@ pub fn get_vaults(
         .collect::<Vec<Vault>>())
 }
 
+pub fn get_active_vault_ids_by_denoms(
+    store: &dyn Storage,
+    denoms: [String; 2],
+    limit: usize,
+) -> StdResult<Vec<Uint128>> {
+    let pair_key = key_from(denoms);
+
+    [VaultStatus::Scheduled, VaultStatus::Active]
+        .into_iter()
+        .flat_map(|status| {
+            vault_store()
+                .idx
+                .pair_status
+                .prefix((pair_key.clone(), status as u8))
+                .keys(store, None, None, cosmwasm_std::Order::Ascending)
+        })
+        .take(limit)
+        .map(|result| result.map(Uint128::from))
+        .collect()
+}
+
+pub fn get_active_vault_ids_by_risk_weighted_average_base_denom(
+    store: &dyn Storage,
+    base_denom: &BaseDenom,
+    limit: usize,
+) -> StdResult<Vec<Uint128>> {
+    [VaultStatus::Scheduled, VaultStatus::Active]
+        .into_iter()
+        .flat_map(|status| {
+            vault_store()
+                .idx
+                .base_denom_status
+                .prefix((base_denom.key(), status as u8))
+                .keys(store, None, None, cosmwasm_std::Order::Ascending)
+        })
+        .take(limit)
+        .map(|result| result.map(Uint128::from))
+        .collect()
+}
+
 pub fn update_vault(store: &mut dyn Storage, vault: Vault) -> StdResult<Vault> {
-    vault_store().save(store, vault.id.into(), &vault.clone().into())?;
+    let existing_vault_data = vault_store().may_load(store, vault.id.into())?;
+    let vault_data: VaultData = vault.clone().into();
+    vault_store().save(store, vault.id.into(), &vault_data)?;
+    update_vault_totals(store, existing_vault_data.as_ref(), &vault_data)?;
     Ok(vault)
 }
 
+const VAULT_TOTALS_STORAGE_VERSION: u16 = 3;
+
+// While the vault totals are being backfilled, only vaults the backfill has
+// already counted are tracked, so that no vault is counted twice.
+fn is_included_in_vault_totals(store: &dyn Storage, vault_id: u128) -> StdResult<bool> {
+    Ok(match get_migration_progress(store)? {
+        Some(progress) if progress.version < VAULT_TOTALS_STORAGE_VERSION => false,
+        Some(progress) if progress.version == VAULT_TOTALS_STORAGE_VERSION => {
+            progress.cursor.is_some_and(|cursor| vault_id <= cursor)
+        }
+        _ => true,
+    })
+}
+
+fn update_vault_totals(
+    store: &mut dyn Storage,
+    existing_vault_data: Option<&VaultData>,
+    vault_data: &VaultData,
+) -> StdResult<()> {
+    if !is_included_in_vault_totals(store, vault_data.id.into())? {
+        return Ok(());
+    }
+
+    if let Some(existing_vault_data) = existing_vault_data {
+        subtract_from_vault_totals(
+            store,
+            &existing_vault_data.balance,
+            &existing_vault_data.escrowed_amount,
+        )?;
+    }
+
+    add_to_vault_totals(store, &vault_data.balance, &vault_data.escrowed_amount)
+}
+
+fn get_vault_data_page(
+    store: &dyn Storage,
+    start_after: Option<u128>,
+    limit: u16,
+) -> StdResult<Vec<(u128, VaultData)>> {
+    vault_store()
+        .range(
+            store,
+            start_after.map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect()
+}
+
+fn next_cursor(page: &[(u128, VaultData)], limit: u16) -> Option<u128> {
+    if page.len() < limit as usize {
+        None
+    } else {
+        page.last().map(|(id, _)| *id)
+    }
+}
+
+pub fn migrate_vault_data(
+    store: &mut dyn Storage,
+    start_after: Option<u128>,
+    limit: u16,
+) -> StdResult<Option<u128>> {
+    let page = get_vault_data_page(store, start_after, limit)?;
+
+    for (id, data) in page.iter() {
+        let destinations = data
+            .destinations
+            .iter()
+            .cloned()
+            .map(|destination| destination_from(store, destination).map(DestinationData::Typed))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        vault_store().save(
+            store,
+            *id,
+            &VaultData {
+                destinations,
+                funding_strategy: Some(
+                    data.funding_strategy
+                        .clone()
+                        .unwrap_or(FundingStrategy::Deposit),
+                ),
+                ..data.clone()
+            },
+        )?;
+    }
+
+    Ok(next_cursor(&page, limit))
+}
+
+/// Re-saves every vault so that indexes added since the vault was last written are populated.
+pub fn reindex_vaults(
+    store: &mut dyn Storage,
+    start_after: Option<u128>,
+    limit: u16,
+) -> StdResult<Option<u128>> {
+    let page = get_vault_data_page(store, start_after, limit)?;
+
+    for (id, data) in page.iter() {
+        vault_store().save(store, *id, data)?;
+    }
+
+    Ok(next_cursor(&page, limit))
+}
+
+pub fn backfill_vault_totals(
+    store: &mut dyn Storage,
+    start_after: Option<u128>,
+    limit: u16,
+) -> StdResult<Option<u128>> {
+    if start_after.is_none() {
+        clear_vault_totals(store)?;
+    }
+
+    let page = get_vault_data_page(store, start_after, limit)?;
+
+    for (_, data) in page.iter() {
+        add_to_vault_totals(store, &data.balance, &data.escrowed_amount)?;
+    }
+
+    Ok(next_cursor(&page, limit))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+enum DestinationData {
+    Typed(Destination),
+    Legacy {
+        allocation: Decimal,
+        address: Addr,
+        msg: Option<Binary>,
+    },
+}
+
+/// Callbacks to this contract that legacy destinations stored as their `msg`.
+#[cw_serde]
+enum LegacyCallbackMsg {
+    ZDelegate {
+        delegator_address: Addr,
+        validator_address: Addr,
+    },
+    ZProvideLiquidity {
+        provider_address: Addr,
+        pool_id: u64,
+        duration: LockableDuration,
+        slippage_tolerance: Option<Decimal>,
+    },
+    Deposit {
+        address: Addr,
+        vault_id: Uint128,
+    },
+}
+
+/// Legacy destinations only ever carried a callback for this contract when their
+/// `address` was this contract, so any other address keeps its message as a
+/// generic contract execution.
+fn destination_from(store: &dyn Storage, data: DestinationData) -> StdResult<Destination> {
+    Ok(match data {
+        DestinationData::Typed(destination) => destination,
+        DestinationData::Legacy {
+            allocation,
+            address,
+            msg: None,
+        } => Destination {
+            allocation,
+            address,
+            action: DestinationAction::Send,
+        },
+        DestinationData::Legacy {
+            allocation,
+            address,
+            msg: Some(msg),
+        } => {
+            let callback = if get_contract_address(store)?.as_ref() == Some(&address) {
+                from_binary::<LegacyCallbackMsg>(&msg).ok()
+            } else {
+                None
+            };
+
+            match callback {
+                Some(LegacyCallbackMsg::ZDelegate {
+                    delegator_address,
+                    validator_address,
+                }) => Destination {
+                    allocation,
+                    address: delegator_address,
+                    action: DestinationAction::Delegate { validator_address },
+                },
+                Some(LegacyCallbackMsg::ZProvideLiquidity {
+                    provider_address,
+                    pool_id,
+                    duration,
+                    slippage_tolerance,
+                }) => Destination {
+                    allocation,
+                    address: provider_address,
+                    action: DestinationAction::ProvideLiquidity {
+                        pool_id,
+                        duration,
+                        slippage_tolerance,
+                        superfluid_validator_address: None,
+                    },
+                },
+                Some(LegacyCallbackMsg::Deposit {
+                    address: vault_owner,
+                    vault_id,
+                }) => Destination {
+                    allocation,
+                    address: vault_owner,
+                    action: DestinationAction::DepositToVault { vault_id },
+                },
+                None => Destination {
+                    allocation,
+                    address,
+                    action: DestinationAction::ExecuteContract { msg },
+                },
+            }
+        }
+    })
+}
+
 #[cw_serde]
 struct VaultData {
     id: Uint128,
     created_at: Timestamp,
     owner: Addr,
     label: Option<String>,
-    destinations: Vec<Destination>,
+    destinations: Vec<DestinationData>,
     status: VaultStatus,
     balance: Coin,
     target_denom: String,
@@ -122,6 +433,7 @@ struct VaultData {
     escrowed_amount: Coin,
     performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    funding_strategy: Option<FundingStrategy>,
 }
 
 impl From<Vault> for VaultData {
@@ -134,7 +446,11 @@ impl From<Vault> for VaultData {
             status: vault.status,
             balance: vault.balance,
             target_denom: vault.target_denom,
-            destinations: vault.destinations,
+            destinations: vault
+                .destinations
+                .into_iter()
+                .map(DestinationData::Typed)
+                .collect(),
             swap_amount: vault.swap_amount,
             slippage_tolerance: vault.slippage_tolerance,
             minimum_receive_amount: vault.minimum_receive_amount,
@@ -147,6 +463,7 @@ impl From<Vault> for VaultData {
             escrowed_amount: vault.escrowed_amount,
             performance_assessment_strategy: vault.performance_assessment_strategy,
             swap_adjustment_strategy: vault.swap_adjustment_strategy,
+            funding_strategy: Some(vault.funding_strategy),
         }
     }
 }
@@ -163,7 +480,12 @@ fn vault_from(store: &dyn Storage, data: &VaultData) -> StdResult<Vault> {
         balance: data.balance.clone(),
         swap_amount: data.swap_amount,
         target_denom: data.target_denom.clone(),
-        destinations: data.destinations.clone(),
+        destinations: data
+            .destinations
+            .iter()
+            .cloned()
+            .map(|destination| destination_from(store, destination))
+            .collect::<StdResult<Vec<_>>>()?,
         slippage_tolerance: data.slippage_tolerance,
         minimum_receive_amount: data.minimum_receive_amount,
         time_interval: data.time_interval.clone(),
@@ -175,6 +497,299 @@ fn vault_from(store: &dyn Storage, data: &VaultData) -> StdResult<Vault> {
         escrowed_amount: data.escrowed_amount.clone(),
         performance_assessment_strategy: data.performance_assessment_strategy.clone(),
         swap_adjustment_strategy: data.swap_adjustment_strategy.clone(),
+        funding_strategy: data
+            .funding_strategy
+            .clone()
+            .unwrap_or(FundingStrategy::Deposit),
         trigger,
     })
 }
+
+#[cfg(test)]
+mod vault_data_tests {
+    use super::*;
+    use crate::{
+        state::config::save_contract_address,
+        tests::mocks::{DENOM_STAKE, DENOM_UOSMO, USER, VALIDATOR},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, MOCK_CONTRACT_ADDR},
+        to_binary,
+    };
+    use cw_storage_plus::Map;
+
+    #[test]
+    fn loads_legacy_destinations_as_typed_destinations() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault::default();
+        let msg = to_binary("test").unwrap();
+
+        let mut vault_data: VaultData = vault.clone().into();
+
+        vault_data.destinations = vec![
+            DestinationData::Legacy {
+                allocation: Decimal::percent(40),
+                address: Addr::unchecked(USER),
+                msg: None,
+            },
+            DestinationData::Legacy {
+                allocation: Decimal::percent(60),
+                address: Addr::unchecked("contract"),
+                msg: Some(msg.clone()),
+            },
+        ];
+
+        vault_store()
+            .save(deps.as_mut().storage, vault.id.into(), &vault_data)
+            .unwrap();
+
+        let stored_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            stored_vault.destinations,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(40),
+                    address: Addr::unchecked(USER),
+                    action: DestinationAction::Send,
+                },
+                Destination {
+                    allocation: Decimal::percent(60),
+                    address: Addr::unchecked("contract"),
+                    action: DestinationAction::ExecuteContract { msg },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn loads_legacy_self_callback_destinations_as_typed_actions() {
+        let mut deps = mock_dependencies();
+
+        save_contract_address(deps.as_mut().storage, &Addr::unchecked(MOCK_CONTRACT_ADDR)).unwrap();
+
+        let vault = Vault::default();
+
+        let mut vault_data: VaultData = vault.clone().into();
+
+        vault_data.destinations = vec![
+            DestinationData::Legacy {
+                allocation: Decimal::percent(30),
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                msg: Some(
+                    to_binary(&LegacyCallbackMsg::ZDelegate {
+                        delegator_address: Addr::unchecked(USER),
+                        validator_address: Addr::unchecked(VALIDATOR),
+                    })
+                    .unwrap(),
+                ),
+            },
+            DestinationData::Legacy {
+                allocation: Decimal::percent(30),
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                msg: Some(
+                    to_binary(&LegacyCallbackMsg::ZProvideLiquidity {
+                        provider_address: Addr::unchecked(USER),
+                        pool_id: 1,
+                        duration: LockableDuration::OneWeek,
+                        slippage_tolerance: Some(Decimal::percent(1)),
+                    })
+                    .unwrap(),
+                ),
+            },
+            DestinationData::Legacy {
+                allocation: Decimal::percent(40),
+                address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                msg: Some(
+                    to_binary(&LegacyCallbackMsg::Deposit {
+                        address: Addr::unchecked(USER),
+                        vault_id: Uint128::new(2),
+                    })
+                    .unwrap(),
+                ),
+            },
+        ];
+
+        vault_store()
+            .save(deps.as_mut().storage, vault.id.into(), &vault_data)
+            .unwrap();
+
+        let stored_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            stored_vault.destinations,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(30),
+                    address: Addr::unchecked(USER),
+                    action: DestinationAction::Delegate {
+                        validator_address: Addr::unchecked(VALIDATOR),
+                    },
+                },
+                Destination {
+                    allocation: Decimal::percent(30),
+                    address: Addr::unchecked(USER),
+                    action: DestinationAction::ProvideLiquidity {
+                        pool_id: 1,
+                        duration: LockableDuration::OneWeek,
+                        slippage_tolerance: Some(Decimal::percent(1)),
+                        superfluid_validator_address: None,
+                    },
+                },
+                Destination {
+                    allocation: Decimal::percent(40),
+                    address: Addr::unchecked(USER),
+                    action: DestinationAction::DepositToVault {
+                        vault_id: Uint128::new(2),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn loads_legacy_callbacks_to_external_contracts_as_contract_executions() {
+        let mut deps = mock_dependencies();
+
+        save_contract_address(deps.as_mut().storage, &Addr::unchecked(MOCK_CONTRACT_ADDR)).unwrap();
+
+        let vault = Vault::default();
+        let msg = to_binary(&LegacyCallbackMsg::ZDelegate {
+            delegator_address: Addr::unchecked(USER),
+            validator_address: Addr::unchecked(VALIDATOR),
+        })
+        .unwrap();
+
+        let mut vault_data: VaultData = vault.clone().into();
+
+        vault_data.destinations = vec![DestinationData::Legacy {
+            allocation: Decimal::percent(100),
+            address: Addr::unchecked("external-contract"),
+            msg: Some(msg.clone()),
+        }];
+
+        vault_store()
+            .save(deps.as_mut().storage, vault.id.into(), &vault_data)
+            .unwrap();
+
+        let stored_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            stored_vault.destinations,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: Addr::unchecked("external-contract"),
+                action: DestinationAction::ExecuteContract { msg },
+            }]
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_vault_data_to_latest_layout() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault::default();
+
+        let mut vault_data: VaultData = vault.clone().into();
+
+        vault_data.funding_strategy = None;
+        vault_data.destinations = vec![DestinationData::Legacy {
+            allocation: Decimal::percent(100),
+            address: Addr::unchecked(USER),
+            msg: None,
+        }];
+
+        vault_store()
+            .save(deps.as_mut().storage, vault.id.into(), &vault_data)
+            .unwrap();
+
+        let cursor = migrate_vault_data(deps.as_mut().storage, None, 10).unwrap();
+
+        assert_eq!(cursor, None);
+
+        let migrated_vault_data = vault_store()
+            .load(deps.as_ref().storage, vault.id.into())
+            .unwrap();
+
+        assert_eq!(
+            migrated_vault_data.funding_strategy,
+            Some(FundingStrategy::Deposit)
+        );
+        assert_eq!(
+            migrated_vault_data.destinations,
+            vec![DestinationData::Typed(Destination {
+                allocation: Decimal::percent(100),
+                address: Addr::unchecked(USER),
+                action: DestinationAction::Send,
+            })]
+        );
+    }
+
+    #[test]
+    fn reindexes_vaults_saved_before_the_pair_status_index() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault {
+            status: VaultStatus::Active,
+            ..Vault::default()
+        };
+
+        Map::<u128, VaultData>::new("vaults_v8")
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &vault.clone().into(),
+            )
+            .unwrap();
+
+        assert!(
+            get_active_vault_ids_by_denoms(deps.as_ref().storage, vault.denoms(), 10)
+                .unwrap()
+                .is_empty()
+        );
+
+        let cursor = reindex_vaults(deps.as_mut().storage, None, 10).unwrap();
+
+        assert_eq!(cursor, None);
+        assert_eq!(
+            get_active_vault_ids_by_denoms(deps.as_ref().storage, vault.denoms(), 10).unwrap(),
+            vec![vault.id]
+        );
+    }
+
+    #[test]
+    fn gets_only_active_and_scheduled_vault_ids_for_the_pair() {
+        let mut deps = mock_dependencies();
+
+        for (id, status, target_denom) in [
+            (1, VaultStatus::Active, DENOM_STAKE),
+            (2, VaultStatus::Scheduled, DENOM_STAKE),
+            (3, VaultStatus::Inactive, DENOM_STAKE),
+            (4, VaultStatus::Cancelled, DENOM_STAKE),
+            (5, VaultStatus::Active, "uion"),
+        ] {
+            let vault = Vault {
+                id: Uint128::new(id),
+                status,
+                target_denom: target_denom.to_string(),
+                ..Vault::default()
+            };
+
+            vault_store()
+                .save(deps.as_mut().storage, id, &vault.into())
+                .unwrap();
+        }
+
+        let mut vault_ids = get_active_vault_ids_by_denoms(
+            deps.as_ref().storage,
+            [DENOM_UOSMO.to_string(), DENOM_STAKE.to_string()],
+            10,
+        )
+        .unwrap();
+
+        vault_ids.sort();
+
+        assert_eq!(vault_ids, vec![Uint128::new(1), Uint128::new(2)]);
+    }
+}
@@ -3,13 +3,20 @@ use crate::{
     constants::{ONE, TEN},
     contract::instantiate,
     handlers::get_vault::get_vault_handler,
-    msg::{ExecuteMsg, InstantiateMsg},
-    state::{cache::VAULT_CACHE, pairs::save_pair, triggers::save_trigger, vaults::update_vault},
+    msg::InstantiateMsg,
+    state::{
+        cache::{ProvideLiquidityCache, VAULT_CACHE},
+        pairs::save_pair,
+        triggers::save_trigger,
+        vaults::update_vault,
+    },
     types::{
         config::Config,
-        destination::Destination,
+        destination::{Destination, DestinationAction},
         event::{EventBuilder, EventData},
         fee_collector::FeeCollector,
+        funding_strategy::FundingStrategy,
+        lockable_duration::LockableDuration,
         pair::Pair,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         position_type::PositionType,
@@ -21,9 +28,7 @@ use crate::{
         vault::{Vault, VaultStatus},
     },
 };
-use cosmwasm_std::{
-    to_binary, Addr, BlockInfo, Coin, Decimal, DepsMut, Env, MessageInfo, Timestamp, Uint128,
-};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, DepsMut, Env, MessageInfo, Timestamp, Uint128};
 use std::{cmp::max, str::FromStr};
 
 pub fn instantiate_contract(deps: DepsMut, env: Env, info: MessageInfo) {
@@ -97,6 +102,9 @@ impl Default for Pair {
             base_denom: DENOM_UOSMO.to_string(),
             quote_denom: DENOM_STAKE.to_string(),
             route: vec![3],
+            price_source: None,
+            circuit_breaker: None,
+            config: None,
         }
     }
 }
@@ -106,7 +114,7 @@ impl Default for Destination {
         Self {
             allocation: Decimal::percent(100),
             address: Addr::unchecked(USER),
-            msg: None,
+            action: DestinationAction::Send,
         }
     }
 }
@@ -120,14 +128,10 @@ impl Default for Vault {
             label: Some("vault".to_string()),
             destinations: vec![Destination {
                 allocation: Decimal::percent(100),
-                address: Addr::unchecked("contractaddress"),
-                msg: Some(
-                    to_binary(&ExecuteMsg::ZDelegate {
-                        delegator_address: Addr::unchecked(USER),
-                        validator_address: Addr::unchecked(VALIDATOR),
-                    })
-                    .unwrap(),
-                ),
+                address: Addr::unchecked(USER),
+                action: DestinationAction::Delegate {
+                    validator_address: Addr::unchecked(VALIDATOR),
+                },
             }],
             status: VaultStatus::Active,
             balance: Coin::new(TEN.into(), DENOM_UOSMO),
@@ -147,6 +151,7 @@ impl Default for Vault {
             }),
             swap_adjustment_strategy: None,
             performance_assessment_strategy: None,
+            funding_strategy: FundingStrategy::Deposit,
         }
     }
 }
@@ -174,6 +179,19 @@ impl Default for PerformanceAssessmentStrategy {
         Self::CompareToStandardDca {
             swapped_amount: Coin::new(0, DENOM_UOSMO),
             received_amount: Coin::new(0, DENOM_STAKE),
+            performance_fee: None,
+        }
+    }
+}
+
+impl Default for ProvideLiquidityCache {
+    fn default() -> Self {
+        Self {
+            provider_address: Addr::unchecked(USER),
+            pool_id: 1,
+            duration: LockableDuration::OneDay,
+            superfluid_validator_address: None,
+            vault_id: None,
         }
     }
 }
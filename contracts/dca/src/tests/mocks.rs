@@ -1,17 +1,22 @@
 use crate::constants::{ONE, ONE_DECIMAL, SWAP_FEE_RATE, TEN};
+use crate::types::price_source::{OraclePriceResponse, OracleQueryMsg};
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_slice, to_binary, Binary, ContractResult, CustomQuery, Empty, OwnedDeps, Querier,
-    QuerierResult, QueryRequest, StdError, StdResult, SystemError, SystemResult, WasmQuery,
+    from_binary, from_slice, to_binary, AllValidatorsResponse, Binary, ContractResult, CustomQuery,
+    Decimal, Empty, FullDelegation, OwnedDeps, Querier, QuerierResult, QueryRequest, StakingQuery,
+    StdError, StdResult, SystemError, SystemResult, Validator, WasmQuery,
 };
 use osmosis_std::shim::Any;
+use osmosis_std::types::cosmos::authz::v1beta1::{Grant, QueryGrantsResponse};
 use osmosis_std::types::cosmos::base::v1beta1::Coin;
 use osmosis_std::types::osmosis::gamm::v1beta1::{
     Pool, PoolAsset, PoolParams, QueryCalcJoinPoolSharesResponse, QueryPoolRequest,
     QueryPoolResponse,
 };
+use osmosis_std::types::osmosis::gamm::v2::QuerySpotPriceResponse;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::EstimateSwapExactAmountInResponse;
-use osmosis_std::types::osmosis::twap::v1beta1::ArithmeticTwapResponse;
+use osmosis_std::types::osmosis::superfluid::{AssetTypeResponse, SuperfluidAssetType};
+use osmosis_std::types::osmosis::twap::v1beta1::{ArithmeticTwapResponse, GeometricTwapResponse};
 use prost::Message;
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
@@ -20,6 +25,9 @@ pub const USER: &str = "user";
 pub const ADMIN: &str = "admin";
 pub const FEE_COLLECTOR: &str = "fee_collector";
 pub const VALIDATOR: &str = "validator";
+pub const VALIDATOR_2: &str = "validator-2";
+pub const HIGH_COMMISSION_VALIDATOR: &str = "high-commission-validator";
+pub const ORACLE: &str = "oracle";
 
 pub const DENOM_UOSMO: &str = "uosmo";
 pub const DENOM_STAKE: &str = "stake";
@@ -32,6 +40,7 @@ pub type StargateHandler = dyn Fn(&str, &Binary) -> StdResult<Binary>;
 pub struct CalcMockQuerier<C: DeserializeOwned = Empty> {
     default_stargate_handler: Box<StargateHandler>,
     stargate_handler: Box<StargateHandler>,
+    jailed_validators: Vec<String>,
     mock_querier: MockQuerier<C>,
 }
 
@@ -44,11 +53,34 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                         arithmetic_twap: ONE_DECIMAL.to_string(),
                     })
                 }
+                "/osmosis.twap.v1beta1.Query/ArithmeticTwap" => {
+                    to_binary(&ArithmeticTwapResponse {
+                        arithmetic_twap: ONE_DECIMAL.to_string(),
+                    })
+                }
+                "/osmosis.twap.v1beta1.Query/GeometricTwapToNow" => {
+                    to_binary(&GeometricTwapResponse {
+                        geometric_twap: ONE_DECIMAL.to_string(),
+                    })
+                }
+                "/osmosis.gamm.v2.Query/SpotPrice" => to_binary(&QuerySpotPriceResponse {
+                    spot_price: ONE_DECIMAL.to_string(),
+                }),
                 "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
                     to_binary(&EstimateSwapExactAmountInResponse {
                         token_out_amount: ONE.to_string(),
                     })
                 }
+                "/cosmos.authz.v1beta1.Query/Grants" => to_binary(&QueryGrantsResponse {
+                    grants: vec![Grant {
+                        authorization: None,
+                        expiration: None,
+                    }],
+                    pagination: None,
+                }),
+                "/osmosis.superfluid.Query/AssetType" => to_binary(&AssetTypeResponse {
+                    asset_type: SuperfluidAssetType::LpShare as i32,
+                }),
                 "/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares" => {
                     to_binary(&QueryCalcJoinPoolSharesResponse {
                         share_out_amount: TEN.to_string(),
@@ -56,7 +88,7 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                     })
                 }
                 "/osmosis.gamm.v1beta1.Query/Pool" => {
-                    let pools = vec![
+                    let pools = [
                         Pool {
                             id: 0,
                             pool_assets: vec![
@@ -196,11 +228,53 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                     "no custom stargate handler, should invoke the default handler",
                 ))
             }),
-            mock_querier: MockQuerier::<C>::new(&[]),
+            jailed_validators: vec![],
+            mock_querier: {
+                let mut mock_querier = MockQuerier::<C>::new(&[]);
+                mock_querier.update_staking(DENOM_UOSMO, &mock_validators(), &[]);
+                mock_querier
+            },
         }
     }
 }
 
+/// A mock oracle contract deployed at `ORACLE` that quotes every pair at the given price.
+pub fn mock_oracle(price: Decimal) -> impl Fn(&WasmQuery) -> QuerierResult {
+    move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == ORACLE => {
+            match from_binary::<OracleQueryMsg>(msg) {
+                Ok(OracleQueryMsg::Price { .. }) => SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&OraclePriceResponse { price }).unwrap(),
+                )),
+                Err(error) => SystemResult::Ok(ContractResult::Err(error.to_string())),
+            }
+        }
+        WasmQuery::Smart { contract_addr, .. } => SystemResult::Err(SystemError::NoSuchContract {
+            addr: contract_addr.clone(),
+        }),
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "wasm".to_string(),
+        }),
+    }
+}
+
+fn mock_validators() -> Vec<Validator> {
+    vec![
+        mock_validator(VALIDATOR, Decimal::percent(5)),
+        mock_validator(VALIDATOR_2, Decimal::percent(5)),
+        mock_validator(HIGH_COMMISSION_VALIDATOR, Decimal::percent(50)),
+    ]
+}
+
+fn mock_validator(address: &str, commission: Decimal) -> Validator {
+    Validator {
+        address: address.to_string(),
+        commission,
+        max_commission: Decimal::percent(100),
+        max_change_rate: Decimal::percent(1),
+    }
+}
+
 impl<C: CustomQuery + DeserializeOwned> Querier for CalcMockQuerier<C> {
     fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
         let request: QueryRequest<C> = match from_slice(bin_request) {
@@ -217,26 +291,50 @@ impl<C: CustomQuery + DeserializeOwned> Querier for CalcMockQuerier<C> {
 }
 
 impl<C: CustomQuery + DeserializeOwned> CalcMockQuerier<C> {
-    pub fn update_stargate<WH: 'static>(&mut self, stargate_handler: WH)
+    pub fn update_stargate<WH>(&mut self, stargate_handler: WH)
     where
-        WH: Fn(&str, &Binary) -> StdResult<Binary>,
+        WH: Fn(&str, &Binary) -> StdResult<Binary> + 'static,
     {
         self.stargate_handler = Box::from(stargate_handler);
     }
 
-    pub fn update_wasm<WH: 'static>(&mut self, wasm_handler: WH)
+    pub fn update_wasm<WH>(&mut self, wasm_handler: WH)
     where
-        WH: Fn(&WasmQuery) -> QuerierResult,
+        WH: Fn(&WasmQuery) -> QuerierResult + 'static,
     {
         self.mock_querier.update_wasm(wasm_handler);
     }
 
+    pub fn update_balance(&mut self, addr: impl Into<String>, balance: Vec<cosmwasm_std::Coin>) {
+        self.mock_querier.update_balance(addr, balance);
+    }
+
+    pub fn update_delegations(&mut self, delegations: &[FullDelegation]) {
+        self.mock_querier
+            .update_staking(DENOM_UOSMO, &mock_validators(), delegations);
+    }
+
+    pub fn jail_validator(&mut self, validator_address: &str) {
+        self.jailed_validators.push(validator_address.to_string());
+    }
+
     pub fn handle_query(&self, request: &QueryRequest<C>) -> QuerierResult {
         match &request {
             QueryRequest::Stargate { path, data } => SystemResult::Ok(ContractResult::Ok(
                 (*self.stargate_handler)(path, data)
                     .unwrap_or_else(|_| (*self.default_stargate_handler)(path, data).unwrap()),
             )),
+            QueryRequest::Staking(StakingQuery::AllValidators {}) => {
+                let mut response: AllValidatorsResponse =
+                    from_binary(&self.mock_querier.handle_query(request).unwrap().unwrap())
+                        .unwrap();
+
+                response
+                    .validators
+                    .retain(|validator| !self.jailed_validators.contains(&validator.address));
+
+                SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
+            }
             _ => self.mock_querier.handle_query(request),
         }
     }
@@ -0,0 +1,19 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp};
+
+/// Guards a pair against executing into a manipulated pool. Swaps are skipped when the spot
+/// price for the swap deviates from the belief price by more than `max_deviation`.
+#[cw_serde]
+pub struct CircuitBreaker {
+    pub max_deviation: Decimal,
+    pub auto_pause: bool,
+}
+
+#[cw_serde]
+pub struct CircuitBreakerState {
+    pub tripped_at: Timestamp,
+    pub spot_price: Decimal,
+    pub belief_price: Decimal,
+    pub deviation: Decimal,
+    pub paused: bool,
+}
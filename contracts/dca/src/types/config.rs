@@ -1,6 +1,6 @@
 use super::fee_collector::FeeCollector;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Decimal, Timestamp};
 
 #[cw_serde]
 pub struct Config {
@@ -16,3 +16,38 @@ pub struct Config {
     pub twap_period: u64,
     pub default_slippage_tolerance: Decimal,
 }
+
+#[cw_serde]
+pub struct PendingAdmin {
+    pub address: Addr,
+    pub expires_at: Timestamp,
+}
+
+#[cw_serde]
+pub enum ConfigChange {
+    UpdateConfig {
+        executors: Option<Vec<Addr>>,
+        fee_collectors: Option<Vec<FeeCollector>>,
+        default_swap_fee_percent: Option<Decimal>,
+        weighted_scale_swap_fee_percent: Option<Decimal>,
+        automation_fee_percent: Option<Decimal>,
+        risk_weighted_average_escrow_level: Option<Decimal>,
+        config_change_delay_seconds: Option<u64>,
+        performance_fee_percent: Option<Decimal>,
+    },
+    CreateCustomSwapFee {
+        denom: String,
+        swap_fee_percent: Decimal,
+    },
+    RemoveCustomSwapFee {
+        denom: String,
+    },
+}
+
+#[cw_serde]
+pub struct QueuedConfigChange {
+    pub id: u64,
+    pub change: ConfigChange,
+    pub queued_at: Timestamp,
+    pub executable_at: Timestamp,
+}
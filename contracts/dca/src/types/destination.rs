@@ -1,9 +1,46 @@
+use super::lockable_duration::LockableDuration;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+
+#[cw_serde]
+pub struct WeightedValidator {
+    pub validator_address: Addr,
+    pub weight: Decimal,
+}
+
+#[cw_serde]
+pub enum DestinationAction {
+    Send,
+    ExecuteContract {
+        msg: Binary,
+    },
+    Delegate {
+        validator_address: Addr,
+    },
+    DelegateToValidators {
+        validators: Vec<WeightedValidator>,
+        max_commission_rate: Option<Decimal>,
+    },
+    ProvideLiquidity {
+        pool_id: u64,
+        duration: LockableDuration,
+        slippage_tolerance: Option<Decimal>,
+        superfluid_validator_address: Option<Addr>,
+    },
+    DepositToVault {
+        vault_id: Uint128,
+    },
+}
+
+impl DestinationAction {
+    pub fn is_automated(&self) -> bool {
+        !matches!(self, DestinationAction::Send)
+    }
+}
 
 #[cw_serde]
 pub struct Destination {
     pub allocation: Decimal,
     pub address: Addr,
-    pub msg: Option<Binary>,
+    pub action: DestinationAction,
 }
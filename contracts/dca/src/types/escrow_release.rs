@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+
+/// A scheduled release of a vault's escrow. The performance fee is assessed when it is released,
+/// so the amount returned to the vault destinations may be less than `escrowed_amount`.
+#[cw_serde]
+pub struct EscrowRelease {
+    pub vault_id: Uint128,
+    pub due_date: Timestamp,
+    pub escrowed_amount: Coin,
+}
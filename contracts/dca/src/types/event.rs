@@ -1,11 +1,29 @@
+use super::{destination::WeightedValidator, lockable_duration::LockableDuration};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
 
 #[cw_serde]
 pub enum ExecutionSkippedReason {
     SlippageToleranceExceeded,
-    PriceThresholdExceeded { price: Decimal },
+    PriceThresholdExceeded {
+        price: Decimal,
+    },
     SwapAmountAdjustedToZero,
+    FundingGrantNotFound,
+    FundingGrantExpired,
+    InsufficientFunderBalance,
+    FundingFailed,
+    NoStakingRewardsClaimed,
+    PriceDeviationTooHigh {
+        spot_price: Decimal,
+        belief_price: Decimal,
+    },
+    PairPaused,
+    PairDisabled,
+    ValueAveragingTargetReached {
+        current_value: Uint128,
+        target_value: Uint128,
+    },
 }
 
 #[cw_serde]
@@ -39,10 +57,59 @@ pub enum EventData {
         amount_disbursed: Coin,
         performance_fee: Coin,
     },
+    DcaVaultEscrowCheckpoint {
+        amount_released: Coin,
+        performance_fee: Coin,
+    },
     DcaVaultPostExecutionActionFailed {
         msg: SubMsg,
         funds: Vec<Coin>,
     },
+    DcaVaultContractExecuted {
+        contract_address: Addr,
+        amount: Coin,
+    },
+    DcaVaultFundsDelegated {
+        delegator_address: Addr,
+        validator_address: Addr,
+        amount: Coin,
+    },
+    DcaVaultFundsDelegatedToValidators {
+        delegator_address: Addr,
+        validators: Vec<WeightedValidator>,
+        amount: Coin,
+    },
+    DcaVaultLiquidityProvided {
+        provider_address: Addr,
+        pool_id: u64,
+        duration: LockableDuration,
+        amount: Coin,
+    },
+    DcaVaultLiquidityLocked {
+        provider_address: Addr,
+        pool_id: u64,
+        lock_id: u64,
+        duration: LockableDuration,
+        superfluid_validator_address: Option<Addr>,
+    },
+    DcaVaultFundsDepositedToVault {
+        vault_id: Uint128,
+        amount: Coin,
+    },
+    DcaVaultPairRouteUpdated {
+        base_denom: String,
+        quote_denom: String,
+        old_route: Vec<u64>,
+        new_route: Vec<u64>,
+    },
+    DcaVaultCircuitBreakerTripped {
+        base_denom: String,
+        quote_denom: String,
+        spot_price: Decimal,
+        belief_price: Decimal,
+        deviation: Decimal,
+        paused: bool,
+    },
 }
 
 #[cw_serde]
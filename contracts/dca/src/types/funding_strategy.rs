@@ -0,0 +1,49 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum FundingStrategy {
+    Deposit,
+    AuthzPull { consecutive_failures: u32 },
+    StakingRewards { consecutive_failures: u32 },
+}
+
+#[cw_serde]
+pub enum FundingStrategyParams {
+    Deposit,
+    AuthzPull { swap_denom: String },
+    StakingRewards {},
+}
+
+impl FundingStrategy {
+    pub fn is_authz_pull(&self) -> bool {
+        matches!(self, FundingStrategy::AuthzPull { .. })
+    }
+
+    pub fn is_staking_rewards(&self) -> bool {
+        matches!(self, FundingStrategy::StakingRewards { .. })
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        match self {
+            FundingStrategy::AuthzPull {
+                consecutive_failures,
+            }
+            | FundingStrategy::StakingRewards {
+                consecutive_failures,
+            } => *consecutive_failures,
+            FundingStrategy::Deposit => 0,
+        }
+    }
+
+    pub fn with_consecutive_failures(&self, consecutive_failures: u32) -> FundingStrategy {
+        match self {
+            FundingStrategy::AuthzPull { .. } => FundingStrategy::AuthzPull {
+                consecutive_failures,
+            },
+            FundingStrategy::StakingRewards { .. } => FundingStrategy::StakingRewards {
+                consecutive_failures,
+            },
+            FundingStrategy::Deposit => FundingStrategy::Deposit,
+        }
+    }
+}
@@ -7,16 +7,24 @@ pub enum LockableDuration {
     OneDay,
     OneWeek,
     TwoWeeks,
+    Custom { seconds: u64 },
+}
+
+impl LockableDuration {
+    pub fn seconds(&self) -> u64 {
+        match self {
+            LockableDuration::OneDay => 60 * 60 * 24,
+            LockableDuration::OneWeek => 60 * 60 * 24 * 7,
+            LockableDuration::TwoWeeks => 60 * 60 * 24 * 14,
+            LockableDuration::Custom { seconds } => *seconds,
+        }
+    }
 }
 
 impl From<LockableDuration> for Duration {
     fn from(ld: LockableDuration) -> Self {
         Duration {
-            seconds: match ld {
-                LockableDuration::OneDay => 60 * 60 * 24,
-                LockableDuration::OneWeek => 60 * 60 * 24 * 7,
-                LockableDuration::TwoWeeks => 60 * 60 * 24 * 14,
-            },
+            seconds: ld.seconds() as i64,
             nanos: 0,
         }
     }
@@ -24,14 +32,11 @@ impl From<LockableDuration> for Duration {
 
 impl Display for LockableDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                LockableDuration::OneDay => "1 day",
-                LockableDuration::OneWeek => "1 week",
-                LockableDuration::TwoWeeks => "2 weeks",
-            }
-        )
+        match self {
+            LockableDuration::OneDay => write!(f, "1 day"),
+            LockableDuration::OneWeek => write!(f, "1 week"),
+            LockableDuration::TwoWeeks => write!(f, "2 weeks"),
+            LockableDuration::Custom { seconds } => write!(f, "{} seconds", seconds),
+        }
     }
 }
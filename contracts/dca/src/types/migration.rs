@@ -0,0 +1,7 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct MigrationProgress {
+    pub version: u16,
+    pub cursor: Option<u128>,
+}
@@ -1,11 +1,21 @@
+pub mod circuit_breaker;
 pub mod config;
 pub mod destination;
+pub mod escrow_release;
 pub mod event;
 pub mod fee_collector;
+pub mod funding_strategy;
 pub mod lockable_duration;
+pub mod migration;
 pub mod pair;
 pub mod performance_assessment_strategy;
 pub mod position_type;
+pub mod price_history;
+pub mod price_source;
+pub mod risk_weighted_average_model;
+pub mod role;
+pub mod solvency;
+pub mod swap_adjustment;
 pub mod swap_adjustment_strategy;
 pub mod time_interval;
 pub mod trigger;
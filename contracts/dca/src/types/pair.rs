@@ -1,11 +1,26 @@
-use super::position_type::PositionType;
+use super::{
+    circuit_breaker::CircuitBreaker, position_type::PositionType, price_source::PriceSource,
+};
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Uint128};
 
 #[cw_serde]
 pub struct Pair {
     pub base_denom: String,
     pub quote_denom: String,
     pub route: Vec<u64>,
+    pub price_source: Option<PriceSource>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub config: Option<PairConfig>,
+}
+
+/// Per pair settings that override the global defaults in `Config` when set.
+#[cw_serde]
+pub struct PairConfig {
+    pub enabled: bool,
+    pub minimum_swap_amount: Option<Uint128>,
+    pub twap_period: Option<u64>,
+    pub max_slippage_tolerance: Option<Decimal>,
 }
 
 impl Pair {
@@ -17,6 +32,36 @@ impl Pair {
         }
     }
 
+    pub fn is_enabled(&self) -> bool {
+        self.config.as_ref().is_none_or(|config| config.enabled)
+    }
+
+    pub fn minimum_swap_amount(&self) -> Option<Uint128> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.minimum_swap_amount)
+    }
+
+    pub fn twap_period(&self, default_twap_period: u64) -> u64 {
+        self.config
+            .as_ref()
+            .and_then(|config| config.twap_period)
+            .unwrap_or(default_twap_period)
+    }
+
+    pub fn max_slippage_tolerance(&self) -> Option<Decimal> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.max_slippage_tolerance)
+    }
+
+    pub fn capped_slippage_tolerance(&self, slippage_tolerance: Decimal) -> Decimal {
+        self.max_slippage_tolerance()
+            .map_or(slippage_tolerance, |max_slippage_tolerance| {
+                slippage_tolerance.min(max_slippage_tolerance)
+            })
+    }
+
     pub fn denoms(&self) -> [String; 2] {
         [self.base_denom.clone(), self.quote_denom.clone()]
     }
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, Decimal};
+use cosmwasm_std::{Coin, Decimal, Uint128};
 
 use super::vault::Vault;
 
@@ -8,12 +8,41 @@ pub enum PerformanceAssessmentStrategy {
     CompareToStandardDca {
         swapped_amount: Coin,
         received_amount: Coin,
+        performance_fee: Option<PerformanceFee>,
+    },
+    CompareToLumpSum {
+        start_price: Option<Decimal>,
+        performance_fee: PerformanceFee,
+    },
+    CompareToHoldingSwapDenom {
+        performance_fee: PerformanceFee,
     },
 }
 
 #[cw_serde]
 pub enum PerformanceAssessmentStrategyParams {
     CompareToStandardDca,
+    CompareToBenchmark {
+        benchmark: PerformanceBenchmark,
+        high_water_mark: bool,
+    },
+}
+
+#[cw_serde]
+pub enum PerformanceBenchmark {
+    StandardDca,
+    LumpSum,
+    HoldSwapDenom,
+}
+
+/// Performance fee terms snapshotted when the vault was created. When `high_water_mark` is
+/// `Some`, it holds the highest added value (in the swap denom) that fees have been charged on.
+/// `settled_added_value` holds the added value at the last escrow checkpoint.
+#[cw_serde]
+pub struct PerformanceFee {
+    pub rate: Decimal,
+    pub high_water_mark: Option<Uint128>,
+    pub settled_added_value: Option<Uint128>,
 }
 
 impl PerformanceAssessmentStrategy {
@@ -22,12 +51,78 @@ impl PerformanceAssessmentStrategy {
             PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. } => {
                 vault.deposited_amount.amount > swapped_amount.amount
             }
+            _ => false,
+        }
+    }
+
+    pub fn performance_fee(&self) -> Option<&PerformanceFee> {
+        match self {
+            PerformanceAssessmentStrategy::CompareToStandardDca {
+                performance_fee, ..
+            } => performance_fee.as_ref(),
+            PerformanceAssessmentStrategy::CompareToLumpSum {
+                performance_fee, ..
+            }
+            | PerformanceAssessmentStrategy::CompareToHoldingSwapDenom { performance_fee } => {
+                Some(performance_fee)
+            }
         }
     }
 
     pub fn performance_fee_rate(&self) -> Decimal {
+        self.performance_fee()
+            .map_or(Decimal::percent(20), |performance_fee| performance_fee.rate)
+    }
+
+    pub fn high_water_mark(&self) -> Option<Uint128> {
+        self.performance_fee()
+            .and_then(|performance_fee| performance_fee.high_water_mark)
+    }
+
+    /// Added value that fees are only charged above: the high water mark if one is tracked,
+    /// otherwise the added value at the last escrow checkpoint.
+    pub fn fee_baseline(&self) -> Uint128 {
+        self.performance_fee()
+            .and_then(|performance_fee| {
+                performance_fee
+                    .high_water_mark
+                    .or(performance_fee.settled_added_value)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Records `added_value` as settled, raising the high water mark to it if one is tracked.
+    pub fn with_settled_added_value(self, added_value: Uint128) -> Self {
+        let raise = |performance_fee: PerformanceFee| PerformanceFee {
+            high_water_mark: performance_fee
+                .high_water_mark
+                .map(|high_water_mark| high_water_mark.max(added_value)),
+            settled_added_value: Some(added_value),
+            ..performance_fee
+        };
+
         match self {
-            PerformanceAssessmentStrategy::CompareToStandardDca { .. } => Decimal::percent(20),
+            PerformanceAssessmentStrategy::CompareToStandardDca {
+                swapped_amount,
+                received_amount,
+                performance_fee,
+            } => PerformanceAssessmentStrategy::CompareToStandardDca {
+                swapped_amount,
+                received_amount,
+                performance_fee: performance_fee.map(raise),
+            },
+            PerformanceAssessmentStrategy::CompareToLumpSum {
+                start_price,
+                performance_fee,
+            } => PerformanceAssessmentStrategy::CompareToLumpSum {
+                start_price,
+                performance_fee: raise(performance_fee),
+            },
+            PerformanceAssessmentStrategy::CompareToHoldingSwapDenom { performance_fee } => {
+                PerformanceAssessmentStrategy::CompareToHoldingSwapDenom {
+                    performance_fee: raise(performance_fee),
+                }
+            }
         }
     }
 }
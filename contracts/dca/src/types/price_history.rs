@@ -0,0 +1,20 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp};
+
+/// A price of the pair's base denom, denominated in its quote denom.
+#[cw_serde]
+pub struct PriceSample {
+    pub price: Decimal,
+    pub timestamp: Timestamp,
+}
+
+/// Open, high, low and close prices of the samples recorded in a bucket starting at `open_time`.
+#[cw_serde]
+pub struct PriceCandle {
+    pub open_time: Timestamp,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub samples: u32,
+}
@@ -0,0 +1,27 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal};
+
+#[cw_serde]
+pub enum PriceSource {
+    ArithmeticTwap,
+    GeometricTwap,
+    Spot,
+    Oracle { contract_address: Addr },
+}
+
+/// The query interface an oracle contract must implement to be used as a pair price source.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum OracleQueryMsg {
+    /// The price of 1 `base_denom` denominated in `quote_denom`.
+    #[returns(OraclePriceResponse)]
+    Price {
+        base_denom: String,
+        quote_denom: String,
+    },
+}
+
+#[cw_serde]
+pub struct OraclePriceResponse {
+    pub price: Decimal,
+}
@@ -0,0 +1,60 @@
+use super::swap_adjustment_strategy::BaseDenom;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+
+#[cw_serde]
+pub struct DurationBucket {
+    pub max_days: u32,
+    pub model_id: u8,
+}
+
+#[cw_serde]
+pub struct RiskWeightedAverageModel {
+    pub base_denom: BaseDenom,
+    pub duration_buckets: Vec<DurationBucket>,
+    pub default_model_id: u8,
+    pub min_adjustment: Decimal,
+    pub max_adjustment: Decimal,
+    pub ttl: u64,
+}
+
+impl RiskWeightedAverageModel {
+    pub fn bitcoin() -> Self {
+        Self {
+            base_denom: BaseDenom::Bitcoin,
+            duration_buckets: [
+                (32, 30),
+                (38, 35),
+                (44, 40),
+                (51, 45),
+                (57, 50),
+                (65, 55),
+                (77, 60),
+                (96, 70),
+                (123, 80),
+            ]
+            .into_iter()
+            .map(|(max_days, model_id)| DurationBucket { max_days, model_id })
+            .collect(),
+            default_model_id: 90,
+            min_adjustment: Decimal::percent(20),
+            max_adjustment: Decimal::percent(350),
+            ttl: 60 * 60 * 25,
+        }
+    }
+
+    pub fn model_id(&self, execution_days: i64) -> u8 {
+        self.duration_buckets
+            .iter()
+            .find(|bucket| execution_days <= bucket.max_days.into())
+            .map_or(self.default_model_id, |bucket| bucket.model_id)
+    }
+
+    pub fn has_model_id(&self, model_id: u8) -> bool {
+        self.default_model_id == model_id
+            || self
+                .duration_buckets
+                .iter()
+                .any(|bucket| bucket.model_id == model_id)
+    }
+}
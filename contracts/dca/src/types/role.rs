@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+
+/// Permissions that can be granted to addresses other than the admin. The admin implicitly
+/// holds every role.
+#[cw_serde]
+pub enum Role {
+    PairManager,
+    FeeManager,
+    SwapAdjustmentPublisher,
+    Pauser,
+}
+
+impl Role {
+    pub fn all() -> Vec<Role> {
+        vec![
+            Role::PairManager,
+            Role::FeeManager,
+            Role::SwapAdjustmentPublisher,
+            Role::Pauser,
+        ]
+    }
+
+    pub fn key(&self) -> &str {
+        match self {
+            Role::PairManager => "pair_manager",
+            Role::FeeManager => "fee_manager",
+            Role::SwapAdjustmentPublisher => "swap_adjustment_publisher",
+            Role::Pauser => "pauser",
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+#[derive(Default)]
+pub struct VaultTotals {
+    pub balance: Uint128,
+    pub escrowed: Uint128,
+}
+
+impl VaultTotals {
+    pub fn liabilities(&self) -> Uint128 {
+        self.balance + self.escrowed
+    }
+}
+
+#[cw_serde]
+pub struct DenomSolvency {
+    pub denom: String,
+    pub total_balance: Uint128,
+    pub total_escrowed: Uint128,
+    pub contract_balance: Uint128,
+    pub surplus: Uint128,
+    pub deficit: Uint128,
+}
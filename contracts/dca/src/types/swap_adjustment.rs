@@ -0,0 +1,53 @@
+use super::swap_adjustment_strategy::SwapAdjustmentStrategy;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+
+#[cw_serde]
+pub struct SwapAdjustment {
+    pub strategy: SwapAdjustmentStrategy,
+    pub value: Decimal,
+    pub updated_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentUpdate {
+    pub strategy: SwapAdjustmentStrategy,
+    pub value: Decimal,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentHistoryEntry {
+    pub value: Decimal,
+    pub updated_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentStatus {
+    pub key: String,
+    pub strategy: SwapAdjustmentStrategy,
+    pub value: Decimal,
+    pub published_value: Option<Decimal>,
+    pub updated_at: Option<Timestamp>,
+    pub expires_at: Option<Timestamp>,
+    pub is_stale: bool,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentAggregation {
+    pub quorum: u8,
+    pub max_deviation: Decimal,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentSubmission {
+    pub value: Decimal,
+    pub submitted_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct PublisherSubmissionStatus {
+    pub publisher: Addr,
+    pub submission: Option<SwapAdjustmentSubmission>,
+    pub is_fresh: bool,
+    pub is_outlier: bool,
+}
@@ -1,7 +1,7 @@
-use super::position_type::PositionType;
-use crate::util::calculate_hash;
+use super::{position_type::PositionType, risk_weighted_average_model::RiskWeightedAverageModel};
+use crate::constants::DEFAULT_MOVING_AVERAGE_RESOLUTION_SECONDS;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_binary, Decimal, Uint128};
+use cosmwasm_std::{Decimal, Uint128};
 
 #[cw_serde]
 pub enum SwapAdjustmentStrategy {
@@ -14,6 +14,29 @@ pub enum SwapAdjustmentStrategy {
         base_receive_amount: Uint128,
         multiplier: Decimal,
         increase_only: bool,
+        curve: Option<WeightedScaleCurve>,
+        min_swap_amount: Option<Uint128>,
+        max_swap_amount: Option<Uint128>,
+    },
+    MovingAverage {
+        lookback: u32,
+        #[serde(default = "default_moving_average_resolution")]
+        resolution: u64,
+        sensitivity: Decimal,
+        min_adjustment: Decimal,
+        max_adjustment: Decimal,
+    },
+    ValueAveraging {
+        target_increment: Uint128,
+        max_swap_multiple: Decimal,
+        allow_sells: bool,
+    },
+    VolatilityScaled {
+        window_seconds: u64,
+        windows: u8,
+        target_volatility: Decimal,
+        min_adjustment: Decimal,
+        max_adjustment: Decimal,
     },
 }
 
@@ -26,39 +49,257 @@ pub enum SwapAdjustmentStrategyParams {
         base_receive_amount: Uint128,
         multiplier: Decimal,
         increase_only: bool,
+        curve: Option<WeightedScaleCurve>,
+        min_swap_amount: Option<Uint128>,
+        max_swap_amount: Option<Uint128>,
+    },
+    MovingAverage {
+        lookback: u32,
+        resolution: u64,
+        sensitivity: Decimal,
+        min_adjustment: Decimal,
+        max_adjustment: Decimal,
+    },
+    ValueAveraging {
+        target_increment: Uint128,
+        max_swap_multiple: Decimal,
+        allow_sells: bool,
+    },
+    VolatilityScaled {
+        window_seconds: u64,
+        windows: u8,
+        target_volatility: Decimal,
+        min_adjustment: Decimal,
+        max_adjustment: Decimal,
+    },
+}
+
+/// Shape of a `WeightedScale` adjustment curve. Vaults without a curve scale linearly by
+/// `multiplier`.
+#[cw_serde]
+pub enum WeightedScaleCurve {
+    /// Adjusts by the ratio of the base price to the current price raised to `multiplier`.
+    Exponential,
+    /// Adjusts by the tier with the highest `price` at or below the current price, or by 1
+    /// when the current price is below every tier.
+    Stepped { tiers: Vec<WeightedScaleTier> },
+    /// Scales linearly by `multiplier` and clamps the result to the given bounds.
+    Clamp {
+        min_adjustment: Decimal,
+        max_adjustment: Decimal,
     },
 }
 
+#[cw_serde]
+pub struct WeightedScaleTier {
+    pub price: Decimal,
+    pub adjustment: Decimal,
+}
+
 #[cw_serde]
 pub enum BaseDenom {
     Bitcoin,
+    Ethereum,
+    Atom,
+    Osmo,
+    Other(String),
+}
+
+impl BaseDenom {
+    pub fn key(&self) -> String {
+        match self {
+            BaseDenom::Bitcoin => "bitcoin".to_string(),
+            BaseDenom::Ethereum => "ethereum".to_string(),
+            BaseDenom::Atom => "atom".to_string(),
+            BaseDenom::Osmo => "osmo".to_string(),
+            BaseDenom::Other(symbol) => format!("other:{}", symbol),
+        }
+    }
 }
 
 impl SwapAdjustmentStrategy {
-    pub fn hash(&self) -> u64 {
-        calculate_hash(&to_binary(self).unwrap())
+    /// Stable storage key for published swap adjustment values, of the form
+    /// `risk_weighted_average:{base_denom}:{model_id}:{enter|exit}`,
+    /// `weighted_scale:{base_receive_amount}:{multiplier}:{increase_only}`,
+    /// `moving_average:{lookback}:{resolution}:{sensitivity}:{min_adjustment}:{max_adjustment}`,
+    /// `value_averaging:{target_increment}:{max_swap_multiple}:{allow_sells}` or
+    /// `volatility_scaled:{window_seconds}:{windows}:{target_volatility}:{min_adjustment}:{max_adjustment}`.
+    pub fn key(&self) -> String {
+        match self {
+            SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id,
+                base_denom,
+                position_type,
+            } => format!(
+                "risk_weighted_average:{}:{}:{}",
+                base_denom.key(),
+                model_id,
+                match position_type {
+                    PositionType::Enter => "enter",
+                    PositionType::Exit => "exit",
+                }
+            ),
+            SwapAdjustmentStrategy::WeightedScale {
+                base_receive_amount,
+                multiplier,
+                increase_only,
+                ..
+            } => format!(
+                "weighted_scale:{}:{}:{}",
+                base_receive_amount, multiplier, increase_only
+            ),
+            SwapAdjustmentStrategy::MovingAverage {
+                lookback,
+                resolution,
+                sensitivity,
+                min_adjustment,
+                max_adjustment,
+            } => format!(
+                "moving_average:{}:{}:{}:{}:{}",
+                lookback, resolution, sensitivity, min_adjustment, max_adjustment
+            ),
+            SwapAdjustmentStrategy::ValueAveraging {
+                target_increment,
+                max_swap_multiple,
+                allow_sells,
+            } => format!(
+                "value_averaging:{}:{}:{}",
+                target_increment, max_swap_multiple, allow_sells
+            ),
+            SwapAdjustmentStrategy::VolatilityScaled {
+                window_seconds,
+                windows,
+                target_volatility,
+                min_adjustment,
+                max_adjustment,
+            } => format!(
+                "volatility_scaled:{}:{}:{}:{}:{}",
+                window_seconds, windows, target_volatility, min_adjustment, max_adjustment
+            ),
+        }
     }
 
-    pub fn ttl(&self) -> u64 {
+    pub fn ttl(&self, model: Option<&RiskWeightedAverageModel>) -> u64 {
         match self {
-            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => 60 * 60 * 25,
+            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => model.map_or(0, |m| m.ttl),
             _ => 0,
         }
     }
 
-    pub fn max_adjustment(&self) -> Decimal {
+    pub fn max_adjustment(&self, model: Option<&RiskWeightedAverageModel>) -> Decimal {
         match self {
-            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => Decimal::percent(350),
-            SwapAdjustmentStrategy::WeightedScale { .. } => Decimal::MAX,
+            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => {
+                model.map_or(Decimal::one(), |m| m.max_adjustment)
+            }
+            SwapAdjustmentStrategy::WeightedScale { curve, .. } => match curve {
+                Some(WeightedScaleCurve::Stepped { tiers }) => tiers
+                    .iter()
+                    .fold(Decimal::one(), |max, tier| max.max(tier.adjustment)),
+                Some(WeightedScaleCurve::Clamp { max_adjustment, .. }) => *max_adjustment,
+                _ => Decimal::MAX,
+            },
+            SwapAdjustmentStrategy::MovingAverage { max_adjustment, .. } => *max_adjustment,
+            SwapAdjustmentStrategy::ValueAveraging {
+                max_swap_multiple, ..
+            } => *max_swap_multiple,
+            SwapAdjustmentStrategy::VolatilityScaled { max_adjustment, .. } => *max_adjustment,
         }
     }
 
-    pub fn min_adjustment(&self) -> Decimal {
+    pub fn min_adjustment(&self, model: Option<&RiskWeightedAverageModel>) -> Decimal {
         match self {
-            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => Decimal::percent(20),
-            SwapAdjustmentStrategy::WeightedScale { increase_only, .. } => {
-                Decimal::percent(if *increase_only { 100 } else { 0 })
+            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => {
+                model.map_or(Decimal::one(), |m| m.min_adjustment)
+            }
+            SwapAdjustmentStrategy::WeightedScale {
+                increase_only,
+                curve,
+                ..
+            } => {
+                let min_adjustment = match curve {
+                    Some(WeightedScaleCurve::Stepped { tiers }) => tiers
+                        .iter()
+                        .fold(Decimal::one(), |min, tier| min.min(tier.adjustment)),
+                    Some(WeightedScaleCurve::Clamp { min_adjustment, .. }) => *min_adjustment,
+                    _ => Decimal::zero(),
+                };
+
+                if *increase_only {
+                    min_adjustment.max(Decimal::one())
+                } else {
+                    min_adjustment
+                }
             }
+            SwapAdjustmentStrategy::MovingAverage { min_adjustment, .. } => *min_adjustment,
+            SwapAdjustmentStrategy::ValueAveraging { .. } => Decimal::zero(),
+            SwapAdjustmentStrategy::VolatilityScaled { min_adjustment, .. } => *min_adjustment,
         }
     }
 }
+
+/// Resolution of moving average vaults created before it was configurable.
+fn default_moving_average_resolution() -> u64 {
+    DEFAULT_MOVING_AVERAGE_RESOLUTION_SECONDS
+}
+
+#[cfg(test)]
+mod swap_adjustment_strategy_tests {
+    use super::*;
+
+    #[test]
+    fn risk_weighted_average_key_is_stable() {
+        let strategy = SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: 30,
+            base_denom: BaseDenom::Other("ATOM".to_string()),
+            position_type: PositionType::Exit,
+        };
+
+        assert_eq!(strategy.key(), "risk_weighted_average:other:ATOM:30:exit");
+    }
+
+    #[test]
+    fn weighted_scale_key_is_stable() {
+        let strategy = SwapAdjustmentStrategy::WeightedScale {
+            base_receive_amount: Uint128::new(100),
+            multiplier: Decimal::percent(150),
+            increase_only: true,
+            curve: None,
+            min_swap_amount: None,
+            max_swap_amount: None,
+        };
+
+        assert_eq!(strategy.key(), "weighted_scale:100:1.5:true");
+    }
+
+    #[test]
+    fn volatility_scaled_key_is_stable() {
+        let strategy = SwapAdjustmentStrategy::VolatilityScaled {
+            window_seconds: 3600,
+            windows: 12,
+            target_volatility: Decimal::percent(2),
+            min_adjustment: Decimal::percent(50),
+            max_adjustment: Decimal::percent(200),
+        };
+
+        assert_eq!(strategy.key(), "volatility_scaled:3600:12:0.02:0.5:2");
+    }
+
+    #[test]
+    fn moving_average_without_resolution_uses_default() {
+        let strategy: SwapAdjustmentStrategy = cosmwasm_std::from_slice(
+            br#"{"moving_average":{"lookback":7,"sensitivity":"1","min_adjustment":"0.5","max_adjustment":"2"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            strategy,
+            SwapAdjustmentStrategy::MovingAverage {
+                lookback: 7,
+                resolution: DEFAULT_MOVING_AVERAGE_RESOLUTION_SECONDS,
+                sensitivity: Decimal::one(),
+                min_adjustment: Decimal::percent(50),
+                max_adjustment: Decimal::percent(200),
+            }
+        );
+    }
+}
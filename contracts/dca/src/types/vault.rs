@@ -1,7 +1,8 @@
 use super::{
-    destination::Destination, performance_assessment_strategy::PerformanceAssessmentStrategy,
-    position_type::PositionType, swap_adjustment_strategy::SwapAdjustmentStrategy,
-    time_interval::TimeInterval, trigger::TriggerConfiguration,
+    destination::Destination, funding_strategy::FundingStrategy,
+    performance_assessment_strategy::PerformanceAssessmentStrategy, position_type::PositionType,
+    swap_adjustment_strategy::SwapAdjustmentStrategy, time_interval::TimeInterval,
+    trigger::TriggerConfiguration,
 };
 use crate::helpers::time::get_total_execution_duration;
 use cosmwasm_schema::cw_serde;
@@ -39,6 +40,7 @@ pub struct Vault {
     pub trigger: Option<TriggerConfiguration>,
     pub performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     pub swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pub funding_strategy: FundingStrategy,
 }
 
 impl Vault {
@@ -108,8 +110,7 @@ impl Vault {
 
     pub fn should_not_continue(&self) -> bool {
         self.is_inactive()
-            && self.performance_assessment_strategy.clone().map_or(
-                true,
+            && self.performance_assessment_strategy.as_ref().is_none_or(
                 |performance_assessment_strategy| {
                     !performance_assessment_strategy.should_continue(self)
                 },
@@ -119,6 +120,10 @@ impl Vault {
     pub fn is_cancelled(&self) -> bool {
         self.status == VaultStatus::Cancelled
     }
+
+    pub fn is_pull_funded(&self) -> bool {
+        self.funding_strategy.is_authz_pull() || self.funding_strategy.is_staking_rewards()
+    }
 }
 
 pub struct VaultBuilder {
@@ -142,9 +147,11 @@ pub struct VaultBuilder {
     pub escrowed_amount: Coin,
     pub performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     pub swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pub funding_strategy: FundingStrategy,
 }
 
 impl VaultBuilder {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         created_at: Timestamp,
         owner: Addr,
@@ -166,6 +173,7 @@ impl VaultBuilder {
         escrowed_amount: Coin,
         performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+        funding_strategy: FundingStrategy,
     ) -> VaultBuilder {
         VaultBuilder {
             created_at,
@@ -188,6 +196,7 @@ impl VaultBuilder {
             escrowed_amount,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            funding_strategy,
         }
     }
 
@@ -213,6 +222,7 @@ impl VaultBuilder {
             escrowed_amount: self.escrowed_amount,
             performance_assessment_strategy: self.performance_assessment_strategy,
             swap_adjustment_strategy: self.swap_adjustment_strategy,
+            funding_strategy: self.funding_strategy,
             trigger: None,
         }
     }
@@ -266,6 +276,7 @@ mod should_not_continue_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     swapped_amount: Coin::new((TEN - ONE).into(), DENOM_UOSMO),
                     received_amount: Coin::new((TEN - ONE).into(), DENOM_UOSMO),
+                    performance_fee: None,
                 },
             ),
             ..Default::default()
@@ -283,6 +294,7 @@ mod should_not_continue_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     swapped_amount: Coin::new(TEN.into(), DENOM_UOSMO),
                     received_amount: Coin::new(TEN.into(), DENOM_UOSMO),
+                    performance_fee: None,
                 },
             ),
             ..Default::default()
@@ -340,6 +352,7 @@ mod get_expected_execution_completed_date_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     swapped_amount: Coin::new(ONE.into(), DENOM_UOSMO),
                     received_amount: Coin::new(ONE.into(), DENOM_UOSMO),
+                    performance_fee: None,
                 },
             ),
             ..Vault::default()
@@ -361,6 +374,7 @@ mod get_expected_execution_completed_date_tests {
                 PerformanceAssessmentStrategy::CompareToStandardDca {
                     swapped_amount: Coin::new((ONE + ONE + ONE).into(), DENOM_UOSMO),
                     received_amount: Coin::new((ONE + ONE + ONE).into(), DENOM_UOSMO),
+                    performance_fee: None,
                 },
             ),
             ..Vault::default()